@@ -6,16 +6,29 @@
 // See README.md for licensing information.
 
 use criterion::{
-    black_box, criterion_group, criterion_main, measurement::WallTime, BenchmarkGroup, BenchmarkId,
+    criterion_group, criterion_main, measurement::WallTime, BenchmarkGroup, BenchmarkId,
     Criterion, Throughput,
 };
 use koopman_checksum::*;
+use std::hint::black_box;
 use std::time::Duration;
 
 fn generate_test_data(size: usize) -> Vec<u8> {
     (0..size).map(|i| (i & 0xFF) as u8).collect()
 }
 
+/// Pseudo-random bytes from a fixed-seed LCG, so the final correction in the
+/// fast reductions is taken unpredictably.
+fn generate_random_data(size: usize) -> Vec<u8> {
+    let mut state: u32 = 0x2545_f491;
+    (0..size)
+        .map(|_| {
+            state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            (state >> 24) as u8
+        })
+        .collect()
+}
+
 /// Speed up benchmark runs by reducing measurement time and warm-up time.
 fn fast_config(group: &mut BenchmarkGroup<WallTime>) {
     group.warm_up_time(Duration::from_millis(500));
@@ -34,6 +47,12 @@ fn bench_koopman8(c: &mut Criterion) {
         group.bench_with_input(BenchmarkId::new("checksum", size), &data, |b, data| {
             b.iter(|| koopman8(black_box(data), 0))
         });
+
+        // Generic `%` reduction, for comparison with the fast_mod_253 path
+        let modulus = std::num::NonZeroU32::new(MODULUS_8).unwrap();
+        group.bench_with_input(BenchmarkId::new("generic_mod", size), &data, |b, data| {
+            b.iter(|| koopman8_with_modulus(black_box(data), 0, modulus))
+        });
     }
 
     group.finish();
@@ -141,6 +160,14 @@ fn bench_streaming(c: &mut Criterion) {
         })
     });
 
+    group.bench_function("streaming_default_single_update", |b| {
+        b.iter(|| {
+            let mut hasher = Koopman16Default::new();
+            hasher.update(black_box(&data));
+            hasher.finalize()
+        })
+    });
+
     group.bench_function("streaming_chunked_64", |b| {
         b.iter(|| {
             let mut hasher = Koopman16::new();
@@ -151,6 +178,167 @@ fn bench_streaming(c: &mut Criterion) {
         })
     });
 
+    group.bench_function("streaming8_single_update", |b| {
+        b.iter(|| {
+            let mut hasher = Koopman8::new();
+            hasher.update(black_box(&data));
+            hasher.finalize()
+        })
+    });
+
+    group.bench_function("streaming32_single_update", |b| {
+        b.iter(|| {
+            let mut hasher = Koopman32::new();
+            hasher.update(black_box(&data));
+            hasher.finalize()
+        })
+    });
+
+    group.bench_function("streaming16p_single_update", |b| {
+        b.iter(|| {
+            let mut hasher = Koopman16P::new();
+            hasher.update(black_box(&data));
+            hasher.finalize()
+        })
+    });
+
+    group.bench_function("streaming32p_single_update", |b| {
+        b.iter(|| {
+            let mut hasher = Koopman32P::new();
+            hasher.update(black_box(&data));
+            hasher.finalize()
+        })
+    });
+
+    group.bench_function("streaming32_chunked_64", |b| {
+        b.iter(|| {
+            let mut hasher = Koopman32::new();
+            for chunk in data.chunks(64) {
+                hasher.update(black_box(chunk));
+            }
+            hasher.finalize()
+        })
+    });
+
+    group.finish();
+}
+
+/// Compare with `cargo bench --bench benchmarks -- Reduction` with and without
+/// `--features branchless` to measure the branchy vs branchless correction.
+fn bench_reduction(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Reduction");
+    fast_config(&mut group);
+
+    let data = generate_random_data(4096);
+    group.throughput(Throughput::Bytes(4096));
+
+    group.bench_function("koopman8_random", |b| b.iter(|| koopman8(black_box(&data), 0xee)));
+    group.bench_function("koopman16_random", |b| b.iter(|| koopman16(black_box(&data), 0xee)));
+    group.bench_function("koopman32_random", |b| b.iter(|| koopman32(black_box(&data), 0xee)));
+    // Odd length and offset: the eight-byte fold sees an unaligned start and a tail
+    group.throughput(Throughput::Bytes(4093));
+    group.bench_function("koopman32_random_unaligned", |b| {
+        b.iter(|| koopman32(black_box(&data[1..4094]), 0xee))
+    });
+
+    group.finish();
+}
+
+/// Koopman16 reduction cadence: how many bytes are folded per reduction.
+fn bench_cadence16(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Cadence16");
+    fast_config(&mut group);
+
+    let data = generate_random_data(KOOPMAN_PAGE_SIZE);
+    let page: &[u8; KOOPMAN_PAGE_SIZE] = data.as_slice().try_into().unwrap();
+    group.throughput(Throughput::Bytes(KOOPMAN_PAGE_SIZE as u64));
+
+    // No library path reduces every byte any more; this is the textbook loop
+    group.bench_function("every_byte", |b| {
+        b.iter(|| {
+            let data = black_box(&data);
+            let mut sum = data[0] as u32;
+            for &byte in &data[1..] {
+                sum = ((sum << 8) + byte as u32) % MODULUS_16;
+            }
+            sum = (sum << 8) % MODULUS_16;
+            (sum << 8) % MODULUS_16
+        })
+    });
+    group.bench_function("every_2_bytes", |b| b.iter(|| koopman16(black_box(&data), 0)));
+    group.bench_function("every_4_bytes", |b| b.iter(|| koopman16_page(black_box(page), 0)));
+    group.bench_function("every_8_bytes", |b| {
+        b.iter(|| {
+            let mut hasher = Koopman16::new();
+            hasher.update(black_box(&data));
+            hasher.finalize()
+        })
+    });
+
+    group.finish();
+}
+
+/// Compare with `cargo bench --bench benchmarks -- Streaming64K` with and
+/// without `--features simd` to measure the SIMD wide fold.
+fn bench_streaming_64k(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Streaming64K");
+    fast_config(&mut group);
+
+    let data = generate_random_data(64 * 1024);
+    group.throughput(Throughput::Bytes(data.len() as u64));
+
+    group.bench_function("koopman16_update", |b| {
+        b.iter(|| {
+            let mut hasher = Koopman16::with_seed(0xee);
+            hasher.update(black_box(&data));
+            hasher.finalize()
+        })
+    });
+    group.bench_function("koopman16_one_shot", |b| b.iter(|| koopman16(black_box(&data), 0xee)));
+
+    group.finish();
+}
+
+/// Fixed-length page path against the generic function on the same bytes.
+fn bench_page(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Page");
+    fast_config(&mut group);
+
+    let data = generate_random_data(KOOPMAN_PAGE_SIZE);
+    let page: &[u8; KOOPMAN_PAGE_SIZE] = data.as_slice().try_into().unwrap();
+    group.throughput(Throughput::Bytes(KOOPMAN_PAGE_SIZE as u64));
+
+    group.bench_function("koopman16", |b| b.iter(|| koopman16(black_box(page), 0xee)));
+    group.bench_function("koopman16_page", |b| b.iter(|| koopman16_page(black_box(page), 0xee)));
+
+    group.finish();
+}
+
+/// Tiny inputs, where per-call overhead such as the first-byte handling and
+/// the implicit zero folds dominates.
+fn bench_small_inputs(c: &mut Criterion) {
+    let mut group = c.benchmark_group("SmallInputs");
+    fast_config(&mut group);
+
+    for size in [1, 4, 8, 16].iter() {
+        let data = generate_test_data(*size);
+        group.throughput(Throughput::Bytes(*size as u64));
+
+        group.bench_with_input(BenchmarkId::new("koopman16", size), &data, |b, data| {
+            b.iter(|| koopman16(black_box(data), 0xee))
+        });
+        group.bench_with_input(BenchmarkId::new("koopman32", size), &data, |b, data| {
+            b.iter(|| koopman32(black_box(data), 0xee))
+        });
+        group.bench_with_input(BenchmarkId::new("streaming16", size), &data, |b, data| {
+            b.iter(|| {
+                let mut hasher = Koopman16::with_seed(0xee);
+                hasher.update(black_box(data));
+                hasher.finalize()
+            })
+        });
+    }
+
     group.finish();
 }
 
@@ -163,6 +351,11 @@ criterion_group!(
     bench_koopman16p,
     bench_koopman32p,
     bench_streaming,
+    bench_reduction,
+    bench_small_inputs,
+    bench_page,
+    bench_cadence16,
+    bench_streaming_64k,
 );
 
 criterion_main!(benches);
@@ -0,0 +1,85 @@
+//! Throughput regression guard for the `koopman32` hot loop.
+//!
+//! Reports through criterion like the other benches, then separately times
+//! `koopman32` over 64 KiB and fails if throughput is below a floor, so a
+//! regression in the fast-mod path breaks CI instead of only moving a graph.
+//!
+//! Run with: `cargo bench --bench throughput_guard`
+//!
+//! The floor defaults to 100 MiB/s and can be set with
+//! `KOOPMAN_MIN_MIBPS=<MiB/s>`; `0` disables the check.
+
+// Copyright (c) 2025 the koopman-checksum authors, all rights reserved.
+// See README.md for licensing information.
+
+use criterion::{Criterion, Throughput};
+use koopman_checksum::koopman32;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const SIZE: usize = 64 * 1024;
+const DEFAULT_MIN_MIBPS: f64 = 100.0;
+
+fn generate_test_data(size: usize) -> Vec<u8> {
+    (0..size).map(|i| (i & 0xFF) as u8).collect()
+}
+
+fn bench_guard(c: &mut Criterion, data: &[u8]) {
+    let mut group = c.benchmark_group("ThroughputGuard");
+    group.warm_up_time(Duration::from_millis(500));
+    group.measurement_time(Duration::from_secs(1));
+    group.throughput(Throughput::Bytes(SIZE as u64));
+    group.bench_function("koopman32_64k", |b| b.iter(|| koopman32(black_box(data), 0xee)));
+    group.finish();
+}
+
+/// Best-of-several throughput in MiB/s, which is robust to scheduler noise.
+fn measure_mibps(data: &[u8]) -> f64 {
+    const ITERS: u32 = 64;
+
+    (0..5)
+        .map(|_| {
+            let start = Instant::now();
+            for _ in 0..ITERS {
+                black_box(koopman32(black_box(data), 0xee));
+            }
+            let secs = start.elapsed().as_secs_f64();
+            (data.len() as f64 * ITERS as f64) / secs / (1024.0 * 1024.0)
+        })
+        .fold(0.0, f64::max)
+}
+
+fn min_mibps() -> f64 {
+    match std::env::var("KOOPMAN_MIN_MIBPS") {
+        Ok(v) => v
+            .parse()
+            .unwrap_or_else(|_| panic!("KOOPMAN_MIN_MIBPS must be a number, got {v:?}")),
+        Err(_) => DEFAULT_MIN_MIBPS,
+    }
+}
+
+fn main() {
+    let data = generate_test_data(SIZE);
+
+    let mut c = Criterion::default().configure_from_args();
+    bench_guard(&mut c, &data);
+    c.final_summary();
+
+    // `cargo test --benches` runs unoptimized without `--bench`; only enforce
+    // the floor on real benchmark runs.
+    if !std::env::args().any(|arg| arg == "--bench") {
+        return;
+    }
+
+    let floor = min_mibps();
+    if floor <= 0.0 {
+        return;
+    }
+
+    let mibps = measure_mibps(&data);
+    println!("koopman32 64 KiB: {mibps:.1} MiB/s (floor {floor:.1} MiB/s)");
+    assert!(
+        mibps >= floor,
+        "koopman32 throughput regression: {mibps:.1} MiB/s is below the floor of {floor:.1} MiB/s"
+    );
+}
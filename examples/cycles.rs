@@ -0,0 +1,87 @@
+//! Cycle-count benchmark for `koopman16`, without criterion.
+//!
+//! Times the checksum with the CPU's cycle counter and prints cycles per
+//! byte. Everything but `main` uses only `core`, so the timing loop can be
+//! copied into a `no_std` firmware image: replace `cycles` with the target's
+//! counter (such as `DWT.CYCCNT` on Cortex-M) and report the result however
+//! the board allows.
+//!
+//! Run with: `cargo run --release --example cycles`
+//!
+//! On x86 the counter is the time-stamp counter, which ticks at a fixed
+//! reference rate rather than the core clock, so results move with turbo
+//! and power states; compare runs on an otherwise idle machine.
+
+// Copyright (c) 2025 the koopman-checksum authors, all rights reserved.
+// See README.md for licensing information.
+
+use core::hint::black_box;
+use koopman_checksum::koopman16;
+
+const SIZES: [usize; 4] = [16, 64, 256, 4096];
+const ITERS: u64 = 1000;
+const RUNS: usize = 10;
+
+/// Read the time-stamp counter, fenced so the reads are not reordered
+/// around the code being timed.
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+fn cycles() -> Option<u64> {
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::{_mm_lfence, _rdtsc};
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::{_mm_lfence, _rdtsc};
+
+    // SAFETY: `rdtsc` is available on every x86 CPU this crate targets, and
+    // `lfence` on every CPU with SSE2, which is baseline on x86_64
+    unsafe {
+        _mm_lfence();
+        let t = _rdtsc();
+        _mm_lfence();
+        Some(t)
+    }
+}
+
+/// No cycle counter is wired up for other targets.
+#[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+fn cycles() -> Option<u64> {
+    None
+}
+
+/// Pseudo-random bytes from a fixed-seed LCG, in a stack buffer.
+fn test_data() -> [u8; 4096] {
+    let mut state: u32 = 0x2545_f491;
+    let mut data = [0u8; 4096];
+    for byte in &mut data {
+        state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+        *byte = (state >> 24) as u8;
+    }
+    data
+}
+
+/// Best-of-`RUNS` cycles per call, which is robust to interrupts and
+/// scheduler noise, or `None` without a cycle counter.
+fn measure(data: &[u8]) -> Option<u64> {
+    let mut best = u64::MAX;
+    for _ in 0..RUNS {
+        let start = cycles()?;
+        for _ in 0..ITERS {
+            black_box(koopman16(black_box(data), 0xee));
+        }
+        best = best.min((cycles()? - start) / ITERS);
+    }
+    Some(best)
+}
+
+fn main() {
+    let data = test_data();
+    for size in SIZES {
+        let Some(per_call) = measure(&data[..size]) else {
+            println!("no cycle counter for this target; see the top of examples/cycles.rs");
+            return;
+        };
+        println!(
+            "koopman16 {size:>5} bytes: {per_call:>7} cycles, {:.2} cycles/byte",
+            per_call as f64 / size as f64
+        );
+    }
+}
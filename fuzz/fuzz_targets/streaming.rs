@@ -0,0 +1,91 @@
+//! Fuzz target checking the streaming and verification invariants.
+//!
+//! - Streaming over arbitrary chunkings equals the one-shot result
+//! - `verify*` agrees with recomputing the checksum
+//! - Flipping any single bit changes the checksum for short inputs
+//!
+//! Run with: `cargo fuzz run streaming`
+
+#![no_main]
+
+use arbitrary::Arbitrary;
+use koopman_checksum::*;
+use libfuzzer_sys::fuzz_target;
+
+/// Inputs short enough that every variant detects all 1-bit errors.
+const SHORT_LEN: usize = 13;
+
+#[derive(Arbitrary, Debug)]
+struct Input {
+    chunk_sizes: Vec<u8>,
+    seed: u8,
+    payload: Vec<u8>,
+}
+
+/// Split `payload` into chunks whose sizes cycle through `chunk_sizes`.
+fn chunks<'a>(payload: &'a [u8], chunk_sizes: &[u8]) -> Vec<&'a [u8]> {
+    let mut out = Vec::new();
+    let mut rest = payload;
+    let mut i = 0;
+    while !rest.is_empty() {
+        let size = match chunk_sizes.get(i % chunk_sizes.len().max(1)) {
+            Some(&s) => (s as usize).clamp(1, rest.len()),
+            None => rest.len(),
+        };
+        let (head, tail) = rest.split_at(size);
+        out.push(head);
+        rest = tail;
+        i += 1;
+    }
+    out
+}
+
+macro_rules! check_streaming {
+    ($hasher:ty, $oneshot:ident, $verify:ident, $input:expr, $chunks:expr) => {{
+        let mut hasher = <$hasher>::with_seed($input.seed);
+        for chunk in $chunks {
+            hasher.update(chunk);
+        }
+        let expected = $oneshot(&$input.payload, $input.seed);
+        assert_eq!(hasher.finalize(), expected, "{} streaming mismatch", stringify!($hasher));
+        assert!($verify(&$input.payload, expected, $input.seed));
+    }};
+}
+
+macro_rules! check_single_bit {
+    ($oneshot:ident, $input:expr) => {{
+        let original = $oneshot(&$input.payload, $input.seed);
+        let mut corrupted = $input.payload.clone();
+        for bit in 0..corrupted.len() * 8 {
+            corrupted[bit / 8] ^= 1 << (bit % 8);
+            assert_ne!(
+                $oneshot(&corrupted, $input.seed),
+                original,
+                "{} missed 1-bit error at bit {}",
+                stringify!($oneshot),
+                bit
+            );
+            corrupted[bit / 8] ^= 1 << (bit % 8);
+        }
+    }};
+}
+
+fuzz_target!(|input: Input| {
+    let chunks = chunks(&input.payload, &input.chunk_sizes);
+
+    check_streaming!(Koopman8, koopman8, verify8, input, &chunks);
+    check_streaming!(Koopman16, koopman16, verify16, input, &chunks);
+    check_streaming!(Koopman32, koopman32, verify32, input, &chunks);
+    check_streaming!(Koopman8P, koopman8p, verify8p, input, &chunks);
+    check_streaming!(Koopman16P, koopman16p, verify16p, input, &chunks);
+    check_streaming!(Koopman32P, koopman32p, verify32p, input, &chunks);
+
+    if input.payload.len() <= SHORT_LEN {
+        check_single_bit!(koopman8, input);
+        check_single_bit!(koopman8p, input);
+        check_single_bit!(koopman16, input);
+        check_single_bit!(koopman32, input);
+        check_single_bit!(koopman16p, input);
+        check_single_bit!(koopman32p, input);
+    }
+});
@@ -0,0 +1,231 @@
+//! Parameter-struct configuration in the style of the `crc` crate.
+//!
+//! A [`KoopmanAlgorithm`] describes a complete checksum: width, modulus,
+//! seed, parity, bit reflection, and output XOR. [`Koopman`] is a
+//! general-purpose streaming hasher configured by one. The associated
+//! constants describe the standard variants and reproduce the crate's
+//! default functions.
+
+// Copyright (c) 2025 the koopman-checksum authors, all rights reserved.
+// See README.md for licensing information.
+
+use crate::{MODULUS_15P, MODULUS_16, MODULUS_31P, MODULUS_32, MODULUS_7P, MODULUS_8};
+
+/// Full description of a Koopman checksum.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct KoopmanAlgorithm {
+    /// Output width in bits: 8, 16, or 32.
+    pub width: u8,
+    /// Modulus of the running sum.
+    pub modulus: u64,
+    /// Seed XORed into the first data byte.
+    pub seed: u8,
+    /// Value XORed into the output after any reflection.
+    pub xorout: u64,
+    /// Bit-reverse each input byte before folding it.
+    pub refin: bool,
+    /// Bit-reverse the output within `width` bits. This only permutes output
+    /// bits, so detection power is unchanged.
+    pub refout: bool,
+    /// Pack a parity bit into the LSB, leaving `width - 1` bits for the sum.
+    pub parity: bool,
+}
+
+impl KoopmanAlgorithm {
+    const fn standard(width: u8, modulus: u64, parity: bool) -> Self {
+        Self {
+            width,
+            modulus,
+            seed: 0,
+            xorout: 0,
+            refin: false,
+            refout: false,
+            parity,
+        }
+    }
+
+    /// [`koopman8`](crate::koopman8) with seed 0.
+    pub const KOOPMAN8: Self = Self::standard(8, MODULUS_8 as u64, false);
+    /// [`koopman16`](crate::koopman16) with seed 0.
+    pub const KOOPMAN16: Self = Self::standard(16, MODULUS_16 as u64, false);
+    /// [`koopman32`](crate::koopman32) with seed 0.
+    pub const KOOPMAN32: Self = Self::standard(32, MODULUS_32, false);
+    /// [`koopman8p`](crate::koopman8p) with seed 0.
+    pub const KOOPMAN8P: Self = Self::standard(8, MODULUS_7P as u64, true);
+    /// [`koopman16p`](crate::koopman16p) with seed 0.
+    pub const KOOPMAN16P: Self = Self::standard(16, MODULUS_15P as u64, true);
+    /// [`koopman32p`](crate::koopman32p) with seed 0.
+    pub const KOOPMAN32P: Self = Self::standard(32, MODULUS_31P, true);
+
+    /// The same algorithm with a different seed.
+    #[must_use]
+    pub const fn with_seed(self, seed: u8) -> Self {
+        Self { seed, ..self }
+    }
+
+    /// Bits available to the sum: `width`, less one for the parity bit.
+    const fn sum_bits(&self) -> u32 {
+        self.width as u32 - self.parity as u32
+    }
+}
+
+/// General-purpose streaming hasher configured by a [`KoopmanAlgorithm`].
+///
+/// Uses the generic `%` reduction for every width, so it is slower than the
+/// dedicated hashers such as [`Koopman16`](crate::Koopman16); it exists so one
+/// descriptor can carry the full behavior. Empty input finalizes to 0, like
+/// every checksum in this crate; `xorout` is not applied to it.
+///
+/// # Example
+/// ```rust
+/// use koopman_checksum::algorithm::{Koopman, KoopmanAlgorithm};
+/// use koopman_checksum::koopman16;
+///
+/// let alg = KoopmanAlgorithm::KOOPMAN16.with_seed(0xee);
+/// let mut hasher = Koopman::new(&alg);
+/// hasher.update(b"test data");
+/// assert_eq!(hasher.finalize(), koopman16(b"test data", 0xee) as u64);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Koopman<'a> {
+    algorithm: &'a KoopmanAlgorithm,
+    sum: u64,
+    psum: u8,
+    initialized: bool,
+}
+
+impl<'a> Koopman<'a> {
+    /// Create a hasher for the given algorithm.
+    ///
+    /// # Panics
+    /// Panics if `width` is not 8, 16, or 32, if `modulus` is below 2 or
+    /// does not fit the sum's bits, or if `xorout` does not fit `width` bits.
+    #[track_caller]
+    pub fn new(algorithm: &'a KoopmanAlgorithm) -> Self {
+        assert!(
+            matches!(algorithm.width, 8 | 16 | 32),
+            "width must be 8, 16, or 32, got {}",
+            algorithm.width
+        );
+        assert!(
+            algorithm.modulus >= 2 && algorithm.modulus <= 1 << algorithm.sum_bits(),
+            "modulus {} does not fit a {}-bit sum",
+            algorithm.modulus,
+            algorithm.sum_bits()
+        );
+        assert!(
+            algorithm.xorout < 1 << algorithm.width,
+            "xorout {:#x} does not fit {} bits",
+            algorithm.xorout,
+            algorithm.width
+        );
+
+        Self {
+            algorithm,
+            sum: 0,
+            psum: 0,
+            initialized: false,
+        }
+    }
+
+    /// The algorithm this hasher was configured with.
+    #[inline]
+    #[must_use]
+    pub fn algorithm(&self) -> &'a KoopmanAlgorithm {
+        self.algorithm
+    }
+
+    /// Update the checksum with more data.
+    pub fn update(&mut self, data: &[u8]) {
+        let alg = self.algorithm;
+        for &byte in data {
+            let byte = if alg.refin { byte.reverse_bits() } else { byte };
+            if self.initialized {
+                self.sum = ((self.sum << 8) + byte as u64) % alg.modulus;
+                self.psum ^= byte;
+            } else {
+                self.sum = (byte ^ alg.seed) as u64;
+                self.psum = byte ^ alg.seed;
+                self.initialized = true;
+            }
+        }
+    }
+
+    /// Finalize and return the checksum, zero-extended to `u64`.
+    ///
+    /// Returns 0 if no data was provided.
+    #[must_use]
+    pub fn finalize(self) -> u64 {
+        if !self.initialized {
+            return 0;
+        }
+
+        let alg = self.algorithm;
+        let mut sum = self.sum;
+        for _ in 0..alg.width / 8 {
+            sum = (sum << 8) % alg.modulus;
+        }
+        if alg.parity {
+            sum = (sum << 1) | (self.psum.count_ones() & 1) as u64;
+        }
+        if alg.refout {
+            sum = sum.reverse_bits() >> (64 - alg.width as u32);
+        }
+        sum ^ alg.xorout
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+
+    const TEST_DATA: &[u8] = b"123456789";
+
+    fn checksum(alg: &KoopmanAlgorithm, data: &[u8]) -> u64 {
+        let mut hasher = Koopman::new(alg);
+        for chunk in data.chunks(5) {
+            hasher.update(chunk);
+        }
+        hasher.finalize()
+    }
+
+    #[test]
+    fn test_standard_descriptors_match_default_functions() {
+        let data: [u8; 300] = core::array::from_fn(|i| (i as u32 * 131 + 7) as u8);
+        for data in [&b""[..], b"123456789", &data, &[0xFF; 64]] {
+            for seed in [0u8, 1, 0xee] {
+                let k = |alg: KoopmanAlgorithm| checksum(&alg.with_seed(seed), data);
+                assert_eq!(k(KoopmanAlgorithm::KOOPMAN8), koopman8(data, seed) as u64);
+                assert_eq!(k(KoopmanAlgorithm::KOOPMAN16), koopman16(data, seed) as u64);
+                assert_eq!(k(KoopmanAlgorithm::KOOPMAN32), koopman32(data, seed) as u64);
+                assert_eq!(k(KoopmanAlgorithm::KOOPMAN8P), koopman8p(data, seed) as u64);
+                assert_eq!(k(KoopmanAlgorithm::KOOPMAN16P), koopman16p(data, seed) as u64);
+                assert_eq!(k(KoopmanAlgorithm::KOOPMAN32P), koopman32p(data, seed) as u64);
+            }
+        }
+    }
+
+    #[test]
+    fn test_reflection_and_xorout() {
+        let reflected: [u8; 9] = core::array::from_fn(|i| TEST_DATA[i].reverse_bits());
+        let base = koopman16(TEST_DATA, 0xee);
+
+        let alg = KoopmanAlgorithm { refin: true, ..KoopmanAlgorithm::KOOPMAN16.with_seed(0xee) };
+        assert_eq!(checksum(&alg, TEST_DATA), koopman16(&reflected, 0xee) as u64);
+
+        let alg = KoopmanAlgorithm { refout: true, ..KoopmanAlgorithm::KOOPMAN16.with_seed(0xee) };
+        assert_eq!(checksum(&alg, TEST_DATA), base.reverse_bits() as u64);
+
+        let alg = KoopmanAlgorithm { xorout: 0xffff, ..KoopmanAlgorithm::KOOPMAN16.with_seed(0xee) };
+        assert_eq!(checksum(&alg, TEST_DATA), (!base) as u64);
+        assert_eq!(checksum(&alg, &[]), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "does not fit a 15-bit sum")]
+    fn test_rejects_modulus_too_large_for_parity() {
+        let alg = KoopmanAlgorithm { modulus: 40000, ..KoopmanAlgorithm::KOOPMAN16P };
+        let _ = Koopman::new(&alg);
+    }
+}
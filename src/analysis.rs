@@ -0,0 +1,156 @@
+//! Sampled error-detection measurements, for comparing against other checksums.
+//!
+//! These estimate undetected-error rates by random sampling rather than
+//! exhaustive enumeration, so they scale to message lengths where the
+//! exhaustive Hamming distance tests are impractical. [`checksum_delta16`]
+//! supports building error models by hand.
+
+// Copyright (c) 2025 the koopman-checksum authors, all rights reserved.
+// See README.md for licensing information.
+
+use crate::koopman16;
+
+/// Xorshift64 generator; fixed-seeded so measurements are reproducible.
+struct XorShift64(u64);
+
+impl XorShift64 {
+    fn next(&mut self) -> u64 {
+        let mut x = self.0;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.0 = x;
+        x
+    }
+
+    /// Uniform-enough value in `0..n` for sampling purposes.
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+}
+
+/// Estimate the fraction of random 2-bit errors that [`koopman16`] misses.
+///
+/// Each trial draws a random `len`-byte message, flips two distinct random
+/// bits, and counts a collision if the checksum is unchanged. The sample
+/// stream is fixed, so repeated calls with the same arguments return the
+/// same rate. Within [`KOOPMAN16_MAX_LEN`](crate::KOOPMAN16_MAX_LEN) the rate
+/// is always 0.
+///
+/// # Arguments
+/// * `len` - Message length in bytes
+/// * `trials` - Number of sampled messages
+/// * `initial_seed` - Initial seed passed to [`koopman16`]
+///
+/// # Panics
+/// Panics if `len` or `trials` is zero.
+///
+/// # Example
+/// ```rust
+/// use koopman_checksum::analysis::collision_rate16;
+///
+/// assert_eq!(collision_rate16(1500, 1000, 0xee), 0.0);
+/// ```
+#[must_use]
+#[track_caller]
+pub fn collision_rate16(len: usize, trials: u64, initial_seed: u8) -> f64 {
+    assert!(len > 0, "len must be non-zero");
+    assert!(trials > 0, "trials must be non-zero");
+
+    let mut rng = XorShift64(0x9e37_79b9_7f4a_7c15);
+    let mut data = vec![0u8; len];
+    let total_bits = len * 8;
+    let mut collisions = 0u64;
+
+    for _ in 0..trials {
+        for chunk in data.chunks_mut(8) {
+            let bytes = rng.next().to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+        let original = koopman16(&data, initial_seed);
+
+        let bit1 = rng.below(total_bits);
+        // Second bit drawn from the remaining positions so the two differ
+        let bit2 = (bit1 + 1 + rng.below(total_bits - 1)) % total_bits;
+        data[bit1 / 8] ^= 1 << (bit1 % 8);
+        data[bit2 / 8] ^= 1 << (bit2 % 8);
+
+        if koopman16(&data, initial_seed) == original {
+            collisions += 1;
+        }
+    }
+
+    collisions as f64 / trials as f64
+}
+
+/// XOR of the [`koopman16`] checksums of two equal-length messages.
+///
+/// Flipping the same bits in different messages of one length does not
+/// always produce the same delta, because each byte is reduced modulo
+/// 65519, but the delta shows how a given error pattern propagates into the
+/// check value. A delta of 0 means the difference between `a` and `b` is an
+/// undetected error.
+///
+/// This is a diagnostic for error modeling, not a security primitive:
+/// Koopman checksums are not cryptographic, and the delta reveals nothing
+/// about whether either message was tampered with deliberately.
+///
+/// Returns `None` if the lengths differ.
+///
+/// # Example
+/// ```rust
+/// use koopman_checksum::analysis::checksum_delta16;
+///
+/// assert_eq!(checksum_delta16(b"test data", b"test data", 0xee), Some(0));
+/// assert_ne!(checksum_delta16(b"test data", b"test dat!", 0xee), Some(0));
+/// assert_eq!(checksum_delta16(b"test", b"test data", 0xee), None);
+/// ```
+#[must_use]
+pub fn checksum_delta16(a: &[u8], b: &[u8], initial_seed: u8) -> Option<u16> {
+    if a.len() != b.len() {
+        return None;
+    }
+    Some(koopman16(a, initial_seed) ^ koopman16(b, initial_seed))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::KOOPMAN16_MAX_LEN;
+
+    #[test]
+    fn test_collision_rate16_zero_within_hd_length() {
+        for len in [1, 2, 64, KOOPMAN16_MAX_LEN] {
+            assert_eq!(collision_rate16(len, 2000, 0xee), 0.0, "len = {len}");
+        }
+    }
+
+    #[test]
+    fn test_collision_rate16_is_reproducible() {
+        assert_eq!(collision_rate16(8192, 500, 1), collision_rate16(8192, 500, 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "trials must be non-zero")]
+    fn test_collision_rate16_zero_trials() {
+        let _ = collision_rate16(16, 0, 0);
+    }
+
+    #[test]
+    fn test_checksum_delta16() {
+        let data: Vec<u8> = (0..256u32).map(|i| (i * 131 + 7) as u8).collect();
+        assert_eq!(checksum_delta16(&data, &data, 0xee), Some(0));
+        assert_eq!(checksum_delta16(&[], &[], 0xee), Some(0));
+
+        // Every single-bit error is detected, so its delta is never 0
+        for bit in 0..data.len() * 8 {
+            let mut corrupted = data.clone();
+            corrupted[bit / 8] ^= 1 << (bit % 8);
+            let delta = checksum_delta16(&data, &corrupted, 0xee).unwrap();
+            assert_ne!(delta, 0, "bit {bit}");
+            assert_eq!(delta, koopman16(&data, 0xee) ^ koopman16(&corrupted, 0xee));
+        }
+
+        assert_eq!(checksum_delta16(&data, &data[1..], 0xee), None);
+    }
+}
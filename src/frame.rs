@@ -0,0 +1,381 @@
+//! Typed "payload + checksum" records.
+//!
+//! A frame is the payload followed by its big-endian checksum:
+//!
+//! ```text
+//! [payload ...][checksum (2 bytes, BE)]
+//! ```
+//!
+//! An envelope also carries the parameters needed to check it, so a consumer
+//! can verify it without out-of-band configuration:
+//!
+//! ```text
+//! [width tag (1)][seed (1)][payload length (4, BE)][payload ...][checksum (2, BE)]
+//! ```
+
+// Copyright (c) 2025 the koopman-checksum authors, all rights reserved.
+// See README.md for licensing information.
+
+use crate::{koopman16, KoopmanError};
+
+/// A payload protected by a trailing big-endian Koopman16 checksum.
+///
+/// # Example
+/// ```rust
+/// use koopman_checksum::frame::Frame16;
+///
+/// let encoded = Frame16::new(b"payload").encode(0xee);
+/// let frame = Frame16::decode(&encoded, 0xee).unwrap();
+/// assert_eq!(frame.payload, b"payload");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Frame16<'a> {
+    /// The protected payload bytes.
+    pub payload: &'a [u8],
+}
+
+impl<'a> Frame16<'a> {
+    /// Size of the trailing checksum in bytes.
+    pub const CHECKSUM_LEN: usize = 2;
+
+    /// Wrap a payload.
+    #[inline]
+    pub fn new(payload: &'a [u8]) -> Self {
+        Self { payload }
+    }
+
+    /// Compute the payload's checksum.
+    #[inline]
+    #[must_use]
+    pub fn checksum(&self, initial_seed: u8) -> u16 {
+        koopman16(self.payload, initial_seed)
+    }
+
+    /// Encode the frame as payload followed by the big-endian checksum.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn encode(&self, initial_seed: u8) -> alloc::vec::Vec<u8> {
+        let mut out = alloc::vec::Vec::with_capacity(self.payload.len() + Self::CHECKSUM_LEN);
+        out.extend_from_slice(self.payload);
+        out.extend_from_slice(&self.checksum(initial_seed).to_be_bytes());
+        out
+    }
+
+    /// Validate an encoded frame and borrow its payload.
+    ///
+    /// # Errors
+    /// - [`KoopmanError::Truncated`] if `bytes` is shorter than the checksum
+    /// - [`KoopmanError::ChecksumMismatch`] if the checksum does not match the payload
+    pub fn decode(bytes: &'a [u8], initial_seed: u8) -> Result<Self, KoopmanError> {
+        if bytes.len() < Self::CHECKSUM_LEN {
+            return Err(KoopmanError::Truncated {
+                len: bytes.len(),
+                min: Self::CHECKSUM_LEN,
+            });
+        }
+
+        let (payload, trailer) = bytes.split_at(bytes.len() - Self::CHECKSUM_LEN);
+        let expected = u16::from_be_bytes([trailer[0], trailer[1]]);
+        let frame = Self { payload };
+        let actual = frame.checksum(initial_seed);
+        if actual != expected {
+            return Err(KoopmanError::ChecksumMismatch {
+                expected: expected as u32,
+                actual: actual as u32,
+            });
+        }
+
+        Ok(frame)
+    }
+}
+
+/// Checksum `frame[..payload_len]` and write the big-endian checksum into
+/// the two bytes that follow it, in place.
+///
+/// For transmit buffers that already reserve room for the trailer; the
+/// result is the same as [`Frame16::encode`] without the extra allocation.
+/// Bytes after `payload_len + 2` are left untouched.
+///
+/// # Errors
+/// [`KoopmanError::Truncated`] if `frame` is shorter than `payload_len + 2`;
+/// the buffer is not modified.
+///
+/// # Example
+/// ```rust
+/// use koopman_checksum::frame::{koopman16_finish_frame, Frame16};
+///
+/// let mut buf = [0u8; 9];
+/// buf[..7].copy_from_slice(b"payload");
+/// koopman16_finish_frame(&mut buf, 7, 0xee).unwrap();
+/// assert_eq!(Frame16::decode(&buf, 0xee).unwrap().payload, b"payload");
+/// ```
+pub fn koopman16_finish_frame(
+    frame: &mut [u8],
+    payload_len: usize,
+    initial_seed: u8,
+) -> Result<(), KoopmanError> {
+    let min = payload_len.saturating_add(Frame16::CHECKSUM_LEN);
+    if frame.len() < min {
+        return Err(KoopmanError::Truncated {
+            len: frame.len(),
+            min,
+        });
+    }
+
+    let (payload, trailer) = frame.split_at_mut(payload_len);
+    trailer[..Frame16::CHECKSUM_LEN]
+        .copy_from_slice(&koopman16(payload, initial_seed).to_be_bytes());
+    Ok(())
+}
+
+/// Width tag of a [`encode_envelope16`] envelope: the checksum width in bits.
+pub const ENVELOPE_TAG16: u8 = 16;
+
+/// Size of the envelope header: tag, seed, and 32-bit payload length.
+pub const ENVELOPE_HEADER_LEN: usize = 6;
+
+/// Wrap `data` in a self-describing envelope protected by Koopman16.
+///
+/// The checksum covers everything before it, header included, so a
+/// corrupted seed or length byte is caught like any payload error.
+///
+/// # Panics
+/// Panics if `data` is longer than `u32::MAX` bytes.
+///
+/// # Example
+/// ```rust
+/// use koopman_checksum::frame::{decode_envelope, encode_envelope16};
+///
+/// let envelope = encode_envelope16(b"payload", 0xee);
+/// assert_eq!(decode_envelope(&envelope).unwrap(), b"payload");
+/// ```
+#[cfg(feature = "alloc")]
+#[must_use]
+#[track_caller]
+pub fn encode_envelope16(data: &[u8], initial_seed: u8) -> alloc::vec::Vec<u8> {
+    let len = u32::try_from(data.len()).expect("envelope payload longer than u32::MAX bytes");
+
+    let mut out = alloc::vec::Vec::with_capacity(
+        ENVELOPE_HEADER_LEN + data.len() + Frame16::CHECKSUM_LEN,
+    );
+    out.push(ENVELOPE_TAG16);
+    out.push(initial_seed);
+    out.extend_from_slice(&len.to_be_bytes());
+    out.extend_from_slice(data);
+    let checksum = koopman16(&out, initial_seed);
+    out.extend_from_slice(&checksum.to_be_bytes());
+    out
+}
+
+/// Validate an envelope produced by [`encode_envelope16`] and return a copy
+/// of its payload.
+///
+/// The width and seed are read from the header; nothing else is needed.
+///
+/// # Errors
+/// - [`KoopmanError::Truncated`] if `bytes` cannot hold a header and checksum
+/// - [`KoopmanError::UnknownTag`] if the width tag is not [`ENVELOPE_TAG16`]
+/// - [`KoopmanError::BadLength`] if `bytes` is not exactly the length the
+///   header declares
+/// - [`KoopmanError::ChecksumMismatch`] if the checksum does not match
+#[cfg(feature = "alloc")]
+pub fn decode_envelope(bytes: &[u8]) -> Result<alloc::vec::Vec<u8>, KoopmanError> {
+    let min = ENVELOPE_HEADER_LEN + Frame16::CHECKSUM_LEN;
+    if bytes.len() < min {
+        return Err(KoopmanError::Truncated {
+            len: bytes.len(),
+            min,
+        });
+    }
+
+    let tag = bytes[0];
+    if tag != ENVELOPE_TAG16 {
+        return Err(KoopmanError::UnknownTag { tag });
+    }
+
+    let seed = bytes[1];
+    let payload_len = u32::from_be_bytes([bytes[2], bytes[3], bytes[4], bytes[5]]);
+    // Saturate on targets where the declared length cannot be a usize
+    let expected = usize::try_from(payload_len)
+        .ok()
+        .and_then(|len| len.checked_add(min))
+        .unwrap_or(usize::MAX);
+    if bytes.len() != expected {
+        return Err(KoopmanError::BadLength {
+            len: bytes.len(),
+            expected,
+        });
+    }
+
+    let (covered, trailer) = bytes.split_at(bytes.len() - Frame16::CHECKSUM_LEN);
+    let stored = u16::from_be_bytes([trailer[0], trailer[1]]);
+    let actual = koopman16(covered, seed);
+    if actual != stored {
+        return Err(KoopmanError::ChecksumMismatch {
+            expected: stored as u32,
+            actual: actual as u32,
+        });
+    }
+
+    Ok(covered[ENVELOPE_HEADER_LEN..].to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_frame16_round_trip() {
+        for payload in [&b""[..], b"x", b"test data", &[0u8; 64]] {
+            let encoded = Frame16::new(payload).encode(0xee);
+            assert_eq!(encoded.len(), payload.len() + 2);
+            assert_eq!(&encoded[..payload.len()], payload);
+
+            let frame = Frame16::decode(&encoded, 0xee).unwrap();
+            assert_eq!(frame.payload, payload);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_frame16_corruption() {
+        let mut encoded = Frame16::new(b"test data").encode(1);
+        encoded[3] ^= 0x10;
+
+        let expected = u16::from_be_bytes([encoded[9], encoded[10]]);
+        assert_eq!(
+            Frame16::decode(&encoded, 1),
+            Err(KoopmanError::ChecksumMismatch {
+                expected: expected as u32,
+                actual: koopman16(&encoded[..9], 1) as u32,
+            })
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_frame16_truncation() {
+        let encoded = Frame16::new(b"test data").encode(1);
+
+        // Dropping trailing bytes misaligns the checksum
+        assert!(matches!(
+            Frame16::decode(&encoded[..encoded.len() - 1], 1),
+            Err(KoopmanError::ChecksumMismatch { .. })
+        ));
+
+        // Too short to hold a checksum at all
+        assert_eq!(
+            Frame16::decode(&encoded[..1], 1),
+            Err(KoopmanError::Truncated { len: 1, min: 2 })
+        );
+        assert_eq!(
+            Frame16::decode(&[], 1),
+            Err(KoopmanError::Truncated { len: 0, min: 2 })
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_finish_frame() {
+        let mut buf = [0xffu8; 12];
+        buf[..9].copy_from_slice(b"test data");
+        koopman16_finish_frame(&mut buf, 9, 0xee).unwrap();
+
+        assert_eq!(
+            &buf[..11],
+            Frame16::new(b"test data").encode(0xee).as_slice()
+        );
+        // Spare capacity past the trailer is untouched
+        assert_eq!(buf[11], 0xff);
+    }
+
+    #[test]
+    fn test_finish_frame_too_short() {
+        let mut buf = *b"test data\0";
+        assert_eq!(
+            koopman16_finish_frame(&mut buf, 9, 0xee),
+            Err(KoopmanError::Truncated { len: 10, min: 11 })
+        );
+        assert_eq!(&buf, b"test data\0");
+
+        assert_eq!(
+            koopman16_finish_frame(&mut [], usize::MAX, 0xee),
+            Err(KoopmanError::Truncated {
+                len: 0,
+                min: usize::MAX
+            })
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_envelope_round_trip() {
+        for payload in [&b""[..], b"x", b"test data", &[0u8; 300]] {
+            for seed in [0u8, 0xee] {
+                let envelope = encode_envelope16(payload, seed);
+                assert_eq!(envelope.len(), payload.len() + 8);
+                assert_eq!(envelope[..2], [ENVELOPE_TAG16, seed]);
+                assert_eq!(envelope[2..6], (payload.len() as u32).to_be_bytes());
+                assert_eq!(decode_envelope(&envelope).unwrap(), payload);
+            }
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_envelope_bad_tag() {
+        let mut envelope = encode_envelope16(b"test data", 0xee);
+        envelope[0] = 32;
+        assert_eq!(
+            decode_envelope(&envelope),
+            Err(KoopmanError::UnknownTag { tag: 32 })
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_envelope_length_mismatch() {
+        let envelope = encode_envelope16(b"test data", 0xee);
+
+        // Declared length no longer matches the bytes present
+        assert_eq!(
+            decode_envelope(&envelope[..envelope.len() - 1]),
+            Err(KoopmanError::BadLength { len: 16, expected: 17 })
+        );
+        let mut extended = envelope.clone();
+        extended.push(0);
+        assert_eq!(
+            decode_envelope(&extended),
+            Err(KoopmanError::BadLength { len: 18, expected: 17 })
+        );
+        let mut corrupted = envelope.clone();
+        corrupted[5] += 1;
+        assert_eq!(
+            decode_envelope(&corrupted),
+            Err(KoopmanError::BadLength { len: 17, expected: 18 })
+        );
+
+        assert_eq!(
+            decode_envelope(&envelope[..7]),
+            Err(KoopmanError::Truncated { len: 7, min: 8 })
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_envelope_checksum_mismatch() {
+        let envelope = encode_envelope16(b"test data", 0xee);
+        let stored = u16::from_be_bytes([envelope[15], envelope[16]]) as u32;
+
+        // A payload error and a seed error are both caught
+        for index in [1, 8] {
+            let mut corrupted = envelope.clone();
+            corrupted[index] ^= 0x01;
+            assert!(matches!(
+                decode_envelope(&corrupted),
+                Err(KoopmanError::ChecksumMismatch { expected, .. }) if expected == stored
+            ));
+        }
+    }
+}
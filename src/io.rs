@@ -0,0 +1,622 @@
+//! `std::io` integration: block-checksummed stream adapters and reader helpers.
+//!
+//! In the block format, a stream is split into blocks of `block_size` payload
+//! bytes, each followed by its big-endian Koopman16 checksum. Only the last
+//! block may be short:
+//!
+//! ```text
+//! [payload (block_size)][checksum (2 bytes, BE)] ... [payload (<= block_size)][checksum]
+//! ```
+
+// Copyright (c) 2025 the koopman-checksum authors, all rights reserved.
+// See README.md for licensing information.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+
+use crate::{koopman16, Koopman16, Koopman32, KoopmanError};
+
+/// Size of each block's trailing checksum in bytes.
+const CHECKSUM_LEN: usize = 2;
+
+/// A [`Write`] adapter that emits each `block_size` bytes written to it as a
+/// checksummed block on the inner writer.
+///
+/// Bytes are buffered until a block fills up. The trailing partial block is
+/// only written by [`finish`](Self::finish); dropping the writer discards it.
+///
+/// # Example
+/// ```rust
+/// use std::io::Write;
+/// use koopman_checksum::{koopman16, io::KoopmanWriter16};
+///
+/// let mut writer = KoopmanWriter16::new(Vec::new(), 4, 0xee);
+/// writer.write_all(b"abcdef").unwrap();
+/// let out = writer.finish().unwrap();
+///
+/// assert_eq!(&out[..4], b"abcd");
+/// assert_eq!(&out[4..6], &koopman16(b"abcd", 0xee).to_be_bytes());
+/// assert_eq!(&out[6..8], b"ef");
+/// assert_eq!(&out[8..], &koopman16(b"ef", 0xee).to_be_bytes());
+/// ```
+#[derive(Debug)]
+pub struct KoopmanWriter16<W: Write> {
+    inner: W,
+    buf: Vec<u8>,
+    block_size: usize,
+    seed: u8,
+}
+
+impl<W: Write> KoopmanWriter16<W> {
+    /// Create a writer emitting blocks of `block_size` payload bytes.
+    ///
+    /// # Panics
+    /// Panics if `block_size` is zero.
+    #[track_caller]
+    pub fn new(inner: W, block_size: usize, initial_seed: u8) -> Self {
+        assert!(block_size > 0, "block_size must be non-zero");
+        Self {
+            inner,
+            buf: Vec::with_capacity(block_size),
+            block_size,
+            seed: initial_seed,
+        }
+    }
+
+    /// Write the trailing partial block, if any, flush, and return the inner writer.
+    pub fn finish(mut self) -> io::Result<W> {
+        if !self.buf.is_empty() {
+            self.emit_block()?;
+        }
+        self.inner.flush()?;
+        Ok(self.inner)
+    }
+
+    fn emit_block(&mut self) -> io::Result<()> {
+        let checksum = koopman16(&self.buf, self.seed);
+        self.inner.write_all(&self.buf)?;
+        self.inner.write_all(&checksum.to_be_bytes())?;
+        self.buf.clear();
+        Ok(())
+    }
+}
+
+impl<W: Write> Write for KoopmanWriter16<W> {
+    fn write(&mut self, data: &[u8]) -> io::Result<usize> {
+        let n = data.len().min(self.block_size - self.buf.len());
+        self.buf.extend_from_slice(&data[..n]);
+        if self.buf.len() == self.block_size {
+            self.emit_block()?;
+        }
+        Ok(n)
+    }
+
+    /// Flush the inner writer.
+    ///
+    /// A buffered partial block is not written, since that would change the
+    /// block boundaries; use [`finish`](KoopmanWriter16::finish) at the end of
+    /// the stream.
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// A [`Read`] adapter that verifies the checksummed blocks produced by
+/// [`KoopmanWriter16`] and yields only their payload bytes.
+///
+/// Each block is read and verified in full before any of its payload is
+/// returned, so corrupted bytes are never handed to the caller.
+///
+/// # Errors
+/// Reads fail with [`io::ErrorKind::InvalidData`] wrapping a
+/// [`KoopmanError::ChecksumMismatch`] if a block fails verification, or a
+/// [`KoopmanError::Truncated`] if the stream ends inside a checksum or a
+/// block has no payload.
+///
+/// # Example
+/// ```rust
+/// use std::io::{Read, Write};
+/// use koopman_checksum::io::{KoopmanReader16, KoopmanWriter16};
+///
+/// let mut writer = KoopmanWriter16::new(Vec::new(), 4, 0xee);
+/// writer.write_all(b"abcdef").unwrap();
+/// let encoded = writer.finish().unwrap();
+///
+/// let mut payload = Vec::new();
+/// KoopmanReader16::new(&encoded[..], 4, 0xee).read_to_end(&mut payload).unwrap();
+/// assert_eq!(payload, b"abcdef");
+/// ```
+#[derive(Debug)]
+pub struct KoopmanReader16<R: Read> {
+    inner: R,
+    buf: Vec<u8>,
+    pos: usize,
+    end: usize,
+    seed: u8,
+}
+
+impl<R: Read> KoopmanReader16<R> {
+    /// Create a reader for blocks of `block_size` payload bytes.
+    ///
+    /// # Panics
+    /// Panics if `block_size` is zero.
+    #[track_caller]
+    pub fn new(inner: R, block_size: usize, initial_seed: u8) -> Self {
+        assert!(block_size > 0, "block_size must be non-zero");
+        Self {
+            inner,
+            buf: vec![0; block_size + CHECKSUM_LEN],
+            pos: 0,
+            end: 0,
+            seed: initial_seed,
+        }
+    }
+
+    /// Return the inner reader, discarding any buffered payload.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Read and verify the next block. Returns `false` at a clean end of stream.
+    fn fill_block(&mut self) -> io::Result<bool> {
+        let mut filled = 0;
+        while filled < self.buf.len() {
+            match self.inner.read(&mut self.buf[filled..]) {
+                Ok(0) => break,
+                Ok(n) => filled += n,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+
+        if filled == 0 {
+            return Ok(false);
+        }
+        // The writer never emits an empty block, and an empty payload's
+        // checksum is 0, so a bare `[0, 0]` would otherwise verify
+        if filled <= CHECKSUM_LEN {
+            return Err(invalid_data(KoopmanError::Truncated {
+                len: filled,
+                min: CHECKSUM_LEN + 1,
+            }));
+        }
+
+        let (payload, trailer) = self.buf[..filled].split_at(filled - CHECKSUM_LEN);
+        let expected = u16::from_be_bytes([trailer[0], trailer[1]]);
+        let actual = koopman16(payload, self.seed);
+        if actual != expected {
+            return Err(invalid_data(KoopmanError::ChecksumMismatch {
+                expected: expected as u32,
+                actual: actual as u32,
+            }));
+        }
+
+        self.pos = 0;
+        self.end = payload.len();
+        Ok(true)
+    }
+}
+
+impl<R: Read> Read for KoopmanReader16<R> {
+    fn read(&mut self, out: &mut [u8]) -> io::Result<usize> {
+        if out.is_empty() {
+            return Ok(0);
+        }
+        while self.pos == self.end {
+            if !self.fill_block()? {
+                return Ok(0);
+            }
+        }
+
+        let n = out.len().min(self.end - self.pos);
+        out[..n].copy_from_slice(&self.buf[self.pos..self.pos + n]);
+        self.pos += n;
+        Ok(n)
+    }
+}
+
+/// Compute a 32-bit Koopman checksum of everything read from `reader`,
+/// reporting progress after each chunk.
+///
+/// Reads in chunks of up to `chunk` bytes and calls `on_progress` with the
+/// cumulative byte count after each one. Interrupted reads are retried.
+///
+/// # Returns
+/// The checksum and the total number of bytes read
+///
+/// # Panics
+/// Panics if `chunk` is zero.
+///
+/// # Example
+/// ```rust
+/// use std::io::Cursor;
+/// use koopman_checksum::{koopman32, io::koopman32_read_with_progress};
+///
+/// let data = vec![0xa5u8; 10_000];
+/// let mut last = 0;
+/// let (checksum, total) =
+///     koopman32_read_with_progress(Cursor::new(&data), 0xee, 4096, |n| last = n).unwrap();
+/// assert_eq!((checksum, total, last), (koopman32(&data, 0xee), 10_000, 10_000));
+/// ```
+#[track_caller]
+pub fn koopman32_read_with_progress<R: Read>(
+    mut reader: R,
+    initial_seed: u8,
+    chunk: usize,
+    mut on_progress: impl FnMut(u64),
+) -> io::Result<(u32, u64)> {
+    assert!(chunk > 0, "chunk must be non-zero");
+
+    let mut hasher = Koopman32::with_seed(initial_seed);
+    let mut buf = vec![0u8; chunk];
+    let mut total = 0u64;
+    loop {
+        let n = match reader.read(&mut buf) {
+            Ok(0) => break,
+            Ok(n) => n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+        hasher.update(&buf[..n]);
+        total += n as u64;
+        on_progress(total);
+    }
+
+    Ok((hasher.finalize(), total))
+}
+
+/// Verify a 32-bit Koopman checksum over everything read from `reader`.
+///
+/// The streaming counterpart of [`verify32`](crate::verify32) for inputs too
+/// large to hold as one slice, such as multi-gigabyte files: data is read in
+/// 64 KiB chunks and folded into a [`Koopman32`], so memory use is bounded
+/// regardless of input size. Interrupted reads are retried.
+///
+/// # Returns
+/// `Ok(true)` if the checksum of the stream matches `expected`
+///
+/// # Errors
+/// Returns the first read error other than [`io::ErrorKind::Interrupted`].
+///
+/// # Example
+/// ```rust
+/// use std::io::Cursor;
+/// use koopman_checksum::{koopman32, io::verify32_streaming};
+///
+/// let data = vec![0xa5u8; 100_000];
+/// let checksum = koopman32(&data, 0xee);
+/// assert!(verify32_streaming(Cursor::new(&data), checksum, 0xee).unwrap());
+/// assert!(!verify32_streaming(Cursor::new(&data), checksum ^ 1, 0xee).unwrap());
+/// ```
+pub fn verify32_streaming<R: Read>(reader: R, expected: u32, initial_seed: u8) -> io::Result<bool> {
+    let (checksum, _) = koopman32_read_with_progress(reader, initial_seed, 64 * 1024, |_| {})?;
+    Ok(checksum == expected)
+}
+
+impl Koopman16 {
+    /// Fold everything read from a trait-object reader into the hasher.
+    ///
+    /// Takes `&mut dyn Read` so boxed or otherwise type-erased sources can be
+    /// passed directly. Reads until EOF through an 8 KiB stack buffer;
+    /// interrupted reads are retried. On error, bytes read before the failure
+    /// have already been folded in.
+    ///
+    /// # Returns
+    /// The number of bytes read
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::io::{Cursor, Read};
+    /// use koopman_checksum::{koopman16, Koopman16};
+    ///
+    /// let mut source: Box<dyn Read> = Box::new(Cursor::new(b"test data"));
+    /// let mut hasher = Koopman16::with_seed(0xee);
+    /// assert_eq!(hasher.update_from_dyn_read(&mut *source).unwrap(), 9);
+    /// assert_eq!(hasher.finalize(), koopman16(b"test data", 0xee));
+    /// ```
+    pub fn update_from_dyn_read(&mut self, r: &mut dyn Read) -> io::Result<u64> {
+        let mut buf = [0u8; 8192];
+        let mut total = 0u64;
+        loop {
+            let n = match r.read(&mut buf) {
+                Ok(0) => return Ok(total),
+                Ok(n) => n,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            };
+            self.update(&buf[..n]);
+            total += n as u64;
+        }
+    }
+
+    /// Fold everything read from an async reader into the hasher.
+    ///
+    /// The async counterpart of [`update_from_dyn_read`](Self::update_from_dyn_read):
+    /// reads until EOF through an 8 KiB buffer held in the future, retrying
+    /// interrupted reads. Only available with the `tokio` feature.
+    ///
+    /// # Cancellation safety
+    /// Each chunk is folded in the same poll that completes its read, so if
+    /// the future is dropped at an `.await` the hasher holds exactly the bytes
+    /// consumed from `r` so far; [`bytes_processed`](Self::bytes_processed)
+    /// says how many. Calling this again resumes the stream where it stopped.
+    ///
+    /// # Returns
+    /// The number of bytes read by this call
+    ///
+    /// # Example
+    /// ```rust
+    /// use koopman_checksum::{koopman16, Koopman16};
+    ///
+    /// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+    /// let mut source: &[u8] = b"test data";
+    /// let mut hasher = Koopman16::with_seed(0xee);
+    /// assert_eq!(hasher.update_from_async_read(&mut source).await.unwrap(), 9);
+    /// assert_eq!(hasher.finalize(), koopman16(b"test data", 0xee));
+    /// # });
+    /// ```
+    #[cfg(feature = "tokio")]
+    pub async fn update_from_async_read<R>(&mut self, r: &mut R) -> io::Result<u64>
+    where
+        R: tokio::io::AsyncRead + Unpin,
+    {
+        use tokio::io::AsyncReadExt;
+
+        let mut buf = [0u8; 8192];
+        let mut total = 0u64;
+        loop {
+            let n = match r.read(&mut buf).await {
+                Ok(0) => return Ok(total),
+                Ok(n) => n,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            };
+            self.update(&buf[..n]);
+            total += n as u64;
+        }
+    }
+}
+
+/// Compute the Koopman32 checksum of every regular file under `root`.
+///
+/// Walks the directory tree recursively with [`std::fs::read_dir`] and reads
+/// each file in 64 KiB chunks. Keys are paths relative to `root`. Symbolic
+/// links, to files or directories, are skipped so the walk cannot cycle;
+/// other non-regular entries are skipped too.
+///
+/// # Errors
+/// Any error from listing a directory or reading a file; the walk stops at
+/// the first one.
+///
+/// # Example
+/// ```rust,no_run
+/// use koopman_checksum::io::koopman32_tree;
+///
+/// for (path, checksum) in koopman32_tree("assets", 0xee).unwrap() {
+///     println!("{checksum:08x}  {}", path.display());
+/// }
+/// ```
+pub fn koopman32_tree(
+    root: impl AsRef<Path>,
+    initial_seed: u8,
+) -> io::Result<BTreeMap<PathBuf, u32>> {
+    fn walk(
+        root: &Path,
+        dir: &Path,
+        initial_seed: u8,
+        out: &mut BTreeMap<PathBuf, u32>,
+    ) -> io::Result<()> {
+        for entry in fs::read_dir(dir)? {
+            let entry = entry?;
+            // `DirEntry::file_type` does not follow symlinks
+            let file_type = entry.file_type()?;
+            let path = entry.path();
+            if file_type.is_dir() {
+                walk(root, &path, initial_seed, out)?;
+            } else if file_type.is_file() {
+                let (checksum, _) =
+                    koopman32_read_with_progress(fs::File::open(&path)?, initial_seed, 64 * 1024, |_| {})?;
+                let relative = path.strip_prefix(root).unwrap_or(&path).to_path_buf();
+                out.insert(relative, checksum);
+            }
+        }
+        Ok(())
+    }
+
+    let root = root.as_ref();
+    let mut out = BTreeMap::new();
+    walk(root, root, initial_seed, &mut out)?;
+    Ok(out)
+}
+
+fn invalid_data(err: KoopmanError) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, err)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::verify16;
+
+    fn write_blocks(data: &[u8], block_size: usize, seed: u8) -> Vec<u8> {
+        let mut writer = KoopmanWriter16::new(Vec::new(), block_size, seed);
+        // Uneven write sizes so writes straddle block boundaries
+        for chunk in data.chunks(7) {
+            writer.write_all(chunk).unwrap();
+        }
+        writer.finish().unwrap()
+    }
+
+    #[test]
+    fn test_writer16_round_trip() {
+        let data: Vec<u8> = (0..100u32).map(|i| (i * 37 + 5) as u8).collect();
+
+        for block_size in [1, 16, 50, 100, 128] {
+            let out = write_blocks(&data, block_size, 0xee);
+
+            let mut payload = Vec::new();
+            for block in out.chunks(block_size + 2) {
+                let (body, trailer) = block.split_at(block.len() - 2);
+                let checksum = u16::from_be_bytes([trailer[0], trailer[1]]);
+                assert!(verify16(body, checksum, 0xee));
+                payload.extend_from_slice(body);
+            }
+            assert_eq!(payload, data, "block_size = {}", block_size);
+        }
+    }
+
+    #[test]
+    fn test_reader16_round_trip() {
+        let data: Vec<u8> = (0..100u32).map(|i| (i * 37 + 5) as u8).collect();
+
+        for block_size in [1, 16, 50, 100, 128] {
+            let encoded = write_blocks(&data, block_size, 0xee);
+
+            let mut reader = KoopmanReader16::new(&encoded[..], block_size, 0xee);
+            let mut payload = Vec::new();
+            // Small reads so they straddle block boundaries
+            let mut buf = [0u8; 5];
+            loop {
+                let n = reader.read(&mut buf).unwrap();
+                if n == 0 {
+                    break;
+                }
+                payload.extend_from_slice(&buf[..n]);
+            }
+            assert_eq!(payload, data, "block_size = {}", block_size);
+        }
+    }
+
+    #[test]
+    fn test_reader16_bit_flip() {
+        let data = [0x5au8; 40];
+        let mut encoded = write_blocks(&data, 16, 0xee);
+        // Corrupt the second block's payload
+        encoded[20] ^= 0x04;
+
+        let mut reader = KoopmanReader16::new(&encoded[..], 16, 0xee);
+        let mut first = [0u8; 16];
+        reader.read_exact(&mut first).unwrap();
+        assert_eq!(first, [0x5a; 16]);
+
+        let err = reader.read(&mut [0u8; 16]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        let inner = err.get_ref().unwrap().downcast_ref::<KoopmanError>().unwrap();
+        assert!(matches!(inner, KoopmanError::ChecksumMismatch { .. }));
+    }
+
+    #[test]
+    fn test_reader16_truncated_checksum() {
+        let encoded = write_blocks(b"abcd", 4, 0xee);
+        // Drop the whole last block except one byte of payload
+        let mut reader = KoopmanReader16::new(&encoded[..encoded.len() - 5], 4, 0xee);
+        let err = reader.read_to_end(&mut Vec::new()).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn test_reader16_rejects_empty_block() {
+        let mut encoded = write_blocks(b"abcd", 4, 0xee);
+        encoded.extend_from_slice(&[0, 0]);
+
+        let mut reader = KoopmanReader16::new(&encoded[..], 4, 0xee);
+        let mut first = [0u8; 4];
+        reader.read_exact(&mut first).unwrap();
+        assert_eq!(&first, b"abcd");
+
+        let err = reader.read(&mut [0u8; 4]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        let inner = err.get_ref().unwrap().downcast_ref::<KoopmanError>().unwrap();
+        assert_eq!(*inner, KoopmanError::Truncated { len: 2, min: 3 });
+    }
+
+    #[test]
+    fn test_koopman32_read_with_progress() {
+        use crate::koopman32;
+        use std::io::Cursor;
+
+        let data: Vec<u8> = (0..10_000u32).map(|i| (i * 131 + 7) as u8).collect();
+        let mut progress = Vec::new();
+        let (checksum, total) =
+            koopman32_read_with_progress(Cursor::new(&data), 0xee, 4096, |n| progress.push(n)).unwrap();
+
+        assert_eq!(checksum, koopman32(&data, 0xee));
+        assert_eq!(total, 10_000);
+        assert_eq!(progress, [4096, 8192, 10_000]);
+
+        // Empty input: no progress, checksum 0
+        let mut calls = 0;
+        let result = koopman32_read_with_progress(Cursor::new(&[]), 0xee, 16, |_| calls += 1).unwrap();
+        assert_eq!((result, calls), ((0, 0), 0));
+    }
+
+    #[test]
+    fn test_verify32_streaming() {
+        use crate::koopman32;
+        use std::io::Cursor;
+
+        // Several chunks plus a partial one
+        let data: Vec<u8> = (0..5_000_003u32).map(|i| (i * 131 + 7) as u8).collect();
+        let checksum = koopman32(&data, 0xee);
+        assert!(verify32_streaming(Cursor::new(&data), checksum, 0xee).unwrap());
+        assert!(!verify32_streaming(Cursor::new(&data), checksum ^ 1, 0xee).unwrap());
+        assert!(!verify32_streaming(Cursor::new(&data[1..]), checksum, 0xee).unwrap());
+
+        assert!(verify32_streaming(Cursor::new(&[]), 0, 0xee).unwrap());
+    }
+
+    #[test]
+    fn test_writer16_empty_stream() {
+        assert!(write_blocks(&[], 8, 0xee).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "block_size must be non-zero")]
+    fn test_writer16_zero_block_size() {
+        let _ = KoopmanWriter16::new(Vec::new(), 0, 0xee);
+    }
+
+    #[test]
+    fn test_koopman16_update_from_dyn_read() {
+        use std::io::Cursor;
+
+        let data: Vec<u8> = (0..20_000u32).map(|i| (i * 131 + 7) as u8).collect();
+        let mut cursor = Cursor::new(&data[..]);
+        let reader: &mut dyn Read = &mut cursor;
+
+        let mut hasher = Koopman16::with_seed(0xee);
+        hasher.update(b"prefix");
+        assert_eq!(hasher.update_from_dyn_read(reader).unwrap(), 20_000);
+        assert_eq!(hasher.finalize(), koopman16(&[&b"prefix"[..], &data].concat(), 0xee));
+    }
+
+    #[cfg(feature = "tokio")]
+    #[test]
+    fn test_koopman16_update_from_async_read() {
+        use tokio::io::AsyncWriteExt;
+
+        let data: Vec<u8> = (0..20_000u32).map(|i| (i * 131 + 7) as u8).collect();
+        // Small duplex buffer so the reader sees many partial reads
+        let (mut tx, mut rx) = tokio::io::duplex(333);
+
+        let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+        let (read, hasher) = runtime.block_on(async {
+            let writer = async {
+                tx.write_all(&data).await.unwrap();
+                drop(tx);
+            };
+            let reader = async {
+                let mut hasher = Koopman16::with_seed(0xee);
+                let read = hasher.update_from_async_read(&mut rx).await.unwrap();
+                (read, hasher)
+            };
+            tokio::join!(writer, reader).1
+        });
+
+        assert_eq!(read, 20_000);
+        assert_eq!(hasher.bytes_processed(), 20_000);
+        assert_eq!(hasher.finalize(), koopman16(&data, 0xee));
+    }
+}
@@ -4,8 +4,26 @@
 // Copyright (c) 2025 the koopman-checksum authors, all rights reserved.
 // See README.md for licensing information.
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 use core::num::{NonZeroU32, NonZeroU64};
 
+pub mod algorithm;
+#[cfg(feature = "std")]
+pub mod analysis;
+pub mod frame;
+#[cfg(feature = "std")]
+pub mod io;
+#[cfg(feature = "std")]
+pub mod pool;
+#[cfg(feature = "simd")]
+mod simd;
+#[cfg(feature = "test-util")]
+pub mod test_util;
+mod traits;
+pub mod typestate;
+
 // ============================================================================
 // Constants
 // ============================================================================
@@ -34,7 +52,43 @@ pub const MODULUS_15P: u32 = 32749;
 /// Detects all 1-bit, 2-bit, and 3-bit errors for data up to 134,217,720 bytes.
 pub const MODULUS_31P: u64 = 2147483629;
 
+/// Conventional non-zero seed, as used throughout the examples.
+///
+/// A non-zero seed makes leading zero bytes affect the checksum. The streaming
+/// hashers' `new` and `Default` still use seed 0; see
+/// [`Koopman16::default_seeded`] for a constructor using this value.
+pub const DEFAULT_SEED: u8 = 0xee;
+
+/// Maximum data length in bytes for which [`koopman8`] detects all 1-bit and 2-bit errors.
+pub const KOOPMAN8_MAX_LEN: usize = 13;
+
+/// Maximum data length in bytes for which [`koopman16`] detects all 1-bit and 2-bit errors.
+pub const KOOPMAN16_MAX_LEN: usize = 4092;
+
+/// Maximum data length in bytes for which [`koopman32`] detects all 1-bit and 2-bit errors.
+pub const KOOPMAN32_MAX_LEN: usize = 134_217_720;
+
+/// Maximum data length in bytes for which [`koopman8p`] detects all 1-bit, 2-bit, and 3-bit errors.
+pub const KOOPMAN8P_MAX_LEN: usize = 5;
+
+/// Maximum data length in bytes for which [`koopman16p`] detects all 1-bit, 2-bit, and 3-bit errors.
+pub const KOOPMAN16P_MAX_LEN: usize = 2044;
+
+/// Maximum data length in bytes for which [`koopman32p`] detects all 1-bit, 2-bit, and 3-bit errors.
+pub const KOOPMAN32P_MAX_LEN: usize = 134_217_720;
+
+/// Largest modulus whose residues fit the 7-bit checksum portion of Koopman8P.
+const MAX_MODULUS_7P: u32 = 127;
+
+/// Largest modulus whose residues fit the 15-bit checksum portion of Koopman16P.
+const MAX_MODULUS_15P: u32 = 32767;
+
+/// Largest modulus whose residues fit the 31-bit checksum portion of Koopman32P.
+const MAX_MODULUS_31P: u64 = (1 << 31) - 1;
+
 const NONZERO_MODULUS_8: NonZeroU32 = NonZeroU32::new(MODULUS_8).unwrap();
+const NONZERO_MODULUS_16: NonZeroU32 = NonZeroU32::new(MODULUS_16).unwrap();
+const NONZERO_MODULUS_32: NonZeroU64 = NonZeroU64::new(MODULUS_32).unwrap();
 const NONZERO_MODULUS_7P: NonZeroU32 = NonZeroU32::new(MODULUS_7P).unwrap();
 const NONZERO_MODULUS_15P: NonZeroU32 = NonZeroU32::new(MODULUS_15P).unwrap();
 const NONZERO_MODULUS_31P: NonZeroU64 = NonZeroU64::new(MODULUS_31P).unwrap();
@@ -43,15 +97,71 @@ const NONZERO_MODULUS_31P: NonZeroU64 = NonZeroU64::new(MODULUS_31P).unwrap();
 // Fast Modular Reduction
 //
 // The moduli are of the form 2^k - c where c is small:
+// - 253 = 2^8 - 3
 // - 65519 = 2^16 - 17
 // - 4294967291 = 2^32 - 5
 //
 // This allows fast reduction: x % (2^k - c) ≡ (x >> k) * c + (x & (2^k - 1))
 // ============================================================================
 
+/// Final correction step of the fast reductions: subtract `m` once if `r >= m`.
+///
+/// With the `branchless` feature this subtracts a computed multiple of `m`
+/// instead of branching, so adversarial inputs cannot cause mispredictions.
+/// Both forms produce identical results.
+#[cfg_attr(not(feature = "small"), inline(always))]
+fn correct_u32(r: u32, m: u32) -> u32 {
+    if cfg!(feature = "branchless") {
+        correct_u32_branchless(r, m)
+    } else if r >= m {
+        r - m
+    } else {
+        r
+    }
+}
+
+#[cfg_attr(not(feature = "small"), inline(always))]
+fn correct_u32_branchless(r: u32, m: u32) -> u32 {
+    r - (r >= m) as u32 * m
+}
+
+/// 64-bit form of [`correct_u32`].
+#[cfg_attr(not(feature = "small"), inline(always))]
+fn correct_u64(r: u64, m: u64) -> u64 {
+    if cfg!(feature = "branchless") {
+        correct_u64_branchless(r, m)
+    } else if r >= m {
+        r - m
+    } else {
+        r
+    }
+}
+
+#[cfg_attr(not(feature = "small"), inline(always))]
+fn correct_u64_branchless(r: u64, m: u64) -> u64 {
+    r - (r >= m) as u64 * m
+}
+
+/// Fast reduction for modulus 253 = 2^8 - 3
+/// Input: x < 2^16, which covers (MODULUS_8 - 1) << 8 + 0xFF = 65023
+#[cfg_attr(not(feature = "small"), inline(always))]
+fn fast_mod_253(x: u32) -> u32 {
+    // First reduction: x = hi * 2^8 + lo, result = hi * 3 + lo
+    let hi: u32 = x >> 8;
+    let lo: u32 = x & 0xFF;
+    let r: u32 = hi * 3 + lo;
+    // r <= 3 * 255 + 255 = 1020
+    // Second reduction
+    let hi2: u32 = r >> 8;
+    let lo2: u32 = r & 0xFF;
+    let r2: u32 = hi2 * 3 + lo2;
+    // r2 <= 3 * 3 + 255 = 264
+    correct_u32(r2, MODULUS_8)
+}
+
 /// Fast reduction for modulus 65519 = 2^16 - 17
 /// Input: x up to (MODULUS_16 - 1) << 16 + 0xFFFF ~= 4_293_918_719 (remains < 2^32)
-#[inline(always)]
+#[cfg_attr(not(feature = "small"), inline(always))]
 fn fast_mod_65519(x: u32) -> u32 {
     // First reduction: x = hi * 2^16 + lo, result = hi * 17 + lo
     let hi: u32 = x >> 16;
@@ -63,19 +173,135 @@ fn fast_mod_65519(x: u32) -> u32 {
     let lo2: u32 = r & 0xFFFF;
     let r2: u32 = hi2 * 17 + lo2;
     // r2 < 17 * 2 + 65536 = 65570
-    if r2 >= MODULUS_16 { r2 - MODULUS_16 } else { r2 }
+    correct_u32(r2, MODULUS_16)
+}
+
+/// Fold eight bytes at a time into a Koopman16 running sum, one reduction each
+///
+/// Load the eight bytes as a big-endian `u64` split into 16-bit limbs
+/// a, b, c, d. With 2^16 ≡ 17, 2^32 ≡ 289, 2^48 ≡ 4913 and 2^64 ≡ 18002
+/// (mod 65519), (sum << 64) + w ≡ 18002 * sum + 4913 * a + 289 * b + 17 * c + d,
+/// which stays below 1.53e9 < 2^32 for sum < MODULUS_16. Returns the new sum
+/// and the unprocessed tail of fewer than eight bytes.
+#[cfg_attr(not(feature = "small"), inline(always))]
+fn fold8_65519<'a>(
+    mut sum: u32,
+    data: &'a [u8],
+    reductions: &mut Reductions,
+) -> (u32, &'a [u8]) {
+    let mut chunks = data.chunks_exact(8);
+    for chunk in &mut chunks {
+        let w = u64::from_be_bytes(chunk.try_into().unwrap());
+        let limb = |shift: u32| ((w >> shift) & 0xFFFF) as u32;
+        sum = fast_mod_65519(
+            sum * 18002 + limb(48) * 4913 + limb(32) * 289 + limb(16) * 17 + limb(0),
+        );
+        reductions.add(1);
+    }
+    (sum, chunks.remainder())
+}
+
+/// Wide fold for the Koopman16 stream: 64-byte SIMD chunks first when the
+/// `simd` feature is enabled and the CPU supports it, then eight-byte folds.
+#[cfg_attr(not(feature = "small"), inline(always))]
+fn fold_wide_65519<'a>(
+    sum: u32,
+    data: &'a [u8],
+    reductions: &mut Reductions,
+) -> (u32, &'a [u8]) {
+    #[cfg(feature = "simd")]
+    let (sum, data) = simd::fold64_65519(sum, data, reductions);
+    fold8_65519(sum, data, reductions)
+}
+
+/// Tally of modular reductions performed by a streaming update.
+///
+/// Counts only with the `profiling` feature; otherwise it is zero-sized and
+/// [`add`](Self::add) compiles to nothing, so the fast paths can carry it
+/// unconditionally.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct Reductions {
+    #[cfg(feature = "profiling")]
+    count: usize,
+}
+
+impl Reductions {
+    #[inline(always)]
+    pub(crate) fn add(&mut self, _n: usize) {
+        #[cfg(feature = "profiling")]
+        {
+            self.count += _n;
+        }
+    }
+}
+
+/// Fast reduction for modulus 32749 = 2^15 - 19
+/// Input: x < 2^31, which covers two bytes folded before reducing:
+/// (MODULUS_15P - 1) << 16 + 0xFFFF = 2_146_238_463
+#[cfg_attr(not(feature = "small"), inline(always))]
+fn fast_mod_32749(x: u32) -> u32 {
+    // First reduction: x = hi * 2^15 + lo, result = hi * 19 + lo
+    let hi: u32 = x >> 15;
+    let lo: u32 = x & 0x7FFF;
+    let r: u32 = hi * 19 + lo;
+    // r < 19 * 2^16 + 2^15 = 1_277_952
+    // Second reduction
+    let hi2: u32 = r >> 15;
+    let lo2: u32 = r & 0x7FFF;
+    let r2: u32 = hi2 * 19 + lo2;
+    // r2 < 19 * 39 + 2^15 = 33_509 < 2 * MODULUS_15P
+    correct_u32(r2, MODULUS_15P)
 }
 
 /// Fast reduction for modulus 4294967291 = 2^32 - 5
-/// Input: x < 2^40 (after shift+add)
-#[inline(always)]
+/// Input: x < 2^48, which covers both byte (sum << 8) and word (sum << 16) folds
+#[cfg_attr(not(feature = "small"), inline(always))]
 fn fast_mod_4294967291(x: u64) -> u64 {
     // x = hi * 2^32 + lo, result = hi * 5 + lo
     let hi: u64 = x >> 32;
     let lo: u64 = x & 0xFFFFFFFF;
     let r: u64 = hi * 5 + lo;
-    // r < 5 * 2^8 + 2^32, need one check
-    if r >= MODULUS_32 { r - MODULUS_32 } else { r }
+    // r < 5 * 2^16 + 2^32 < 2 * MODULUS_32, need one check
+    correct_u64(r, MODULUS_32)
+}
+
+/// Fold eight bytes at a time into a Koopman32 running sum, one reduction each
+///
+/// Since 2^32 ≡ 5 (mod 4294967291), folding a big-endian word `w` is
+/// (sum << 64) + w ≡ 25 * sum + 5 * w_hi + w_lo, which stays below
+/// 31 * 2^32 < 2^48 for sum < MODULUS_32. Returns the new sum and the
+/// unprocessed tail of fewer than eight bytes.
+#[cfg_attr(not(feature = "small"), inline(always))]
+fn fold8_4294967291<'a>(
+    mut sum: u64,
+    data: &'a [u8],
+    reductions: &mut Reductions,
+) -> (u64, &'a [u8]) {
+    let mut chunks = data.chunks_exact(8);
+    for chunk in &mut chunks {
+        let w = u64::from_be_bytes(chunk.try_into().unwrap());
+        sum = fast_mod_4294967291(sum * 25 + (w >> 32) * 5 + (w & 0xFFFF_FFFF));
+        reductions.add(1);
+    }
+    (sum, chunks.remainder())
+}
+
+/// Fast reduction for modulus 2147483629 = 2^31 - 19
+/// Input: x < 2^63, which covers four bytes folded before reducing:
+/// (MODULUS_31P - 1) << 32 + 0xFFFF_FFFF
+#[cfg_attr(not(feature = "small"), inline(always))]
+fn fast_mod_2147483629(x: u64) -> u64 {
+    // First reduction: x = hi * 2^31 + lo, result = hi * 19 + lo
+    let hi: u64 = x >> 31;
+    let lo: u64 = x & 0x7FFF_FFFF;
+    let r: u64 = hi * 19 + lo;
+    // r < 19 * 2^32 + 2^31 < 2^37
+    // Second reduction
+    let hi2: u64 = r >> 31;
+    let lo2: u64 = r & 0x7FFF_FFFF;
+    let r2: u64 = hi2 * 19 + lo2;
+    // r2 < 19 * 64 + 2^31 < 2 * MODULUS_31P
+    correct_u64(r2, MODULUS_31P)
 }
 
 /// Compute an 8-bit Koopman checksum.
@@ -96,10 +322,28 @@ fn fast_mod_4294967291(x: u64) -> u64 {
 /// let checksum = koopman8(b"test data", 0xee);
 /// assert_eq!(koopman8(&[], 0xee), 0); // Empty data returns 0
 /// ```
-#[inline]
+#[cfg_attr(not(feature = "small"), inline)]
 #[must_use]
 pub fn koopman8(data: &[u8], initial_seed: u8) -> u8 {
-    koopman8_with_modulus(data, initial_seed, NONZERO_MODULUS_8)
+    if cfg!(feature = "small") {
+        return koopman8_with_modulus(data, initial_seed, NONZERO_MODULUS_8);
+    }
+
+    let [first, rest @ ..] = data else {
+        return 0;
+    };
+
+    let mut sum: u32 = (first ^ initial_seed) as u32;
+
+    // Use fast modular reduction for the default modulus
+    for &byte in rest {
+        sum = fast_mod_253((sum << 8) + byte as u32);
+    }
+
+    // Append implicit zero byte
+    sum = fast_mod_253(sum << 8);
+
+    sum as u8
 }
 
 /// Compute an 8-bit Koopman checksum with a custom modulus.
@@ -120,17 +364,17 @@ pub fn koopman8(data: &[u8], initial_seed: u8) -> u8 {
 /// let modulus = NonZeroU32::new(239).unwrap();
 /// let checksum = koopman8_with_modulus(b"test", 0xee, modulus);
 /// ```
-#[inline]
+#[cfg_attr(not(feature = "small"), inline)]
 #[must_use]
 pub fn koopman8_with_modulus(data: &[u8], initial_seed: u8, modulus: NonZeroU32) -> u8 {
-    if data.is_empty() {
+    let [first, rest @ ..] = data else {
         return 0;
-    }
+    };
 
     let modulus = modulus.get();
-    let mut sum: u32 = (data[0] ^ initial_seed) as u32;
+    let mut sum: u32 = (first ^ initial_seed) as u32;
 
-    for &byte in &data[1..] {
+    for &byte in rest {
         sum = ((sum << 8) + byte as u32) % modulus;
     }
 
@@ -158,19 +402,23 @@ pub fn koopman8_with_modulus(data: &[u8], initial_seed: u8, modulus: NonZeroU32)
 /// let checksum = koopman16(b"test data", 0xee);
 /// assert_eq!(koopman16(&[], 0xee), 0); // Empty data returns 0
 /// ```
-#[inline]
+#[cfg_attr(not(feature = "small"), inline)]
 #[must_use]
 pub fn koopman16(data: &[u8], initial_seed: u8) -> u16 {
-    if data.is_empty() {
-        return 0;
+    if cfg!(feature = "small") {
+        return koopman16_with_modulus(data, initial_seed, NONZERO_MODULUS_16);
     }
 
-    let mut sum: u64 = (data[0] ^ initial_seed) as u64;
+    let [first, rest @ ..] = data else {
+        return 0;
+    };
+
+    let mut sum: u64 = (first ^ initial_seed) as u64;
 
     // Process bytes with delayed modulo reduction every 2 bytes
     // This reduces the number of modulo operations by half
     let mut count = 0;
-    for &byte in &data[1..] {
+    for &byte in rest {
         sum = (sum << 8) + byte as u64;
         count += 1;
         if count == 2 {
@@ -209,17 +457,17 @@ pub fn koopman16(data: &[u8], initial_seed: u8) -> u16 {
 /// let modulus = NonZeroU32::new(65519).unwrap();
 /// let checksum = koopman16_with_modulus(b"test", 0xee, modulus);
 /// ```
-#[inline]
+#[cfg_attr(not(feature = "small"), inline)]
 #[must_use]
 pub fn koopman16_with_modulus(data: &[u8], initial_seed: u8, modulus: NonZeroU32) -> u16 {
-    if data.is_empty() {
+    let [first, rest @ ..] = data else {
         return 0;
-    }
+    };
 
     let modulus = modulus.get();
-    let mut sum: u32 = (data[0] ^ initial_seed) as u32;
+    let mut sum: u32 = (first ^ initial_seed) as u32;
 
-    for &byte in &data[1..] {
+    for &byte in rest {
         sum = ((sum << 8) + byte as u32) % modulus;
     }
 
@@ -248,17 +496,22 @@ pub fn koopman16_with_modulus(data: &[u8], initial_seed: u8, modulus: NonZeroU32
 /// let checksum = koopman32(b"test data", 0xee);
 /// assert_eq!(koopman32(&[], 0xee), 0); // Empty data returns 0
 /// ```
-#[inline]
+#[cfg_attr(not(feature = "small"), inline)]
 #[must_use]
 pub fn koopman32(data: &[u8], initial_seed: u8) -> u32 {
-    if data.is_empty() {
-        return 0;
+    if cfg!(feature = "small") {
+        return koopman32_with_modulus(data, initial_seed, NONZERO_MODULUS_32);
     }
 
-    let mut sum: u64 = (data[0] ^ initial_seed) as u64;
+    let [first, rest @ ..] = data else {
+        return 0;
+    };
+
+    let sum: u64 = (first ^ initial_seed) as u64;
 
-    // Use fast modular reduction for the default modulus
-    for &byte in &data[1..] {
+    // Use fast modular reduction for the default modulus, eight bytes at a time
+    let (mut sum, rest) = fold8_4294967291(sum, rest, &mut Reductions::default());
+    for &byte in rest {
         sum = fast_mod_4294967291((sum << 8) + byte as u64);
     }
 
@@ -289,17 +542,17 @@ pub fn koopman32(data: &[u8], initial_seed: u8) -> u32 {
 /// let modulus = NonZeroU64::new(4294967291).unwrap();
 /// let checksum = koopman32_with_modulus(b"test", 0xee, modulus);
 /// ```
-#[inline]
+#[cfg_attr(not(feature = "small"), inline)]
 #[must_use]
 pub fn koopman32_with_modulus(data: &[u8], initial_seed: u8, modulus: NonZeroU64) -> u32 {
-    if data.is_empty() {
+    let [first, rest @ ..] = data else {
         return 0;
-    }
+    };
 
     let modulus = modulus.get();
-    let mut sum: u64 = (data[0] ^ initial_seed) as u64;
+    let mut sum: u64 = (first ^ initial_seed) as u64;
 
-    for &byte in &data[1..] {
+    for &byte in rest {
         sum = ((sum << 8) + byte as u64) % modulus;
     }
 
@@ -342,7 +595,7 @@ fn parity8(x: u8) -> u8 {
 /// let parity_bit = checksum & 1;
 /// let checksum_bits = checksum >> 1;
 /// ```
-#[inline]
+#[cfg_attr(not(feature = "small"), inline)]
 #[must_use]
 pub fn koopman8p(data: &[u8], initial_seed: u8) -> u8 {
     koopman8p_with_modulus(data, initial_seed, NONZERO_MODULUS_7P)
@@ -354,6 +607,7 @@ pub fn koopman8p(data: &[u8], initial_seed: u8) -> u8 {
 /// * `data` - The data bytes to checksum
 /// * `initial_seed` - Initial seed value
 /// * `modulus` - The modulus for the 7-bit checksum. Must be non-zero and <= 127.
+///   Debug builds assert this bound; use [`koopman8p_with_modulus_checked`] to reject it in all builds.
 ///
 /// # Returns
 /// 8-bit value: 7-bit checksum in upper bits, parity in LSB, or 0 if data is empty
@@ -366,18 +620,25 @@ pub fn koopman8p(data: &[u8], initial_seed: u8) -> u8 {
 /// let modulus = NonZeroU32::new(125).unwrap();
 /// let checksum = koopman8p_with_modulus(b"test", 0xee, modulus);
 /// ```
-#[inline]
+#[cfg_attr(not(feature = "small"), inline)]
 #[must_use]
+#[track_caller]
 pub fn koopman8p_with_modulus(data: &[u8], initial_seed: u8, modulus: NonZeroU32) -> u8 {
-    if data.is_empty() {
+    // Checked before the empty-input return so a bad modulus fails on any input
+    debug_assert!(
+        modulus.get() <= MAX_MODULUS_7P,
+        "modulus exceeds the 7-bit checksum portion of the parity packing"
+    );
+
+    let [first, rest @ ..] = data else {
         return 0;
-    }
+    };
 
     let modulus = modulus.get();
-    let mut sum: u32 = (data[0] ^ initial_seed) as u32;
+    let mut sum: u32 = (first ^ initial_seed) as u32;
     let mut psum: u8 = sum as u8;
 
-    for &byte in &data[1..] {
+    for &byte in rest {
         sum = ((sum << 8) + byte as u32) % modulus;
         psum ^= byte;
     }
@@ -410,10 +671,37 @@ pub fn koopman8p_with_modulus(data: &[u8], initial_seed: u8, modulus: NonZeroU32
 /// let parity_bit = checksum & 1;
 /// let checksum_bits = checksum >> 1;
 /// ```
-#[inline]
+#[cfg_attr(not(feature = "small"), inline)]
 #[must_use]
 pub fn koopman16p(data: &[u8], initial_seed: u8) -> u16 {
-    koopman16p_with_modulus(data, initial_seed, NONZERO_MODULUS_15P)
+    if cfg!(feature = "small") {
+        return koopman16p_with_modulus(data, initial_seed, NONZERO_MODULUS_15P);
+    }
+
+    let [first, rest @ ..] = data else {
+        return 0;
+    };
+
+    let mut sum: u32 = (first ^ initial_seed) as u32;
+    let mut psum: u8 = sum as u8;
+
+    // Fold two bytes per reduction; sum < 2^15 leaves room for 16 more bits
+    let mut pairs = rest.chunks_exact(2);
+    for pair in &mut pairs {
+        sum = fast_mod_32749((sum << 16) + ((pair[0] as u32) << 8) + pair[1] as u32);
+        psum ^= pair[0] ^ pair[1];
+    }
+    for &byte in pairs.remainder() {
+        sum = fast_mod_32749((sum << 8) + byte as u32);
+        psum ^= byte;
+    }
+
+    // Append two implicit zero bytes
+    sum = fast_mod_32749(sum << 8);
+    sum = fast_mod_32749(sum << 8);
+
+    // Pack: checksum in upper 15 bits, parity in LSB
+    ((sum as u16) << 1) | (parity8(psum) as u16)
 }
 
 /// Compute a 16-bit Koopman checksum with parity using a custom modulus.
@@ -422,6 +710,7 @@ pub fn koopman16p(data: &[u8], initial_seed: u8) -> u16 {
 /// * `data` - The data bytes to checksum
 /// * `initial_seed` - Initial seed value
 /// * `modulus` - The modulus for the 15-bit checksum. Must be non-zero and ≤ 32767.
+///   Debug builds assert this bound; use [`koopman16p_with_modulus_checked`] to reject it in all builds.
 ///
 /// # Returns
 /// 16-bit value: 15-bit checksum in upper bits, parity in LSB, or 0 if data is empty
@@ -434,18 +723,25 @@ pub fn koopman16p(data: &[u8], initial_seed: u8) -> u16 {
 /// let modulus = NonZeroU32::new(32749).unwrap();
 /// let checksum = koopman16p_with_modulus(b"test", 0xee, modulus);
 /// ```
-#[inline]
+#[cfg_attr(not(feature = "small"), inline)]
 #[must_use]
+#[track_caller]
 pub fn koopman16p_with_modulus(data: &[u8], initial_seed: u8, modulus: NonZeroU32) -> u16 {
-    if data.is_empty() {
+    // Checked before the empty-input return so a bad modulus fails on any input
+    debug_assert!(
+        modulus.get() <= MAX_MODULUS_15P,
+        "modulus exceeds the 15-bit checksum portion of the parity packing"
+    );
+
+    let [first, rest @ ..] = data else {
         return 0;
-    }
+    };
 
     let modulus = modulus.get();
-    let mut sum: u32 = (data[0] ^ initial_seed) as u32;
+    let mut sum: u32 = (first ^ initial_seed) as u32;
     let mut psum: u8 = sum as u8;
 
-    for &byte in &data[1..] {
+    for &byte in rest {
         sum = ((sum << 8) + byte as u32) % modulus;
         psum ^= byte;
     }
@@ -479,10 +775,40 @@ pub fn koopman16p_with_modulus(data: &[u8], initial_seed: u8, modulus: NonZeroU3
 /// let parity_bit = checksum & 1;
 /// let checksum_bits = checksum >> 1;
 /// ```
-#[inline]
+#[cfg_attr(not(feature = "small"), inline)]
 #[must_use]
 pub fn koopman32p(data: &[u8], initial_seed: u8) -> u32 {
-    koopman32p_with_modulus(data, initial_seed, NONZERO_MODULUS_31P)
+    if cfg!(feature = "small") {
+        return koopman32p_with_modulus(data, initial_seed, NONZERO_MODULUS_31P);
+    }
+
+    let [first, rest @ ..] = data else {
+        return 0;
+    };
+
+    let mut sum: u64 = (first ^ initial_seed) as u64;
+    let mut psum: u8 = sum as u8;
+
+    // Fold four bytes per reduction; sum < 2^31 leaves room for 32 more bits
+    let mut quads = rest.chunks_exact(4);
+    for quad in &mut quads {
+        let word = u32::from_be_bytes([quad[0], quad[1], quad[2], quad[3]]);
+        sum = fast_mod_2147483629((sum << 32) + word as u64);
+        psum ^= quad[0] ^ quad[1] ^ quad[2] ^ quad[3];
+    }
+    for &byte in quads.remainder() {
+        sum = fast_mod_2147483629((sum << 8) + byte as u64);
+        psum ^= byte;
+    }
+
+    // Append four implicit zero bytes, one byte per reduction
+    sum = fast_mod_2147483629(sum << 8);
+    sum = fast_mod_2147483629(sum << 8);
+    sum = fast_mod_2147483629(sum << 8);
+    sum = fast_mod_2147483629(sum << 8);
+
+    // Pack: checksum in upper 31 bits, parity in LSB
+    ((sum as u32) << 1) | (parity8(psum) as u32)
 }
 
 /// Compute a 32-bit Koopman checksum with parity using a custom modulus.
@@ -491,6 +817,7 @@ pub fn koopman32p(data: &[u8], initial_seed: u8) -> u32 {
 /// * `data` - The data bytes to checksum
 /// * `initial_seed` - Initial seed value
 /// * `modulus` - The modulus for the 31-bit checksum. Must be non-zero and <= 2^31-1.
+///   Debug builds assert this bound; use [`koopman32p_with_modulus_checked`] to reject it in all builds.
 ///
 /// # Returns
 /// 32-bit value: 31-bit checksum in upper bits, parity in LSB, or 0 if data is empty
@@ -503,18 +830,25 @@ pub fn koopman32p(data: &[u8], initial_seed: u8) -> u32 {
 /// let modulus = NonZeroU64::new(2147483629).unwrap();
 /// let checksum = koopman32p_with_modulus(b"test", 0xee, modulus);
 /// ```
-#[inline]
+#[cfg_attr(not(feature = "small"), inline)]
 #[must_use]
+#[track_caller]
 pub fn koopman32p_with_modulus(data: &[u8], initial_seed: u8, modulus: NonZeroU64) -> u32 {
-    if data.is_empty() {
+    // Checked before the empty-input return so a bad modulus fails on any input
+    debug_assert!(
+        modulus.get() <= MAX_MODULUS_31P,
+        "modulus exceeds the 31-bit checksum portion of the parity packing"
+    );
+
+    let [first, rest @ ..] = data else {
         return 0;
-    }
+    };
 
     let modulus = modulus.get();
-    let mut sum: u64 = (data[0] ^ initial_seed) as u64;
+    let mut sum: u64 = (first ^ initial_seed) as u64;
     let mut psum: u8 = sum as u8;
 
-    for &byte in &data[1..] {
+    for &byte in rest {
         sum = ((sum << 8) + byte as u64) % modulus;
         psum ^= byte;
     }
@@ -531,1051 +865,6244 @@ pub fn koopman32p_with_modulus(data: &[u8], initial_seed: u8, modulus: NonZeroU6
 }
 
 // ============================================================================
-// Streaming/Incremental API
+// Generic Input API
+//
+// Thin wrappers accepting any byte container (`Vec<u8>`, `[u8; N]`, `&str`,
+// `Box<[u8]>`, ...). The `&[u8]` functions above remain the primitives.
 // ============================================================================
 
-/// Macro to generate streaming checksum structs.
-/// This reduces code duplication across Koopman8, Koopman16, Koopman32.
-macro_rules! impl_streaming_hasher {
-    (
-        $name:ident,
-        $sum_type:ty,
-        $output_type:ty,
-        $default_modulus_raw:expr,
-        $nonzero_type:ty,
-        $finalize_shifts:expr,
-        $fast_mod:expr
-    ) => {
-        impl Default for $name {
-            fn default() -> Self {
-                Self::new()
-            }
-        }
+/// Compute an 8-bit Koopman checksum over any byte container.
+///
+/// Equivalent to [`koopman8`] called with `data.as_ref()`.
+///
+/// # Example
+/// ```rust
+/// use koopman_checksum::{koopman8, koopman8_of};
+///
+/// let data = vec![1u8, 2, 3];
+/// assert_eq!(koopman8_of(&data, 0xee), koopman8(&data, 0xee));
+/// ```
+#[cfg_attr(not(feature = "small"), inline)]
+#[must_use]
+pub fn koopman8_of(data: impl AsRef<[u8]>, initial_seed: u8) -> u8 {
+    koopman8(data.as_ref(), initial_seed)
+}
+
+/// Compute a 16-bit Koopman checksum over any byte container.
+///
+/// Equivalent to [`koopman16`] called with `data.as_ref()`.
+///
+/// # Example
+/// ```rust
+/// use koopman_checksum::{koopman16, koopman16_of};
+///
+/// assert_eq!(koopman16_of("test data", 0xee), koopman16(b"test data", 0xee));
+/// ```
+#[cfg_attr(not(feature = "small"), inline)]
+#[must_use]
+pub fn koopman16_of(data: impl AsRef<[u8]>, initial_seed: u8) -> u16 {
+    koopman16(data.as_ref(), initial_seed)
+}
+
+/// Compute a 32-bit Koopman checksum over any byte container.
+///
+/// Equivalent to [`koopman32`] called with `data.as_ref()`.
+///
+/// # Example
+/// ```rust
+/// use koopman_checksum::{koopman32, koopman32_of};
+///
+/// assert_eq!(koopman32_of([1u8, 2, 3], 0xee), koopman32(&[1, 2, 3], 0xee));
+/// ```
+#[cfg_attr(not(feature = "small"), inline)]
+#[must_use]
+pub fn koopman32_of(data: impl AsRef<[u8]>, initial_seed: u8) -> u32 {
+    koopman32(data.as_ref(), initial_seed)
+}
+
+/// Compute an 8-bit Koopman checksum with parity over any byte container.
+///
+/// Equivalent to [`koopman8p`] called with `data.as_ref()`.
+#[cfg_attr(not(feature = "small"), inline)]
+#[must_use]
+pub fn koopman8p_of(data: impl AsRef<[u8]>, initial_seed: u8) -> u8 {
+    koopman8p(data.as_ref(), initial_seed)
+}
+
+/// Compute a 16-bit Koopman checksum with parity over any byte container.
+///
+/// Equivalent to [`koopman16p`] called with `data.as_ref()`.
+#[cfg_attr(not(feature = "small"), inline)]
+#[must_use]
+pub fn koopman16p_of(data: impl AsRef<[u8]>, initial_seed: u8) -> u16 {
+    koopman16p(data.as_ref(), initial_seed)
+}
+
+/// Compute a 32-bit Koopman checksum with parity over any byte container.
+///
+/// Equivalent to [`koopman32p`] called with `data.as_ref()`.
+#[cfg_attr(not(feature = "small"), inline)]
+#[must_use]
+pub fn koopman32p_of(data: impl AsRef<[u8]>, initial_seed: u8) -> u32 {
+    koopman32p(data.as_ref(), initial_seed)
+}
+
+// ============================================================================
+// Typed Checksums
+// ============================================================================
+
+macro_rules! checksum_newtype {
+    ($name:ident, $raw:ty, $bits:expr) => {
+        #[doc = concat!("A ", stringify!($bits), "-bit checksum value.")]
+        ///
+        /// Ordered numerically, so values can be sorted or used as `BTreeMap` keys.
+        #[doc = concat!("Layout-identical to `", stringify!($raw), "`, so it can cross FFI boundaries;")]
+        /// with the `bytemuck` feature, slices can be cast to and from bytes in
+        /// native endianness.
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[repr(transparent)]
+        pub struct $name(pub $raw);
+
+        // SAFETY: `#[repr(transparent)]` over a primitive integer, for which
+        // the all-zero bit pattern is valid.
+        #[cfg(feature = "bytemuck")]
+        unsafe impl bytemuck::Zeroable for $name {}
+
+        // SAFETY: `#[repr(transparent)]` over a primitive integer: no padding,
+        // every bit pattern is valid, and the type is `Copy + 'static`.
+        #[cfg(feature = "bytemuck")]
+        unsafe impl bytemuck::Pod for $name {}
 
         impl $name {
-            /// Create a new hasher with the default modulus.
+            /// The raw checksum value.
             #[inline]
-            pub fn new() -> Self {
-                Self {
-                    sum: 0,
-                    modulus: $default_modulus_raw,
-                    seed: 0,
-                    initialized: false,
-                    use_fast_mod: true,
-                }
+            #[must_use]
+            pub const fn get(self) -> $raw {
+                self.0
             }
 
-            /// Create a new hasher with a custom modulus.
-            ///
-            /// # Arguments
-            /// * `modulus` - The modulus to use. Must be non-zero.
-            ///
-            /// # Example
-            /// ```rust
-            #[doc = concat!("use std::num::", stringify!($nonzero_type), ";")]
-            #[doc = concat!("use koopman_checksum::{", stringify!($name), ", ", stringify!($default_modulus_raw), "};")]
-            ///
-            #[doc = concat!("let modulus = ", stringify!($nonzero_type), "::new(", stringify!($default_modulus_raw), ").unwrap();")]
-            #[doc = concat!("let hasher = ", stringify!($name), "::with_modulus(modulus);")]
-            /// ```
+            /// The checksum as big-endian bytes, in wire order.
             #[inline]
-            pub fn with_modulus(modulus: $nonzero_type) -> Self {
-                let modulus_val = modulus.get();
-                Self {
-                    sum: 0,
-                    modulus: modulus_val,
-                    seed: 0,
-                    initialized: false,
-                    use_fast_mod: modulus_val == $default_modulus_raw,
-                }
+            #[must_use]
+            pub const fn to_be_bytes(self) -> [u8; core::mem::size_of::<$raw>()] {
+                self.0.to_be_bytes()
             }
 
-            /// Create a new hasher with an initial seed.
-            ///
-            /// # Example
-            /// ```rust
-            #[doc = concat!("use koopman_checksum::", stringify!($name), ";")]
-            ///
-            #[doc = concat!("let hasher = ", stringify!($name), "::with_seed(0xee);")]
-            /// ```
+            /// Parse a checksum from big-endian bytes.
             #[inline]
-            pub fn with_seed(seed: u8) -> Self {
-                Self {
-                    sum: seed as $sum_type,
-                    modulus: $default_modulus_raw,
-                    seed: seed as $sum_type,
-                    initialized: false,
-                    use_fast_mod: true,
+            #[must_use]
+            pub const fn from_be_bytes(bytes: [u8; core::mem::size_of::<$raw>()]) -> Self {
+                Self(<$raw>::from_be_bytes(bytes))
+            }
+        }
+
+        /// Parse big-endian bytes read from a wire buffer.
+        ///
+        /// # Errors
+        /// [`KoopmanError::BadLength`] unless the slice is exactly the checksum width.
+        impl TryFrom<&[u8]> for $name {
+            type Error = KoopmanError;
+
+            fn try_from(bytes: &[u8]) -> Result<Self, KoopmanError> {
+                match bytes.try_into() {
+                    Ok(array) => Ok(Self::from_be_bytes(array)),
+                    Err(_) => Err(KoopmanError::BadLength {
+                        len: bytes.len(),
+                        expected: core::mem::size_of::<$raw>(),
+                    }),
                 }
             }
+        }
 
-            /// Update the checksum with more data.
+        impl From<$raw> for $name {
             #[inline]
-            pub fn update(&mut self, data: &[u8]) {
-                if data.is_empty() {
-                    return;
-                }
+            fn from(value: $raw) -> Self {
+                Self(value)
+            }
+        }
 
-                let mut iter = data.iter();
-
-                if !self.initialized {
-                    if let Some(&first) = iter.next() {
-                        self.sum ^= first as $sum_type;
-                        self.initialized = true;
-                    }
-                }
-
-                if self.use_fast_mod {
-                    for &byte in iter {
-                        self.sum = $fast_mod((self.sum << 8) + byte as $sum_type);
-                    }
-                } else {
-                    for &byte in iter {
-                        self.sum = ((self.sum << 8) + byte as $sum_type) % self.modulus;
-                    }
-                }
+        impl From<$name> for $raw {
+            #[inline]
+            fn from(value: $name) -> Self {
+                value.0
             }
+        }
 
-            /// Finalize and return the checksum.
-            ///
-            /// Returns 0 if no data was provided.
+        /// Compare against a raw checksum, e.g. `typed == 0x1234u16`.
+        ///
+        /// Only the exact raw type is supported, so unsuffixed integer
+        /// literals need a suffix to pick it.
+        impl PartialEq<$raw> for $name {
             #[inline]
-            #[must_use]
-            pub fn finalize(self) -> $output_type {
-                if !self.initialized {
-                    return 0;
-                }
-                let mut sum = self.sum;
-                if self.use_fast_mod {
-                    for _ in 0..$finalize_shifts {
-                        sum = $fast_mod(sum << 8);
-                    }
-                } else {
-                    for _ in 0..$finalize_shifts {
-                        sum = (sum << 8) % self.modulus;
-                    }
-                }
-                sum as $output_type
+            fn eq(&self, other: &$raw) -> bool {
+                self.0 == *other
             }
+        }
 
-            /// Reset the hasher to initial state.
+        impl PartialEq<$name> for $raw {
             #[inline]
-            pub fn reset(&mut self) {
-                self.sum = self.seed;
-                self.initialized = false;
+            fn eq(&self, other: &$name) -> bool {
+                *self == other.0
             }
         }
     };
 }
 
-/// Incremental Koopman8 checksum calculator.
-///
-/// Allows computing checksums over data that arrives in chunks.
+checksum_newtype!(Checksum8, u8, 8);
+checksum_newtype!(Checksum16, u16, 16);
+checksum_newtype!(Checksum32, u32, 32);
+
+/// Compute an 8-bit Koopman checksum as a [`Checksum8`].
+#[inline]
+#[must_use]
+pub fn koopman8_typed(data: &[u8], initial_seed: u8) -> Checksum8 {
+    Checksum8(koopman8(data, initial_seed))
+}
+
+/// Compute a 16-bit Koopman checksum as a [`Checksum16`].
 ///
 /// # Example
 /// ```rust
-/// use koopman_checksum::Koopman8;
+/// use std::collections::BTreeMap;
+/// use koopman_checksum::{koopman16, koopman16_typed, Checksum16};
 ///
-/// let mut hasher = Koopman8::new();
-/// hasher.update(b"Hello, ");
-/// hasher.update(b"World!");
-/// let checksum = hasher.finalize();
+/// let mut index: BTreeMap<Checksum16, &str> = BTreeMap::new();
+/// index.insert(koopman16_typed(b"record", 0xee), "record");
+/// assert_eq!(index.keys().next().unwrap().get(), koopman16(b"record", 0xee));
 /// ```
-#[derive(Clone, Debug)]
-pub struct Koopman8 {
-    sum: u32,
-    modulus: u32,
-    seed: u32,
-    initialized: bool,
-    use_fast_mod: bool,
+#[inline]
+#[must_use]
+pub fn koopman16_typed(data: &[u8], initial_seed: u8) -> Checksum16 {
+    Checksum16(koopman16(data, initial_seed))
 }
 
-// Koopman8 doesn't have a fast_mod, so we use a passthrough
-#[inline(always)]
-fn identity_mod_8(x: u32) -> u32 { x % MODULUS_8 }
+/// Compute a 32-bit Koopman checksum as a [`Checksum32`].
+#[inline]
+#[must_use]
+pub fn koopman32_typed(data: &[u8], initial_seed: u8) -> Checksum32 {
+    Checksum32(koopman32(data, initial_seed))
+}
 
-impl_streaming_hasher!(
-    Koopman8, u32, u8,
-    MODULUS_8, NonZeroU32,
-    1, identity_mod_8
-);
+// ============================================================================
+// Runtime Width Selection
+// ============================================================================
 
-/// Incremental Koopman16 checksum calculator.
+/// Checksum width, for protocols that negotiate it at runtime.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Width {
+    /// 8-bit checksum ([`koopman8`]).
+    Bits8,
+    /// 16-bit checksum ([`koopman16`]).
+    Bits16,
+    /// 32-bit checksum ([`koopman32`]).
+    Bits32,
+}
+
+impl Width {
+    /// Number of bits in the checksum.
+    #[inline]
+    #[must_use]
+    pub const fn bits(self) -> u32 {
+        match self {
+            Width::Bits8 => 8,
+            Width::Bits16 => 16,
+            Width::Bits32 => 32,
+        }
+    }
+}
+
+/// Compute a Koopman checksum of the given width, zero-extended to `u64`.
 ///
-/// Allows computing checksums over data that arrives in chunks.
-/// Uses fast modular reduction when using the default modulus.
+/// Dispatches to [`koopman8`], [`koopman16`], or [`koopman32`].
 ///
 /// # Example
 /// ```rust
-/// use koopman_checksum::Koopman16;
+/// use koopman_checksum::{koopman16, koopman_dyn, Width};
 ///
-/// let mut hasher = Koopman16::new();
-/// hasher.update(b"Hello, ");
-/// hasher.update(b"World!");
-/// let checksum = hasher.finalize();
+/// let width = Width::Bits16; // e.g. negotiated with a peer
+/// assert_eq!(koopman_dyn(width, b"test", 0xee), koopman16(b"test", 0xee) as u64);
 /// ```
-#[derive(Clone, Debug)]
-pub struct Koopman16 {
-    sum: u32,
-    modulus: u32,
-    seed: u32,
-    initialized: bool,
-    use_fast_mod: bool,
+#[inline]
+#[must_use]
+pub fn koopman_dyn(width: Width, data: &[u8], initial_seed: u8) -> u64 {
+    match width {
+        Width::Bits8 => koopman8(data, initial_seed) as u64,
+        Width::Bits16 => koopman16(data, initial_seed) as u64,
+        Width::Bits32 => koopman32(data, initial_seed) as u64,
+    }
 }
 
-impl_streaming_hasher!(
-    Koopman16, u32, u16,
-    MODULUS_16, NonZeroU32,
-    2, fast_mod_65519
-);
+/// Recommended moduli and the data length in bytes up to which each detects
+/// all 1-bit and 2-bit errors, sorted by ascending modulus.
+///
+/// 239 is covered for 14 bytes: 119 is the smallest `d` with
+/// `2^d ≡ ±1 (mod 239)`, so no two-bit error within 14 bytes (112 bits)
+/// cancels. The same criterion gives the documented 13 bytes for 253.
+const MODULI_8: &[(u64, usize)] = &[(239, 14), (MODULUS_8 as u64, KOOPMAN8_MAX_LEN)];
+const MODULI_16: &[(u64, usize)] = &[(MODULUS_16 as u64, KOOPMAN16_MAX_LEN)];
+const MODULI_32: &[(u64, usize)] = &[(MODULUS_32, KOOPMAN32_MAX_LEN)];
+
+/// Parity variants, with the lengths up to which all 1-3 bit errors are detected.
+const MODULI_8P: &[(u64, usize)] = &[(MODULUS_7P as u64, KOOPMAN8P_MAX_LEN)];
+const MODULI_16P: &[(u64, usize)] = &[(MODULUS_15P as u64, KOOPMAN16P_MAX_LEN)];
+const MODULI_32P: &[(u64, usize)] = &[(MODULUS_31P, KOOPMAN32P_MAX_LEN)];
+
+/// HD-safe length for `modulus` from a table of recommended moduli, or 0 if
+/// the modulus is not tabulated and no guarantee is known.
+fn tabulated_max_len(table: &[(u64, usize)], modulus: u64) -> usize {
+    table
+        .iter()
+        .find(|&&(m, _)| m == modulus)
+        .map_or(0, |&(_, max_len)| max_len)
+}
 
-/// Incremental Koopman32 checksum calculator.
+/// Smallest recommended modulus that detects all 1-bit and 2-bit errors at `len` bytes.
 ///
-/// Allows computing checksums over data that arrives in chunks.
-/// Uses fast modular reduction when using the default modulus.
+/// Looks up a table of recommended moduli for the width. For 8-bit checksums
+/// 239 covers up to 14 bytes, one more than the default 253; for 16 and 32 bits
+/// only the defaults are tabulated.
+///
+/// # Returns
+/// The modulus, or `None` if no tabulated modulus covers `len`
 ///
 /// # Example
 /// ```rust
-/// use koopman_checksum::Koopman32;
+/// use koopman_checksum::{min_modulus_for_length, Width, MODULUS_16};
 ///
-/// let mut hasher = Koopman32::new();
-/// hasher.update(b"Hello, ");
-/// hasher.update(b"World!");
-/// let checksum = hasher.finalize();
+/// assert_eq!(min_modulus_for_length(Width::Bits8, 14), Some(239));
+/// assert_eq!(min_modulus_for_length(Width::Bits16, 1500), Some(MODULUS_16 as u64));
+/// assert_eq!(min_modulus_for_length(Width::Bits16, 5000), None);
 /// ```
-#[derive(Clone, Debug)]
-pub struct Koopman32 {
-    sum: u64,
-    modulus: u64,
-    seed: u64,
-    initialized: bool,
-    use_fast_mod: bool,
+#[must_use]
+pub fn min_modulus_for_length(width: Width, len: usize) -> Option<u64> {
+    let table = match width {
+        Width::Bits8 => MODULI_8,
+        Width::Bits16 => MODULI_16,
+        Width::Bits32 => MODULI_32,
+    };
+    table
+        .iter()
+        .find(|&&(_, max_len)| len <= max_len)
+        .map(|&(modulus, _)| modulus)
 }
 
-impl_streaming_hasher!(
-    Koopman32, u64, u32,
-    MODULUS_32, NonZeroU64,
-    4, fast_mod_4294967291
-);
-
 // ============================================================================
-// Parity Streaming API
+// Word Slices
 // ============================================================================
 
-/// Macro to generate streaming parity checksum structs.
-macro_rules! impl_streaming_parity_hasher {
-    (
-        $name:ident,
-        $sum_type:ty,
-        $output_type:ty,
-        $default_modulus_raw:expr,
-        $nonzero_type:ty,
-        $finalize_shifts:expr
-    ) => {
-        impl Default for $name {
-            fn default() -> Self {
-                Self::new()
-            }
-        }
+/// Byte order used to serialize words before folding them into a checksum.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Endian {
+    /// Most significant byte first.
+    Big,
+    /// Least significant byte first.
+    Little,
+}
 
-        impl $name {
-            /// Create a new hasher with the default modulus.
-            #[inline]
-            pub fn new() -> Self {
-                Self {
-                    sum: 0,
-                    psum: 0,
-                    modulus: $default_modulus_raw,
-                    seed: 0,
-                    initialized: false,
-                }
-            }
+/// Compute a 32-bit Koopman checksum over a slice of `u32` words.
+///
+/// Each word is folded as four bytes in the given byte order, so the result
+/// equals [`koopman32`] over the flattened byte representation. Bytes are
+/// extracted with shifts rather than indexed, so there are no per-byte bounds
+/// checks and no intermediate byte buffer.
+///
+/// # Arguments
+/// * `words` - The words to checksum
+/// * `initial_seed` - Initial seed value, applied to the first byte
+/// * `endian` - Byte order of each word in the checksummed stream
+///
+/// # Returns
+/// 32-bit checksum value, or 0 if `words` is empty
+///
+/// # Example
+/// ```rust
+/// use koopman_checksum::{koopman32, koopman32_u32_slice, Endian};
+///
+/// let words = [0x0102_0304u32, 0x0506_0708];
+/// assert_eq!(
+///     koopman32_u32_slice(&words, 0xee, Endian::Big),
+///     koopman32(&[1, 2, 3, 4, 5, 6, 7, 8], 0xee),
+/// );
+/// ```
+#[cfg_attr(not(feature = "small"), inline)]
+#[must_use]
+pub fn koopman32_u32_slice(words: &[u32], initial_seed: u8, endian: Endian) -> u32 {
+    let Some((&first, rest)) = words.split_first() else {
+        return 0;
+    };
 
-            /// Create a new hasher with a custom modulus.
-            ///
-            /// # Arguments
-            /// * `modulus` - The modulus to use. Must be non-zero.
-            #[inline]
-            pub fn with_modulus(modulus: $nonzero_type) -> Self {
-                Self {
-                    sum: 0,
-                    psum: 0,
-                    modulus: modulus.get(),
-                    seed: 0,
-                    initialized: false,
-                }
-            }
+    // Normalize so the first byte in stream order is the most significant
+    let to_stream = |word: u32| match endian {
+        Endian::Big => word,
+        Endian::Little => word.swap_bytes(),
+    };
 
-            /// Create a new hasher with an initial seed.
-            #[inline]
-            pub fn with_seed(seed: u8) -> Self {
-                Self {
-                    sum: seed as $sum_type,
-                    psum: seed,
-                    modulus: $default_modulus_raw,
-                    seed: seed as $sum_type,
-                    initialized: false,
-                }
-            }
+    // The generic `%` with the `small` feature, as in `koopman32`
+    let reduce = |x: u64| {
+        if cfg!(feature = "small") {
+            x % MODULUS_32
+        } else {
+            fast_mod_4294967291(x)
+        }
+    };
 
-            /// Update the checksum with more data.
-            #[inline]
-            pub fn update(&mut self, data: &[u8]) {
-                if data.is_empty() {
-                    return;
-                }
+    let first = to_stream(first);
+    let mut sum: u64 = ((first >> 24) as u8 ^ initial_seed) as u64;
+    sum = reduce((sum << 8) + ((first >> 16) & 0xFF) as u64);
+    sum = reduce((sum << 8) + ((first >> 8) & 0xFF) as u64);
+    sum = reduce((sum << 8) + (first & 0xFF) as u64);
+
+    for &word in rest {
+        let word = to_stream(word);
+        sum = reduce((sum << 8) + (word >> 24) as u64);
+        sum = reduce((sum << 8) + ((word >> 16) & 0xFF) as u64);
+        sum = reduce((sum << 8) + ((word >> 8) & 0xFF) as u64);
+        sum = reduce((sum << 8) + (word & 0xFF) as u64);
+    }
 
-                let mut iter = data.iter();
+    // Append four implicit zero bytes
+    sum = reduce(sum << 8);
+    sum = reduce(sum << 8);
+    sum = reduce(sum << 8);
+    sum = reduce(sum << 8);
 
-                if !self.initialized {
-                    if let Some(&first) = iter.next() {
-                        self.sum ^= first as $sum_type;
-                        self.psum ^= first;
-                        self.initialized = true;
-                    }
-                }
+    sum as u32
+}
 
-                for &byte in iter {
-                    self.sum = ((self.sum << 8) + byte as $sum_type) % self.modulus;
-                    self.psum ^= byte;
-                }
-            }
+/// Compute a 32-bit Koopman checksum folding 16-bit words.
+///
+/// Each step computes `sum = ((sum << 16) + word) % modulus`, one reduction
+/// per two bytes instead of one per byte. Because `(sum << 16) + (hi << 8) + lo`
+/// is congruent to folding `hi` then `lo` byte by byte, this is not a distinct
+/// checksum: the result equals [`koopman32`] over the words serialized in the
+/// given byte order, with the seed applied to the first serialized byte. Word
+/// folding is purely a throughput optimization.
+///
+/// # Arguments
+/// * `words` - The words to checksum
+/// * `initial_seed` - Initial seed value, applied to the first byte in stream order
+/// * `endian` - Byte order of each word in the checksummed stream
+///
+/// # Returns
+/// 32-bit checksum value, or 0 if `words` is empty
+///
+/// # Example
+/// ```rust
+/// use koopman_checksum::{koopman32, koopman32_word16, Endian};
+///
+/// let words = [0x0102u16, 0x0304];
+/// assert_eq!(
+///     koopman32_word16(&words, 0xee, Endian::Little),
+///     koopman32(&[2, 1, 4, 3], 0xee),
+/// );
+/// ```
+#[cfg_attr(not(feature = "small"), inline)]
+#[must_use]
+pub fn koopman32_word16(words: &[u16], initial_seed: u8, endian: Endian) -> u32 {
+    let Some((&first, rest)) = words.split_first() else {
+        return 0;
+    };
 
-            /// Finalize and return the checksum with parity.
-            ///
-            /// Returns 0 if no data was provided.
-            #[inline]
-            #[must_use]
-            pub fn finalize(self) -> $output_type {
-                if !self.initialized {
-                    return 0;
-                }
-                let mut sum = self.sum;
-                for _ in 0..$finalize_shifts {
-                    sum = (sum << 8) % self.modulus;
-                }
-                // Pack: checksum in upper bits, parity in LSB
-                ((sum as $output_type) << 1) | (parity8(self.psum) as $output_type)
-            }
+    // Normalize so the first byte in stream order is the most significant
+    let to_stream = |word: u16| match endian {
+        Endian::Big => word,
+        Endian::Little => word.swap_bytes(),
+    };
 
-            /// Reset the hasher to initial state.
-            #[inline]
-            pub fn reset(&mut self) {
-                self.sum = self.seed;
-                self.psum = self.seed as u8;
-                self.initialized = false;
-            }
+    // The generic `%` with the `small` feature, as in `koopman32`
+    let reduce = |x: u64| {
+        if cfg!(feature = "small") {
+            x % MODULUS_32
+        } else {
+            fast_mod_4294967291(x)
         }
     };
+
+    let mut sum: u64 = (to_stream(first) ^ ((initial_seed as u16) << 8)) as u64;
+    for &word in rest {
+        sum = reduce((sum << 16) + to_stream(word) as u64);
+    }
+
+    // Append four implicit zero bytes as two zero words
+    sum = reduce(sum << 16);
+    sum = reduce(sum << 16);
+
+    sum as u32
 }
 
-/// Incremental Koopman8P checksum calculator (7-bit checksum + 1 parity bit).
+/// Compute a 32-bit Koopman checksum over fixed-size records as one
+/// contiguous stream.
 ///
-/// Allows computing checksums over data that arrives in chunks.
+/// The records are laid out back to back in memory, so this is exactly
+/// [`koopman32`] of the flattened bytes, with no copy. The seed applies to
+/// the first byte of the first record only.
+///
+/// # Returns
+/// 32-bit checksum value, or 0 if there are no bytes (no records, or `N == 0`)
 ///
 /// # Example
 /// ```rust
-/// use koopman_checksum::Koopman8P;
+/// use koopman_checksum::{koopman32, koopman32_records};
 ///
-/// let mut hasher = Koopman8P::new();
-/// hasher.update(b"Hello");
-/// let checksum = hasher.finalize();
-/// let parity_bit = checksum & 1;
+/// let records = [*b"abcd", *b"efgh"];
+/// assert_eq!(koopman32_records(&records, 0xee), koopman32(b"abcdefgh", 0xee));
 /// ```
-#[derive(Clone, Debug)]
-pub struct Koopman8P {
-    sum: u32,
-    psum: u8,
-    modulus: u32,
-    seed: u32,
-    initialized: bool,
+#[cfg_attr(not(feature = "small"), inline)]
+#[must_use]
+pub fn koopman32_records<const N: usize>(records: &[[u8; N]], initial_seed: u8) -> u32 {
+    koopman32(records.as_flattened(), initial_seed)
 }
 
-impl_streaming_parity_hasher!(
-    Koopman8P, u32, u8,
-    MODULUS_7P, NonZeroU32,
-    1
-);
+// ============================================================================
+// Reversed Byte Order
+// ============================================================================
 
-/// Incremental Koopman16P checksum calculator (15-bit checksum + 1 parity bit).
+/// Compute a 16-bit Koopman checksum folding the bytes in reverse order.
 ///
-/// Allows computing checksums over data that arrives in chunks.
+/// For frame conventions that transmit the payload last-byte-first relative
+/// to the checksum fold. Equivalent to [`koopman16`] over the reversed slice,
+/// without allocating a reversed copy: the seed is applied to the last byte.
+///
+/// # Returns
+/// 16-bit checksum value, or 0 if data is empty
 ///
 /// # Example
 /// ```rust
-/// use koopman_checksum::Koopman16P;
+/// use koopman_checksum::{koopman16, koopman16_reversed};
 ///
-/// let mut hasher = Koopman16P::new();
-/// hasher.update(b"Hello, ");
-/// hasher.update(b"World!");
-/// let checksum = hasher.finalize();
-/// let parity_bit = checksum & 1;
+/// assert_eq!(koopman16_reversed(b"abc", 0xee), koopman16(b"cba", 0xee));
 /// ```
-#[derive(Clone, Debug)]
-pub struct Koopman16P {
-    sum: u32,
-    psum: u8,
-    modulus: u32,
-    seed: u32,
-    initialized: bool,
-}
+#[cfg_attr(not(feature = "small"), inline)]
+#[must_use]
+pub fn koopman16_reversed(data: &[u8], initial_seed: u8) -> u16 {
+    let Some((&first, rest)) = data.split_last() else {
+        return 0;
+    };
 
-impl_streaming_parity_hasher!(
-    Koopman16P, u32, u16,
-    MODULUS_15P, NonZeroU32,
-    2
-);
+    let mut sum: u32 = (first ^ initial_seed) as u32;
 
-/// Incremental Koopman32P checksum calculator (31-bit checksum + 1 parity bit).
+    if cfg!(feature = "small") {
+        for &byte in rest.iter().rev() {
+            sum = ((sum << 8) + byte as u32) % MODULUS_16;
+        }
+        sum = (sum << 8) % MODULUS_16;
+        sum = (sum << 8) % MODULUS_16;
+        return sum as u16;
+    }
+
+    for &byte in rest.iter().rev() {
+        sum = fast_mod_65519((sum << 8) + byte as u32);
+    }
+
+    // Append two implicit zero bytes
+    sum = fast_mod_65519(sum << 8);
+    sum = fast_mod_65519(sum << 8);
+
+    sum as u16
+}
+
+/// Compute a 16-bit Koopman checksum with the output bit-reflected.
 ///
-/// Allows computing checksums over data that arrives in chunks.
+/// For frame conventions that transmit the checksum LSB-first. Returns
+/// `koopman16(data, initial_seed).reverse_bits()`; the same as a
+/// [`KoopmanAlgorithm`](algorithm::KoopmanAlgorithm) with `refout` set.
+/// Reflection is a fixed permutation of the output bits, so it does not
+/// change which errors are detected.
+///
+/// # Returns
+/// 16-bit reflected checksum value, or 0 if data is empty
 ///
 /// # Example
 /// ```rust
-/// use koopman_checksum::Koopman32P;
+/// use koopman_checksum::{koopman16, koopman16_refout};
 ///
-/// let mut hasher = Koopman32P::new();
-/// hasher.update(b"Hello, ");
-/// hasher.update(b"World!");
-/// let checksum = hasher.finalize();
-/// let parity_bit = checksum & 1;
+/// assert_eq!(koopman16_refout(b"abc", 0xee), koopman16(b"abc", 0xee).reverse_bits());
 /// ```
-#[derive(Clone, Debug)]
-pub struct Koopman32P {
-    sum: u64,
-    psum: u8,
-    modulus: u64,
-    seed: u64,
-    initialized: bool,
+#[inline]
+#[must_use]
+pub fn koopman16_refout(data: &[u8], initial_seed: u8) -> u16 {
+    koopman16(data, initial_seed).reverse_bits()
 }
 
-impl_streaming_parity_hasher!(
-    Koopman32P, u64, u32,
-    MODULUS_31P, NonZeroU64,
-    4
-);
-
 // ============================================================================
-// Verification Functions
+// Ring Buffers
 // ============================================================================
 
-/// Verify data integrity using Koopman8 checksum.
+/// Compute a 16-bit Koopman checksum over a message split across two slices.
 ///
-/// # Arguments
-/// * `data` - The data bytes (excluding checksum)
-/// * `expected` - The expected checksum value
-/// * `initial_seed` - Initial seed used when computing the checksum
+/// For messages that wrap around the end of a ring buffer: `head` and `tail`
+/// are folded as one logical message, equal to [`koopman16`] over their
+/// concatenation. The seed is applied to the first byte of the message, which
+/// is the first byte of `tail` when `head` is empty.
 ///
 /// # Returns
-/// `true` if the checksum matches, `false` otherwise
+/// 16-bit checksum value, or 0 if both slices are empty
 ///
 /// # Example
 /// ```rust
-/// use koopman_checksum::{koopman8, verify8};
+/// use koopman_checksum::{koopman16, koopman16_wrapped};
 ///
-/// let data = b"test data";
-/// let checksum = koopman8(data, 0xee);
-/// assert!(verify8(data, checksum, 0xee));
-/// assert!(!verify8(data, checksum.wrapping_add(1), 0));
+/// assert_eq!(koopman16_wrapped(b"hello, ", b"ring", 0xee), koopman16(b"hello, ring", 0xee));
 /// ```
 #[inline]
 #[must_use]
-pub fn verify8(data: &[u8], expected: u8, initial_seed: u8) -> bool {
-    koopman8(data, initial_seed) == expected
+pub fn koopman16_wrapped(head: &[u8], tail: &[u8], initial_seed: u8) -> u16 {
+    let mut hasher = Koopman16::with_seed(initial_seed);
+    hasher.update(head);
+    hasher.update(tail);
+    hasher.finalize()
 }
 
-/// Verify data integrity using Koopman16 checksum.
+// ============================================================================
+// Header and Payload
+// ============================================================================
+
+/// Compute a 16-bit Koopman checksum of `header` followed by `payload`,
+/// without copying them together.
 ///
-/// # Arguments
-/// * `data` - The data bytes (excluding checksum)
-/// * `expected` - The expected checksum value
-/// * `initial_seed` - Initial seed used when computing the checksum
+/// Equal to [`koopman16`] over the concatenation. This is
+/// [`koopman16_wrapped`] under the name network code looks for; both fold
+/// the two slices in place, with no copy or allocation. The seed is applied
+/// to the first byte of the message, which is the first byte of `payload`
+/// when `header` is empty.
 ///
 /// # Returns
-/// `true` if the checksum matches, `false` otherwise
+/// 16-bit checksum value, or 0 if both slices are empty
 ///
 /// # Example
 /// ```rust
-/// use koopman_checksum::{koopman16, verify16};
+/// use koopman_checksum::{koopman16, koopman16_header_payload};
 ///
-/// let data = b"test data";
-/// let checksum = koopman16(data, 0xee);
-/// assert!(verify16(data, checksum, 0xee));
+/// let checksum = koopman16_header_payload(b"HDR", b"payload", 0xee);
+/// assert_eq!(checksum, koopman16(b"HDRpayload", 0xee));
 /// ```
 #[inline]
 #[must_use]
-pub fn verify16(data: &[u8], expected: u16, initial_seed: u8) -> bool {
-    koopman16(data, initial_seed) == expected
+pub fn koopman16_header_payload(header: &[u8], payload: &[u8], initial_seed: u8) -> u16 {
+    koopman16_wrapped(header, payload, initial_seed)
 }
 
-/// Verify data integrity using Koopman32 checksum.
+// ============================================================================
+// Nonced Checksums
+// ============================================================================
+
+/// Compute a 16-bit Koopman checksum diversified by a per-message nonce.
 ///
-/// # Arguments
-/// * `data` - The data bytes (excluding checksum)
-/// * `expected` - The expected checksum value
-/// * `initial_seed` - Initial seed used when computing the checksum
+/// The nonce is folded into the register ahead of the data: the result is
+/// [`koopman16`] of the big-endian `nonce` bytes followed by `data`, with
+/// `base_seed` XORed into the first nonce byte. The register the data is
+/// folded into is therefore
+///
+/// ```text
+/// (nonce ^ (base_seed << 24)) % 65519
+/// ```
+///
+/// and, because it is multiplied through every later fold, it affects the
+/// whole checksum, not just the first byte. Two nonces give the same
+/// checksum for the same data exactly when their registers are equal, so
+/// any 65519 consecutive counter values below 2^24 are all distinct.
+///
+/// This is for diversification, e.g. so identical payloads with different
+/// counters do not share a checksum. It is not authentication: anyone who
+/// knows the nonce can compute the checksum, and it offers no resistance to
+/// deliberate replay or forgery.
 ///
 /// # Returns
-/// `true` if the checksum matches, `false` otherwise
+/// 16-bit checksum value, or 0 if data is empty
 ///
 /// # Example
 /// ```rust
-/// use koopman_checksum::{koopman32, verify32};
+/// use koopman_checksum::{koopman16, koopman16_nonced};
 ///
-/// let data = b"test data";
-/// let checksum = koopman32(data, 0xee);
-/// assert!(verify32(data, checksum, 0xee));
+/// let a = koopman16_nonced(b"reading=42", 0xee, 1);
+/// let b = koopman16_nonced(b"reading=42", 0xee, 2);
+/// assert_ne!(a, b);
+/// assert_eq!(a, koopman16(b"\0\0\0\x01reading=42", 0xee));
 /// ```
 #[inline]
 #[must_use]
-pub fn verify32(data: &[u8], expected: u32, initial_seed: u8) -> bool {
-    koopman32(data, initial_seed) == expected
+pub fn koopman16_nonced(data: &[u8], base_seed: u8, nonce: u32) -> u16 {
+    if data.is_empty() {
+        return 0;
+    }
+    koopman16_header_payload(&nonce.to_be_bytes(), data, base_seed)
 }
 
-/// Verify data integrity using Koopman8P checksum (with parity).
+// ============================================================================
+// Sampled Checksums
+// ============================================================================
+
+/// Compute a 16-bit Koopman checksum over every `stride`-th byte.
 ///
-/// # Arguments
-/// * `data` - The data bytes (excluding checksum)
-/// * `expected` - The expected checksum value (7-bit checksum + 1 parity bit)
-/// * `initial_seed` - Initial seed used when computing the checksum
+/// Folds `data[0]`, `data[stride]`, `data[2 * stride]`, ... exactly as
+/// [`koopman16`] would fold that subsequence. This is a weak, sampled
+/// integrity check for links where checksumming every byte is too costly:
+/// it does NOT provide the HD=3 guarantee, and corruption confined to the
+/// skipped bytes is never detected. With `stride == 1` it equals [`koopman16`].
 ///
 /// # Returns
-/// `true` if the checksum matches, `false` otherwise
+/// 16-bit checksum of the sampled bytes, or 0 if data is empty
+///
+/// # Panics
+/// Panics if `stride` is zero.
 ///
 /// # Example
 /// ```rust
-/// use koopman_checksum::{koopman8p, verify8p};
+/// use koopman_checksum::{koopman16, koopman16_sampled};
 ///
-/// let data = b"test";
-/// let checksum = koopman8p(data, 0xee);
-/// assert!(verify8p(data, checksum, 0xee));
+/// assert_eq!(koopman16_sampled(b"a-b-c-d", 0xee, 2), koopman16(b"abcd", 0xee));
 /// ```
-#[inline]
+#[cfg_attr(not(feature = "small"), inline)]
 #[must_use]
-pub fn verify8p(data: &[u8], expected: u8, initial_seed: u8) -> bool {
-    koopman8p(data, initial_seed) == expected
+#[track_caller]
+pub fn koopman16_sampled(data: &[u8], initial_seed: u8, stride: usize) -> u16 {
+    assert!(stride > 0, "stride must be non-zero");
+
+    let mut sampled = data.iter().step_by(stride);
+    let Some(&first) = sampled.next() else {
+        return 0;
+    };
+
+    // The generic `%` with the `small` feature, as in `koopman16`
+    let reduce = |x: u32| {
+        if cfg!(feature = "small") {
+            x % MODULUS_16
+        } else {
+            fast_mod_65519(x)
+        }
+    };
+
+    let mut sum: u32 = (first ^ initial_seed) as u32;
+    for &byte in sampled {
+        sum = reduce((sum << 8) + byte as u32);
+    }
+
+    // Append two implicit zero bytes
+    sum = reduce(sum << 8);
+    sum = reduce(sum << 8);
+
+    sum as u16
 }
 
-/// Verify data integrity using Koopman16P checksum (with parity).
+// ============================================================================
+// Storage Pages
+// ============================================================================
+
+/// Size in bytes of the page accepted by [`koopman16_page`].
+pub const KOOPMAN_PAGE_SIZE: usize = 4096;
+
+/// Compute a 16-bit Koopman checksum of one 4 KiB page.
 ///
-/// # Arguments
-/// * `data` - The data bytes (excluding checksum)
-/// * `expected` - The expected checksum value (15-bit checksum + 1 parity bit)
-/// * `initial_seed` - Initial seed used when computing the checksum
+/// Equal to [`koopman16`] of the same bytes. The fixed length lets the
+/// compiler fully unroll the loop, and four bytes are folded per reduction:
+/// since 2^32 ≡ 289 (mod 65519), `sum * 2^32 + word` reduces to
+/// `sum * 289 + (word >> 16) * 17 + (word & 0xFFFF)`, which stays below
+/// 2^25 and is within the range of the fast reduction.
+///
+/// The page is well past [`KOOPMAN16_MAX_LEN`], so HD=3 is not guaranteed;
+/// use [`koopman32`] when that matters.
+///
+/// # Example
+/// ```rust
+/// use koopman_checksum::{koopman16, koopman16_page, KOOPMAN_PAGE_SIZE};
+///
+/// let page = [0xA5u8; KOOPMAN_PAGE_SIZE];
+/// assert_eq!(koopman16_page(&page, 0xee), koopman16(&page, 0xee));
+/// ```
+#[cfg_attr(not(feature = "small"), inline)]
+#[must_use]
+pub fn koopman16_page(page: &[u8; KOOPMAN_PAGE_SIZE], initial_seed: u8) -> u16 {
+    if cfg!(feature = "small") {
+        return koopman16(page, initial_seed);
+    }
+
+    // 2^32 mod 65519 = 17^2
+    const SHIFT_32: u32 = 289;
+
+    // The first byte and the next three single-stepped leave 4092 bytes,
+    // an exact number of four-byte words
+    let mut sum = (page[0] ^ initial_seed) as u32;
+    for &byte in &page[1..4] {
+        sum = fast_mod_65519((sum << 8) + byte as u32);
+    }
+    for word in page[4..].chunks_exact(4) {
+        let word = u32::from_be_bytes([word[0], word[1], word[2], word[3]]);
+        sum = fast_mod_65519(sum * SHIFT_32 + (word >> 16) * 17 + (word & 0xFFFF));
+    }
+
+    // Append two implicit zero bytes
+    sum = fast_mod_65519(sum << 8);
+    sum = fast_mod_65519(sum << 8);
+
+    sum as u16
+}
+
+// ============================================================================
+// Caller-Owned Scratch
+// ============================================================================
+
+/// Working state for [`koopman16_in`], owned by the caller.
+///
+/// Holds the running sum and the count of bytes folded since the last
+/// reduction. After a call it holds the register before the implicit zero
+/// bytes were appended, which can be inspected with
+/// [`running_sum`](Self::running_sum).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ReductionScratch {
+    sum: u32,
+    pending: u32,
+}
+
+impl ReductionScratch {
+    /// Zeroed scratch, usable in `static` and `const` contexts.
+    pub const fn new() -> Self {
+        Self { sum: 0, pending: 0 }
+    }
+
+    /// The pre-finalization register left by the last [`koopman16_in`] call.
+    #[inline]
+    #[must_use]
+    pub fn running_sum(&self) -> u32 {
+        self.sum
+    }
+}
+
+/// Compute a 16-bit Koopman checksum with the reduction state in a
+/// caller-supplied scratch.
+///
+/// For worst-case execution time analysis. [`koopman16`] lets the optimizer
+/// unroll and keep its temporaries wherever it likes; this function is never
+/// inlined and folds one byte per loop iteration with the same delayed
+/// reduction (every second byte), keeping the running sum and pending count
+/// in `scratch` rather than in locals. The work is a fixed cost per byte,
+/// and the state it needs beyond a few registers is an explicit 8-byte
+/// object whose placement the caller controls. `scratch` is reset on entry,
+/// so its prior contents never affect the result.
 ///
 /// # Returns
-/// `true` if the checksum matches, `false` otherwise
+/// 16-bit checksum value, or 0 if data is empty; the same as [`koopman16`]
 ///
 /// # Example
 /// ```rust
-/// use koopman_checksum::{koopman16p, verify16p};
+/// use koopman_checksum::{koopman16, koopman16_in, ReductionScratch};
 ///
-/// let data = b"test data";
-/// let checksum = koopman16p(data, 0xee);
-/// assert!(verify16p(data, checksum, 0xee));
+/// let mut scratch = ReductionScratch::new();
+/// assert_eq!(koopman16_in(b"test data", 0xee, &mut scratch), koopman16(b"test data", 0xee));
 /// ```
-#[inline]
+#[inline(never)]
 #[must_use]
-pub fn verify16p(data: &[u8], expected: u16, initial_seed: u8) -> bool {
-    koopman16p(data, initial_seed) == expected
+pub fn koopman16_in(data: &[u8], initial_seed: u8, scratch: &mut ReductionScratch) -> u16 {
+    *scratch = ReductionScratch::new();
+    let [first, rest @ ..] = data else {
+        return 0;
+    };
+
+    // The generic `%` with the `small` feature, as in `koopman16`
+    let reduce = |x: u32| {
+        if cfg!(feature = "small") {
+            x % MODULUS_16
+        } else {
+            fast_mod_65519(x)
+        }
+    };
+
+    scratch.sum = (first ^ initial_seed) as u32;
+    for &byte in rest {
+        // sum < 2^16 after a reduction, so two unreduced bytes stay below 2^32
+        scratch.sum = (scratch.sum << 8) + byte as u32;
+        scratch.pending += 1;
+        if scratch.pending == 2 {
+            scratch.sum = reduce(scratch.sum);
+            scratch.pending = 0;
+        }
+    }
+    if scratch.pending > 0 {
+        scratch.sum = reduce(scratch.sum);
+        scratch.pending = 0;
+    }
+
+    // Append two implicit zero bytes
+    let sum = reduce(scratch.sum << 8);
+    reduce(sum << 8) as u16
 }
 
-/// Verify data integrity using Koopman32P checksum (with parity).
+// ============================================================================
+// Custom Trailers
+// ============================================================================
+
+/// Compute a 16-bit Koopman checksum with a custom number of implicit zero
+/// bytes appended before the result is taken.
 ///
-/// # Arguments
-/// * `data` - The data bytes (excluding checksum)
-/// * `expected` - The expected checksum value (31-bit checksum + 1 parity bit)
-/// * `initial_seed` - Initial seed used when computing the checksum
+/// [`koopman16`] and the other standard functions always append as many zero
+/// bytes as the checksum is wide (two for 16 bits); `zero_bytes = 2`
+/// reproduces it exactly. Other counts are for experimenting and for
+/// matching frame conventions that differ from the paper's. The zeros are
+/// folded as in [`Koopman16::append_zeros`], in `O(log zero_bytes)` steps,
+/// so any count is valid; `zero_bytes = 0` returns the raw register.
+/// The HD guarantees documented for [`koopman16`] assume the standard count.
 ///
 /// # Returns
-/// `true` if the checksum matches, `false` otherwise
+/// 16-bit checksum value, or 0 if data is empty
 ///
 /// # Example
 /// ```rust
-/// use koopman_checksum::{koopman32p, verify32p};
+/// use koopman_checksum::{koopman16, koopman16_with_trailer};
 ///
-/// let data = b"test data";
-/// let checksum = koopman32p(data, 0xee);
-/// assert!(verify32p(data, checksum, 0xee));
+/// assert_eq!(koopman16_with_trailer(b"test data", 0xee, 2), koopman16(b"test data", 0xee));
 /// ```
-#[inline]
 #[must_use]
-pub fn verify32p(data: &[u8], expected: u32, initial_seed: u8) -> bool {
-    koopman32p(data, initial_seed) == expected
+pub fn koopman16_with_trailer(data: &[u8], initial_seed: u8, zero_bytes: usize) -> u16 {
+    if data.is_empty() {
+        return 0;
+    }
+
+    let mut hasher = Koopman16::with_seed(initial_seed);
+    hasher.update(data);
+    hasher.append_zeros(zero_bytes);
+    hasher.running_sum() as u16
+}
+
+/// Compute a 16-bit Koopman checksum of `frame[start..end]`, padding the
+/// implicit zero trailer so the fold ends on an `align_to`-byte boundary of
+/// the enclosing frame.
+///
+/// The trailer is the standard two zero bytes, treated as if they sat at
+/// frame offsets `end..end + 2`, plus as many more as it takes for the
+/// total to reach the next multiple of `align_to`:
+///
+/// ```text
+/// zeros = 2 + (align_to - (end + 2) % align_to) % align_to
+/// ```
+///
+/// With `align_to = 1`, or whenever `end + 2` is already aligned, this is
+/// exactly [`koopman16`] of the region. Only `end` decides the padding;
+/// `start` just selects the first byte folded. The extra zeros are computed
+/// as in [`koopman16_with_trailer`], and the same caveat about HD guarantees
+/// applies when padding is added.
+///
+/// # Arguments
+/// * `frame` - The enclosing record
+/// * `start`, `end` - Byte range of the covered region within `frame`
+/// * `align_to` - Word size, in bytes, the fold is aligned to
+/// * `initial_seed` - Initial seed value
+///
+/// # Returns
+/// 16-bit checksum value, or 0 if the region is empty
+///
+/// # Errors
+/// Returns [`KoopmanError::Truncated`] if `end` lies past the end of `frame`.
+///
+/// # Panics
+/// Panics if `align_to` is zero or if `start` is greater than `end`.
+///
+/// # Example
+/// ```rust
+/// use koopman_checksum::{koopman16_region, koopman16_with_trailer, KoopmanError};
+///
+/// let frame = b"HDRtest data..";
+/// // Region ends at offset 12; 12 + 2 is not a multiple of 4, so two more zeros
+/// let checksum = koopman16_region(frame, 3, 12, 4, 0xee);
+/// assert_eq!(checksum, Ok(koopman16_with_trailer(b"test data", 0xee, 4)));
+///
+/// let past_end = koopman16_region(frame, 3, 20, 4, 0xee);
+/// assert_eq!(past_end, Err(KoopmanError::Truncated { len: 14, min: 20 }));
+/// ```
+#[track_caller]
+pub fn koopman16_region(
+    frame: &[u8],
+    start: usize,
+    end: usize,
+    align_to: usize,
+    initial_seed: u8,
+) -> Result<u16, KoopmanError> {
+    assert!(align_to > 0, "align_to must be non-zero");
+
+    if end > frame.len() {
+        return Err(KoopmanError::Truncated {
+            len: frame.len(),
+            min: end,
+        });
+    }
+    let region = &frame[start..end];
+    if region.is_empty() {
+        return Ok(0);
+    }
+
+    // `end <= frame.len() <= isize::MAX`, so `end + 2` cannot overflow. The
+    // padding and the standard two zeros are folded separately so that a
+    // huge `align_to` cannot overflow their total.
+    let padding = (align_to - (end + 2) % align_to) % align_to;
+    let mut hasher = Koopman16::with_seed(initial_seed);
+    hasher.update(region);
+    hasher.append_zeros(padding);
+    hasher.append_zeros(2);
+    Ok(hasher.running_sum() as u16)
 }
 
-// ============================================================================
-// Tests
-// ============================================================================
+/// Compute a 32-bit Koopman checksum with the message length folded in.
+///
+/// This is a distinct scheme from [`koopman32`], not a drop-in replacement:
+/// the big-endian `data.len() as u32` is folded after the data and before
+/// the four implicit zero bytes, so the result equals `koopman32` of
+/// `data` followed by those four length bytes. Lengths past `u32::MAX`
+/// wrap.
+///
+/// Plain `koopman32` cannot tell some messages of different lengths apart;
+/// with a zero seed, for example, leading zero bytes do not change the sum.
+/// Mixing in the length makes such messages diverge. The checksum is still
+/// not cryptographic and does not stop deliberate forgery.
+///
+/// # Returns
+/// 32-bit checksum value, or 0 if data is empty
+///
+/// # Example
+/// ```rust
+/// use koopman_checksum::{koopman32, koopman32_len_bound};
+///
+/// // The leading zero byte is invisible to koopman32 with seed 0
+/// assert_eq!(koopman32(b"\0data", 0), koopman32(b"data", 0));
+/// assert_ne!(koopman32_len_bound(b"\0data", 0), koopman32_len_bound(b"data", 0));
+/// ```
+#[must_use]
+pub fn koopman32_len_bound(data: &[u8], initial_seed: u8) -> u32 {
+    if data.is_empty() {
+        return 0;
+    }
+
+    let mut hasher = Koopman32::with_seed(initial_seed);
+    hasher.update(data);
+    hasher.update(&(data.len() as u32).to_be_bytes());
+    hasher.finalize()
+}
+
+// ============================================================================
+// Content Keys
+// ============================================================================
+
+/// Derive a fixed-size content key from data using Koopman32.
+///
+/// Writes the checksum as 8 uppercase hexadecimal ASCII digits into a stack
+/// array, suitable as a short content tag or cache key. Does not allocate.
+///
+/// # Example
+/// ```rust
+/// use koopman_checksum::{koopman32, koopman32_key};
+///
+/// let key = koopman32_key(b"test data", 0xee);
+/// let text = core::str::from_utf8(&key).unwrap();
+/// assert_eq!(text, format!("{:08X}", koopman32(b"test data", 0xee)));
+/// ```
+#[inline]
+#[must_use]
+pub fn koopman32_key(data: &[u8], initial_seed: u8) -> [u8; 8] {
+    const HEX: &[u8; 16] = b"0123456789ABCDEF";
+
+    let checksum = koopman32(data, initial_seed);
+    let mut key = [0u8; 8];
+    for (i, digit) in key.iter_mut().enumerate() {
+        let nibble = (checksum >> (28 - 4 * i)) & 0xF;
+        *digit = HEX[nibble as usize];
+    }
+    key
+}
+
+/// Crockford base32 alphabet: digits and uppercase letters without I, L, O, U.
+const CROCKFORD: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// Number of base32 digits in a [`koopman32_tag`]; 7 * 5 = 35 bits hold 32.
+const TAG_LEN: usize = 7;
+
+/// Derive a short human-readable integrity tag from data using Koopman32.
+///
+/// Encodes the checksum as 7 Crockford base32 digits, most significant
+/// first, e.g. for embedding in file names. Parse it back with
+/// [`verify32_tag`].
+///
+/// # Example
+/// ```rust
+/// use koopman_checksum::{koopman32_tag, verify32_tag};
+///
+/// let tag = koopman32_tag(b"test data", 0xee);
+/// assert_eq!(tag.len(), 7);
+/// assert!(verify32_tag(b"test data", &tag, 0xee));
+/// ```
+#[cfg(feature = "alloc")]
+#[must_use]
+pub fn koopman32_tag(data: &[u8], initial_seed: u8) -> alloc::string::String {
+    let checksum = koopman32(data, initial_seed);
+    (0..TAG_LEN)
+        .map(|i| {
+            let digit = (checksum >> (5 * (TAG_LEN - 1 - i))) & 0x1F;
+            CROCKFORD[digit as usize] as char
+        })
+        .collect()
+}
+
+/// Verify data against a tag produced by [`koopman32_tag`].
+///
+/// Decoding follows Crockford's rules: case-insensitive, with `O` read as
+/// `0` and `I`/`L` as `1`.
+///
+/// # Returns
+/// `true` if the tag is well-formed and matches the data; `false` for a
+/// mismatch or a malformed tag (wrong length, invalid characters, or a value
+/// that does not fit in 32 bits)
+#[must_use]
+pub fn verify32_tag(data: &[u8], tag: &str, initial_seed: u8) -> bool {
+    if tag.len() != TAG_LEN {
+        return false;
+    }
+
+    let mut value: u64 = 0;
+    for c in tag.bytes() {
+        let digit = match c.to_ascii_uppercase() {
+            b'O' => 0,
+            b'I' | b'L' => 1,
+            c => match CROCKFORD.iter().position(|&d| d == c) {
+                Some(digit) => digit as u64,
+                None => return false,
+            },
+        };
+        value = (value << 5) | digit;
+    }
+
+    u32::try_from(value).is_ok_and(|expected| koopman32(data, initial_seed) == expected)
+}
+
+// ============================================================================
+// Checked API
+// ============================================================================
+
+/// Errors returned by the checked APIs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum KoopmanError {
+    /// The data is longer than the maximum length for which the variant's
+    /// error detection guarantee holds.
+    DataTooLong {
+        /// Length of the rejected data in bytes.
+        len: usize,
+        /// Maximum guaranteed length in bytes for the variant.
+        max: usize,
+    },
+    /// The modulus is too large for the checksum portion of a parity variant.
+    ModulusTooLarge {
+        /// The rejected modulus.
+        modulus: u64,
+        /// Largest modulus the variant's bit budget allows.
+        max: u64,
+    },
+    /// The input is too short to contain the expected fields.
+    Truncated {
+        /// Length of the rejected input in bytes.
+        len: usize,
+        /// Minimum required length in bytes.
+        min: usize,
+    },
+    /// The stored checksum does not match the recomputed one.
+    ChecksumMismatch {
+        /// The checksum stored alongside the data, zero-extended.
+        expected: u32,
+        /// The checksum recomputed from the data, zero-extended.
+        actual: u32,
+    },
+    /// The input is not exactly the required length.
+    BadLength {
+        /// Length of the rejected input in bytes.
+        len: usize,
+        /// Required length in bytes.
+        expected: usize,
+    },
+    /// The input declares a checksum width this crate does not recognize.
+    UnknownTag {
+        /// The rejected tag byte.
+        tag: u8,
+    },
+}
+
+impl core::fmt::Display for KoopmanError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            KoopmanError::DataTooLong { len, max } => write!(
+                f,
+                "data too long: {len} bytes exceeds HD-safe maximum of {max}"
+            ),
+            KoopmanError::ModulusTooLarge { modulus, max } => write!(
+                f,
+                "modulus too large: {modulus} exceeds parity variant maximum of {max}"
+            ),
+            KoopmanError::Truncated { len, min } => write!(
+                f,
+                "input truncated: {len} bytes is shorter than the minimum of {min}"
+            ),
+            KoopmanError::ChecksumMismatch { expected, actual } => write!(
+                f,
+                "checksum mismatch: expected {expected:#x}, computed {actual:#x}"
+            ),
+            KoopmanError::BadLength { len, expected } => write!(
+                f,
+                "bad length: {len} bytes, expected exactly {expected}"
+            ),
+            KoopmanError::UnknownTag { tag } => write!(f, "unknown width tag: {tag:#04x}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for KoopmanError {}
+
+/// Return `Ok(())` if `len` is within `max`, otherwise [`KoopmanError::DataTooLong`].
+#[inline]
+fn check_len(len: usize, max: usize) -> Result<(), KoopmanError> {
+    if len > max {
+        return Err(KoopmanError::DataTooLong { len, max });
+    }
+    Ok(())
+}
+
+/// Compute an 8-bit Koopman checksum, rejecting data longer than [`KOOPMAN8_MAX_LEN`].
+///
+/// # Errors
+/// Returns [`KoopmanError::DataTooLong`] if the detection guarantee would not hold.
+///
+/// # Example
+/// ```rust
+/// use koopman_checksum::{koopman8, koopman8_checked, KoopmanError, KOOPMAN8_MAX_LEN};
+///
+/// assert_eq!(koopman8_checked(b"short", 0xee), Ok(koopman8(b"short", 0xee)));
+/// assert_eq!(
+///     koopman8_checked(&[0u8; 14], 0xee),
+///     Err(KoopmanError::DataTooLong { len: 14, max: KOOPMAN8_MAX_LEN })
+/// );
+/// ```
+#[inline]
+pub fn koopman8_checked(data: &[u8], initial_seed: u8) -> Result<u8, KoopmanError> {
+    check_len(data.len(), KOOPMAN8_MAX_LEN)?;
+    Ok(koopman8(data, initial_seed))
+}
+
+/// Compute a 16-bit Koopman checksum, rejecting data longer than [`KOOPMAN16_MAX_LEN`].
+///
+/// # Errors
+/// Returns [`KoopmanError::DataTooLong`] if the detection guarantee would not hold.
+#[inline]
+pub fn koopman16_checked(data: &[u8], initial_seed: u8) -> Result<u16, KoopmanError> {
+    check_len(data.len(), KOOPMAN16_MAX_LEN)?;
+    Ok(koopman16(data, initial_seed))
+}
+
+/// Compute a 32-bit Koopman checksum, rejecting data longer than [`KOOPMAN32_MAX_LEN`].
+///
+/// # Errors
+/// Returns [`KoopmanError::DataTooLong`] if the detection guarantee would not hold.
+#[inline]
+pub fn koopman32_checked(data: &[u8], initial_seed: u8) -> Result<u32, KoopmanError> {
+    check_len(data.len(), KOOPMAN32_MAX_LEN)?;
+    Ok(koopman32(data, initial_seed))
+}
+
+/// Compute an 8-bit Koopman checksum with parity, rejecting data longer than [`KOOPMAN8P_MAX_LEN`].
+///
+/// # Errors
+/// Returns [`KoopmanError::DataTooLong`] if the detection guarantee would not hold.
+#[inline]
+pub fn koopman8p_checked(data: &[u8], initial_seed: u8) -> Result<u8, KoopmanError> {
+    check_len(data.len(), KOOPMAN8P_MAX_LEN)?;
+    Ok(koopman8p(data, initial_seed))
+}
+
+/// Compute a 16-bit Koopman checksum with parity, rejecting data longer than [`KOOPMAN16P_MAX_LEN`].
+///
+/// # Errors
+/// Returns [`KoopmanError::DataTooLong`] if the detection guarantee would not hold.
+#[inline]
+pub fn koopman16p_checked(data: &[u8], initial_seed: u8) -> Result<u16, KoopmanError> {
+    check_len(data.len(), KOOPMAN16P_MAX_LEN)?;
+    Ok(koopman16p(data, initial_seed))
+}
+
+/// Compute a 32-bit Koopman checksum with parity, rejecting data longer than [`KOOPMAN32P_MAX_LEN`].
+///
+/// # Errors
+/// Returns [`KoopmanError::DataTooLong`] if the detection guarantee would not hold.
+#[inline]
+pub fn koopman32p_checked(data: &[u8], initial_seed: u8) -> Result<u32, KoopmanError> {
+    check_len(data.len(), KOOPMAN32P_MAX_LEN)?;
+    Ok(koopman32p(data, initial_seed))
+}
+
+/// Compute an 8-bit Koopman checksum with parity, rejecting moduli above 127.
+///
+/// The checksum portion is packed into 7 bits, so residues of a larger
+/// modulus would overflow into the parity bit. [`koopman8p_with_modulus`]
+/// only checks this with a debug assertion.
+///
+/// # Errors
+/// Returns [`KoopmanError::ModulusTooLarge`] if `modulus > 127`.
+///
+/// # Example
+/// ```rust
+/// use std::num::NonZeroU32;
+/// use koopman_checksum::{koopman8p_with_modulus_checked, KoopmanError};
+///
+/// let ok = NonZeroU32::new(125).unwrap();
+/// assert!(koopman8p_with_modulus_checked(b"test", 0xee, ok).is_ok());
+///
+/// let too_large = NonZeroU32::new(200).unwrap();
+/// assert_eq!(
+///     koopman8p_with_modulus_checked(b"test", 0xee, too_large),
+///     Err(KoopmanError::ModulusTooLarge { modulus: 200, max: 127 })
+/// );
+/// ```
+#[inline]
+pub fn koopman8p_with_modulus_checked(
+    data: &[u8],
+    initial_seed: u8,
+    modulus: NonZeroU32,
+) -> Result<u8, KoopmanError> {
+    check_modulus(modulus.get() as u64, MAX_MODULUS_7P as u64)?;
+    Ok(koopman8p_with_modulus(data, initial_seed, modulus))
+}
+
+/// Compute a 16-bit Koopman checksum with parity, rejecting moduli above 32767.
+///
+/// The checksum portion is packed into 15 bits, so residues of a larger
+/// modulus would overflow into the parity bit. [`koopman16p_with_modulus`]
+/// only checks this with a debug assertion.
+///
+/// # Errors
+/// Returns [`KoopmanError::ModulusTooLarge`] if `modulus > 32767`.
+///
+/// # Example
+/// ```rust
+/// use std::num::NonZeroU32;
+/// use koopman_checksum::{koopman16p_with_modulus_checked, KoopmanError};
+///
+/// let too_large = NonZeroU32::new(65519).unwrap();
+/// assert_eq!(
+///     koopman16p_with_modulus_checked(b"test", 0xee, too_large),
+///     Err(KoopmanError::ModulusTooLarge { modulus: 65519, max: 32767 })
+/// );
+/// ```
+#[inline]
+pub fn koopman16p_with_modulus_checked(
+    data: &[u8],
+    initial_seed: u8,
+    modulus: NonZeroU32,
+) -> Result<u16, KoopmanError> {
+    check_modulus(modulus.get() as u64, MAX_MODULUS_15P as u64)?;
+    Ok(koopman16p_with_modulus(data, initial_seed, modulus))
+}
+
+/// Compute a 32-bit Koopman checksum with parity, rejecting moduli above 2^31-1.
+///
+/// The checksum portion is packed into 31 bits, so residues of a larger
+/// modulus would overflow into the parity bit. [`koopman32p_with_modulus`]
+/// only checks this with a debug assertion.
+///
+/// # Errors
+/// Returns [`KoopmanError::ModulusTooLarge`] if `modulus > 2^31-1`.
+///
+/// # Example
+/// ```rust
+/// use std::num::NonZeroU64;
+/// use koopman_checksum::{koopman32p_with_modulus_checked, MODULUS_32};
+///
+/// let too_large = NonZeroU64::new(MODULUS_32).unwrap();
+/// assert!(koopman32p_with_modulus_checked(b"test", 0xee, too_large).is_err());
+/// ```
+#[inline]
+pub fn koopman32p_with_modulus_checked(
+    data: &[u8],
+    initial_seed: u8,
+    modulus: NonZeroU64,
+) -> Result<u32, KoopmanError> {
+    check_modulus(modulus.get(), MAX_MODULUS_31P)?;
+    Ok(koopman32p_with_modulus(data, initial_seed, modulus))
+}
+
+/// Return `Ok(())` if `modulus` is within `max`, otherwise [`KoopmanError::ModulusTooLarge`].
+#[inline]
+fn check_modulus(modulus: u64, max: u64) -> Result<(), KoopmanError> {
+    if modulus > max {
+        return Err(KoopmanError::ModulusTooLarge { modulus, max });
+    }
+    Ok(())
+}
+
+// ============================================================================
+// Streaming/Incremental API
+// ============================================================================
+
+/// Common interface of the streaming hashers, for code generic over the variant.
+///
+/// Implemented by [`Koopman8`], [`Koopman16`], [`Koopman32`], [`Koopman8P`],
+/// [`Koopman16P`], and [`Koopman32P`]. Each method forwards to the inherent
+/// method of the same name.
+///
+/// # Example
+/// ```rust
+/// use koopman_checksum::{koopman16, Koopman16, KoopmanHasherState};
+///
+/// fn checksum_chunks<H: KoopmanHasherState>(mut hasher: H, chunks: &[&[u8]]) -> H::Output {
+///     for chunk in chunks {
+///         hasher.update(chunk);
+///     }
+///     hasher.finalize()
+/// }
+///
+/// let result = checksum_chunks(Koopman16::new(), &[b"Hello, ", b"World!"]);
+/// assert_eq!(result, koopman16(b"Hello, World!", 0));
+/// ```
+pub trait KoopmanHasherState {
+    /// The finalized checksum type.
+    type Output;
+
+    /// Update the checksum with more data.
+    fn update(&mut self, data: &[u8]);
+
+    /// Finalize and return the checksum.
+    fn finalize(self) -> Self::Output;
+
+    /// Reset the hasher to initial state.
+    fn reset(&mut self);
+}
+
+/// Macro to generate streaming checksum structs.
+/// This reduces code duplication across Koopman8, Koopman16, Koopman32.
+macro_rules! impl_streaming_hasher {
+    (
+        $name:ident,
+        $sum_type:ty,
+        $output_type:ty,
+        $default_modulus_raw:expr,
+        $nonzero_type:ty,
+        $finalize_shifts:expr,
+        $fast_mod:expr
+        $(, $wide_fold:expr)?
+    ) => {
+        impl Default for $name {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        /// Finalize the consumed hasher, so `let checksum: T = hasher.into();`
+        /// and `.map(Into::into)` work.
+        impl From<$name> for $output_type {
+            #[inline]
+            fn from(hasher: $name) -> Self {
+                hasher.finalize()
+            }
+        }
+
+        /// Mix a control byte into the seed before any data is folded.
+        ///
+        /// XORs `byte` into the low byte of the running sum while it still
+        /// holds the seed, which is the same as having constructed the hasher
+        /// with `seed ^ byte`. Once data has been folded this is a no-op, so
+        /// the byte is never "appended" to the message. [`reset`](Self::reset)
+        /// restores the original seed.
+        impl core::ops::BitXorAssign<u8> for $name {
+            #[inline]
+            fn bitxor_assign(&mut self, byte: u8) {
+                if !self.initialized {
+                    self.sum ^= byte as $sum_type;
+                }
+            }
+        }
+
+        impl KoopmanHasherState for $name {
+            type Output = $output_type;
+
+            #[inline]
+            fn update(&mut self, data: &[u8]) {
+                $name::update(self, data);
+            }
+
+            #[inline]
+            fn finalize(self) -> Self::Output {
+                $name::finalize(self)
+            }
+
+            #[inline]
+            fn reset(&mut self) {
+                $name::reset(self);
+            }
+        }
+
+        impl $name {
+            /// Create a new hasher with the default modulus.
+            #[inline]
+            pub fn new() -> Self {
+                Self {
+                    sum: 0,
+                    modulus: $default_modulus_raw,
+                    seed: 0,
+                    len: 0,
+                    initialized: false,
+                    use_fast_mod: true,
+                }
+                .traced()
+            }
+
+            /// Create a new hasher with a custom modulus.
+            ///
+            /// # Arguments
+            /// * `modulus` - The modulus to use. Must be non-zero.
+            ///
+            /// # Example
+            /// ```rust
+            #[doc = concat!("use std::num::", stringify!($nonzero_type), ";")]
+            #[doc = concat!("use koopman_checksum::{", stringify!($name), ", ", stringify!($default_modulus_raw), "};")]
+            ///
+            #[doc = concat!("let modulus = ", stringify!($nonzero_type), "::new(", stringify!($default_modulus_raw), ").unwrap();")]
+            #[doc = concat!("let hasher = ", stringify!($name), "::with_modulus(modulus);")]
+            /// ```
+            #[inline]
+            pub fn with_modulus(modulus: $nonzero_type) -> Self {
+                let modulus_val = modulus.get();
+                Self {
+                    sum: 0,
+                    modulus: modulus_val,
+                    seed: 0,
+                    len: 0,
+                    initialized: false,
+                    use_fast_mod: modulus_val == $default_modulus_raw,
+                }
+                .traced()
+            }
+
+            /// Create a new hasher with an initial seed.
+            ///
+            /// # Example
+            /// ```rust
+            #[doc = concat!("use koopman_checksum::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let hasher = ", stringify!($name), "::with_seed(0xee);")]
+            /// ```
+            #[inline]
+            pub fn with_seed(seed: u8) -> Self {
+                Self {
+                    sum: seed as $sum_type,
+                    modulus: $default_modulus_raw,
+                    seed: seed as $sum_type,
+                    len: 0,
+                    initialized: false,
+                    use_fast_mod: true,
+                }
+                .traced()
+            }
+
+            /// Log the reduction strategy under the `trace` feature.
+            #[inline(always)]
+            fn traced(self) -> Self {
+                #[cfg(feature = "trace")]
+                log::debug!(
+                    "{}: modulus {}, {} reduction, use_fast_mod = {}",
+                    stringify!($name),
+                    self.modulus,
+                    if self.use_fast_mod && !cfg!(feature = "small") { "fast" } else { "generic" },
+                    self.use_fast_mod,
+                );
+                self
+            }
+
+            /// Update the checksum with more data.
+            #[cfg_attr(not(feature = "small"), inline)]
+            pub fn update(&mut self, data: &[u8]) {
+                self.update_counted(data, &mut Reductions::default());
+            }
+
+            /// [`update`](Self::update), tallying each modular reduction.
+            #[cfg_attr(not(feature = "small"), inline)]
+            fn update_counted(&mut self, data: &[u8], reductions: &mut Reductions) {
+                debug_assert!(
+                    !self.use_fast_mod || self.modulus == $default_modulus_raw,
+                    "fast reduction enabled for non-default modulus {}",
+                    self.modulus,
+                );
+                let rest = match data {
+                    [] => return,
+                    [first, rest @ ..] if !self.initialized => {
+                        self.sum ^= *first as $sum_type;
+                        self.initialized = true;
+                        rest
+                    }
+                    _ => data,
+                };
+                self.len += data.len() as u64;
+
+                if self.use_fast_mod && !cfg!(feature = "small") {
+                    // Keep the running sum in a local. Variants with a wide fold
+                    // consume most of the input with it first; the rest goes
+                    // byte by byte. Unrolling the byte loop four ways measured
+                    // no faster (Streaming/streaming8_single_update, 4 KiB:
+                    // 15.0-15.5 us either way), so it is left as a plain loop.
+                    let mut sum = self.sum;
+                    #[allow(unused_mut)]
+                    let mut rest = rest;
+                    $(
+                        (sum, rest) = $wide_fold(sum, rest, reductions);
+                    )?
+                    for &byte in rest {
+                        sum = $fast_mod((sum << 8) + byte as $sum_type);
+                        reductions.add(1);
+                    }
+                    self.sum = sum;
+                } else {
+                    for &byte in rest {
+                        self.sum = ((self.sum << 8) + byte as $sum_type) % self.modulus;
+                        reductions.add(1);
+                    }
+                }
+            }
+
+            /// Finalize and return the checksum.
+            ///
+            /// Returns 0 if no data was provided.
+            #[cfg_attr(not(feature = "small"), inline)]
+            #[must_use]
+            pub fn finalize(self) -> $output_type {
+                if !self.initialized {
+                    return 0;
+                }
+                let mut sum = self.sum;
+                if self.use_fast_mod && !cfg!(feature = "small") {
+                    for _ in 0..$finalize_shifts {
+                        sum = $fast_mod(sum << 8);
+                    }
+                } else {
+                    for _ in 0..$finalize_shifts {
+                        sum = (sum << 8) % self.modulus;
+                    }
+                }
+                sum as $output_type
+            }
+
+            /// Return the checksum of the data so far without consuming the hasher.
+            ///
+            /// Equivalent to finalizing a clone; further updates are unaffected.
+            #[inline]
+            #[must_use]
+            pub fn peek(&self) -> $output_type {
+                self.clone().finalize()
+            }
+
+            /// The modulus this hasher was configured with.
+            #[inline]
+            #[must_use]
+            pub fn modulus(&self) -> $sum_type {
+                self.modulus
+            }
+
+            /// Number of data bytes folded since construction or the last [`reset`](Self::reset).
+            #[inline]
+            #[must_use]
+            pub fn bytes_processed(&self) -> u64 {
+                self.len
+            }
+
+            /// Reset the hasher to initial state.
+            ///
+            /// Preserves the configuration (seed, modulus, and whether the fast
+            /// reduction is used). Clears the accumulated state (sum, byte count,
+            /// and whether the first byte has been folded).
+            #[inline]
+            pub fn reset(&mut self) {
+                self.sum = self.seed;
+                self.len = 0;
+                self.initialized = false;
+            }
+        }
+    };
+}
+
+/// Incremental Koopman8 checksum calculator.
+///
+/// Allows computing checksums over data that arrives in chunks.
+/// Uses fast modular reduction when using the default modulus.
+///
+/// # Example
+/// ```rust
+/// use koopman_checksum::Koopman8;
+///
+/// let mut hasher = Koopman8::new();
+/// hasher.update(b"Hello, ");
+/// hasher.update(b"World!");
+/// let checksum = hasher.finalize();
+/// ```
+#[derive(Clone, Debug)]
+pub struct Koopman8 {
+    sum: u32,
+    modulus: u32,
+    seed: u32,
+    len: u64,
+    initialized: bool,
+    use_fast_mod: bool,
+}
+
+impl_streaming_hasher!(
+    Koopman8, u32, u8,
+    MODULUS_8, NonZeroU32,
+    1, fast_mod_253
+);
+
+/// Incremental Koopman16 checksum calculator.
+///
+/// Allows computing checksums over data that arrives in chunks.
+/// Uses fast modular reduction when using the default modulus.
+///
+/// # Example
+/// ```rust
+/// use koopman_checksum::Koopman16;
+///
+/// let mut hasher = Koopman16::new();
+/// hasher.update(b"Hello, ");
+/// hasher.update(b"World!");
+/// let checksum = hasher.finalize();
+/// ```
+#[derive(Clone, Debug)]
+pub struct Koopman16 {
+    sum: u32,
+    modulus: u32,
+    seed: u32,
+    len: u64,
+    initialized: bool,
+    use_fast_mod: bool,
+}
+
+impl_streaming_hasher!(
+    Koopman16, u32, u16,
+    MODULUS_16, NonZeroU32,
+    2, fast_mod_65519, fold_wide_65519
+);
+
+/// Opaque, resumable snapshot of a [`Koopman16`] hasher.
+///
+/// Captures the running sum, whether the first byte has been folded, the
+/// number of bytes processed, the seed that [`reset`](Koopman16::reset)
+/// returns to, and the modulus, so a hasher built with
+/// [`with_modulus`](Koopman16::with_modulus) resumes with the same one.
+///
+/// # Wire Format
+/// [`to_bytes`](Self::to_bytes) produces 18 bytes:
+///
+/// | Offset | Size | Field                        |
+/// |--------|------|------------------------------|
+/// | 0      | 4    | `sum`, big-endian            |
+/// | 4      | 8    | `len`, big-endian            |
+/// | 12     | 1    | `initialized` (0 or 1)       |
+/// | 13     | 1    | `seed`                       |
+/// | 14     | 4    | `modulus`, big-endian        |
+///
+/// # Example
+/// ```rust
+/// use koopman_checksum::{koopman16, Koopman16, KoopmanState16};
+///
+/// let data = b"distributed data";
+/// let mut hasher = Koopman16::with_seed(0xee);
+/// hasher.update(&data[..8]);
+/// let bytes = hasher.into_state().to_bytes();
+///
+/// let state = KoopmanState16::from_bytes(&bytes).unwrap();
+/// let mut hasher = Koopman16::from_state(state);
+/// hasher.update(&data[8..]);
+/// assert_eq!(hasher.finalize(), koopman16(data, 0xee));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct KoopmanState16 {
+    sum: u32,
+    len: u64,
+    initialized: bool,
+    seed: u8,
+    modulus: u32,
+}
+
+impl KoopmanState16 {
+    /// Size of the encoded state in bytes.
+    pub const ENCODED_LEN: usize = 18;
+
+    /// Encode the state into its stable wire format.
+    #[must_use]
+    pub fn to_bytes(&self) -> [u8; Self::ENCODED_LEN] {
+        let mut out = [0u8; Self::ENCODED_LEN];
+        out[0..4].copy_from_slice(&self.sum.to_be_bytes());
+        out[4..12].copy_from_slice(&self.len.to_be_bytes());
+        out[12] = self.initialized as u8;
+        out[13] = self.seed;
+        out[14..18].copy_from_slice(&self.modulus.to_be_bytes());
+        out
+    }
+
+    /// Decode a state from its wire format.
+    ///
+    /// Returns `None` if the flag byte is not 0 or 1, if the modulus is zero,
+    /// or if the sum is not a valid residue for the modulus once data has
+    /// been folded.
+    #[must_use]
+    pub fn from_bytes(bytes: &[u8; Self::ENCODED_LEN]) -> Option<Self> {
+        let sum = u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+        let mut len_bytes = [0u8; 8];
+        len_bytes.copy_from_slice(&bytes[4..12]);
+        let len = u64::from_be_bytes(len_bytes);
+        let initialized = match bytes[12] {
+            0 => false,
+            1 => true,
+            _ => return None,
+        };
+        let modulus = u32::from_be_bytes([bytes[14], bytes[15], bytes[16], bytes[17]]);
+        if modulus == 0 {
+            return None;
+        }
+
+        // Before the first byte the sum holds the (8-bit) seed
+        let limit = if initialized { modulus } else { 256 };
+        if sum >= limit {
+            return None;
+        }
+
+        Some(Self {
+            sum,
+            len,
+            initialized,
+            seed: bytes[13],
+            modulus,
+        })
+    }
+}
+
+impl Koopman16 {
+    /// Create a new hasher seeded with [`DEFAULT_SEED`].
+    ///
+    /// Unlike [`new`](Self::new) and [`Default`], which use seed 0.
+    ///
+    /// # Example
+    /// ```rust
+    /// use koopman_checksum::{koopman16, Koopman16, DEFAULT_SEED};
+    ///
+    /// let mut hasher = Koopman16::default_seeded();
+    /// hasher.update(b"data");
+    /// assert_eq!(hasher.finalize(), koopman16(b"data", DEFAULT_SEED));
+    /// ```
+    #[inline]
+    pub fn default_seeded() -> Self {
+        Self::with_seed(DEFAULT_SEED)
+    }
+
+    /// Create a hasher that emits either plain or parity-packed output.
+    ///
+    /// Shorthand for [`Koopman16Hybrid::new`]; see there for the format of
+    /// each mode.
+    ///
+    /// # Example
+    /// ```rust
+    /// use koopman_checksum::{koopman16, koopman16p, Koopman16};
+    ///
+    /// for parity in [false, true] {
+    ///     let mut hasher = Koopman16::with_parity(parity);
+    ///     hasher.update(b"data");
+    ///     let expected = if parity { koopman16p(b"data", 0) } else { koopman16(b"data", 0) };
+    ///     assert_eq!(hasher.finalize(), expected);
+    /// }
+    /// ```
+    #[inline]
+    pub fn with_parity(parity: bool) -> Koopman16Hybrid {
+        Koopman16Hybrid::new(parity)
+    }
+
+    /// Fold another checksum into this hasher as its big-endian bytes.
+    ///
+    /// Equivalent to `update(&checksum.to_be_bytes())`, for tree-of-checksums
+    /// constructions where child checksums are appended to a parent.
+    ///
+    /// # Example
+    /// ```rust
+    /// use koopman_checksum::{koopman16, Koopman16};
+    ///
+    /// let mut parent = Koopman16::new();
+    /// parent.update_checksum(koopman16(b"left", 0));
+    /// parent.update_checksum(koopman16(b"right", 0));
+    /// let root = parent.finalize();
+    /// ```
+    #[inline]
+    pub fn update_checksum(&mut self, checksum: u16) {
+        self.update(&checksum.to_be_bytes());
+    }
+
+    /// Update the checksum with the filled prefix of an uninitialized buffer.
+    ///
+    /// Folds `buf[..filled]` without requiring the buffer to be zeroed first,
+    /// e.g. after a read into `[MaybeUninit<u8>; N]` that reports how many
+    /// bytes it wrote.
+    ///
+    /// # Panics
+    /// Panics if `filled > buf.len()`.
+    ///
+    /// # Safety
+    /// The caller must guarantee that every byte in `buf[..filled]` has been
+    /// initialized. Bytes at and after `filled` are never read.
+    ///
+    /// # Example
+    /// ```rust
+    /// use core::mem::MaybeUninit;
+    /// use koopman_checksum::{koopman16, Koopman16};
+    ///
+    /// let mut buf = [MaybeUninit::<u8>::uninit(); 16];
+    /// for (slot, &byte) in buf.iter_mut().zip(b"hello") {
+    ///     slot.write(byte);
+    /// }
+    ///
+    /// let mut hasher = Koopman16::new();
+    /// // SAFETY: the first 5 bytes were written above
+    /// unsafe { hasher.update_init(&buf, 5) };
+    /// assert_eq!(hasher.finalize(), koopman16(b"hello", 0));
+    /// ```
+    #[cfg(feature = "uninit")]
+    #[inline]
+    #[track_caller]
+    pub unsafe fn update_init(&mut self, buf: &[core::mem::MaybeUninit<u8>], filled: usize) {
+        let filled = &buf[..filled];
+        // SAFETY: `MaybeUninit<u8>` has the same layout as `u8`, and the caller
+        // guarantees the `filled` prefix is initialized.
+        let data = unsafe { core::slice::from_raw_parts(filled.as_ptr().cast::<u8>(), filled.len()) };
+        self.update(data);
+    }
+
+    /// Like [`update`](Self::update), but return how many modular reductions
+    /// folding `data` performed.
+    ///
+    /// The count is tallied where each reduction happens, so it reflects the
+    /// path taken: one per 64-byte chunk on the SIMD path (`simd` feature),
+    /// one per eight-byte fold, and one per remaining byte. A custom modulus
+    /// or the `small` feature reduces once per byte. The first byte of the
+    /// message is only XORed with the seed and is never reduced. Only
+    /// available with the `profiling` feature.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #[cfg(all(feature = "profiling", not(feature = "small")))] {
+    /// use koopman_checksum::Koopman16;
+    ///
+    /// let mut hasher = Koopman16::new();
+    /// // One eight-byte fold and one remaining byte
+    /// assert_eq!(hasher.update_instrumented(b"abcdefghij"), 2);
+    /// # }
+    /// ```
+    #[cfg(feature = "profiling")]
+    pub fn update_instrumented(&mut self, data: &[u8]) -> usize {
+        let mut reductions = Reductions::default();
+        self.update_counted(data, &mut reductions);
+        reductions.count
+    }
+
+    /// Create a hasher that re-checks its state against the one-shot
+    /// [`koopman16`] after every update, for chasing streaming desync bugs.
+    ///
+    /// Only available with the `test-util` and `alloc` features; see
+    /// [`test_util::Oracle16`].
+    #[cfg(all(feature = "test-util", feature = "alloc"))]
+    pub fn with_oracle() -> test_util::Oracle16 {
+        test_util::Oracle16::with_seed(0)
+    }
+
+    /// Fold `count` implicit zero bytes into the running sum.
+    ///
+    /// Has the effect of the shift-and-reduce step that
+    /// [`finalize`](Self::finalize) uses for its implicit zeros, repeated
+    /// `count` times, for experimenting with frame layouts and inspecting
+    /// intermediate states via [`running_sum`](Self::running_sum). The zeros
+    /// are folded in one multiplication by `256^count`, computed in
+    /// `O(log count)` steps, so any count is cheap. `finalize` still appends
+    /// its standard two zero bytes afterwards. Zero bytes folded here are not
+    /// data: they do not count towards
+    /// [`bytes_processed`](Self::bytes_processed), and before any data has
+    /// been folded this does nothing.
+    ///
+    /// # Example
+    /// ```rust
+    /// use koopman_checksum::{koopman16, Koopman16};
+    ///
+    /// let mut hasher = Koopman16::new();
+    /// hasher.update(b"data");
+    /// hasher.append_zeros(2);
+    /// assert_eq!(hasher.running_sum(), koopman16(b"data", 0) as u32);
+    /// ```
+    pub fn append_zeros(&mut self, count: usize) {
+        if !self.initialized || count == 0 {
+            return;
+        }
+        // Each zero multiplies the sum by 256; square-and-multiply for 256^count
+        let modulus = self.modulus as u64;
+        let (mut factor, mut base, mut n) = (1, 256 % modulus, count);
+        while n > 0 {
+            if n & 1 == 1 {
+                factor = factor * base % modulus;
+            }
+            base = base * base % modulus;
+            n >>= 1;
+        }
+        self.sum = (self.sum as u64 * factor % modulus) as u32;
+    }
+
+    /// The running sum before finalization.
+    ///
+    /// Before any data has been folded this is the seed.
+    #[inline]
+    #[must_use]
+    pub fn running_sum(&self) -> u32 {
+        self.sum
+    }
+
+    /// Snapshot the hasher as a [`KoopmanState16`] token.
+    #[inline]
+    #[must_use]
+    pub fn into_state(self) -> KoopmanState16 {
+        KoopmanState16 {
+            sum: self.sum,
+            len: self.len,
+            initialized: self.initialized,
+            seed: self.seed as u8,
+            modulus: self.modulus,
+        }
+    }
+
+    /// Resume a hasher from a [`KoopmanState16`] token.
+    ///
+    /// The restored hasher uses the token's modulus and seed, which
+    /// [`reset`](Self::reset) restores.
+    #[inline]
+    pub fn from_state(state: KoopmanState16) -> Self {
+        Self {
+            sum: state.sum,
+            modulus: state.modulus,
+            seed: state.seed as u32,
+            len: state.len,
+            initialized: state.initialized,
+            use_fast_mod: state.modulus == MODULUS_16,
+        }
+        .traced()
+    }
+
+    /// Create a hasher with a custom seed and feed it a first chunk of data.
+    ///
+    /// Shorthand for [`with_seed`](Self::with_seed) followed by
+    /// [`update`](Self::update); the hasher can take more data or be finalized.
+    ///
+    /// # Example
+    /// ```rust
+    /// use koopman_checksum::{koopman16, Koopman16};
+    ///
+    /// let mut hasher = Koopman16::from_slice(b"Hello, ", 0xee);
+    /// hasher.update(b"World!");
+    /// assert_eq!(hasher.finalize(), koopman16(b"Hello, World!", 0xee));
+    /// ```
+    #[inline]
+    pub fn from_slice(data: &[u8], seed: u8) -> Self {
+        let mut hasher = Self::with_seed(seed);
+        hasher.update(data);
+        hasher
+    }
+
+    /// Hash `data[..mid]` and `data[mid..]` as two independent partials that
+    /// [`combine`](Self::combine) back into the checksum of all of `data`.
+    ///
+    /// The left partial uses `seed`. The right partial uses the identity seed
+    /// 0, since only the first byte of the whole message is XORed with the
+    /// seed; if `mid` is 0 the left partial is empty and the right one uses
+    /// `seed` instead. Each partial carries its length, which `combine` needs.
+    /// The partials can be split further for recursive decomposition.
+    ///
+    /// # Panics
+    /// Panics if `mid > data.len()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use koopman_checksum::{koopman16, Koopman16};
+    ///
+    /// let (left, right) = Koopman16::split_at(b"test data", 4, 0xee);
+    /// assert_eq!(left.combine(&right).finalize(), koopman16(b"test data", 0xee));
+    /// ```
+    #[track_caller]
+    pub fn split_at(data: &[u8], mid: usize, seed: u8) -> (Self, Self) {
+        let (head, tail) = data.split_at(mid);
+        let right_seed = if head.is_empty() { seed } else { 0 };
+        (Self::from_slice(head, seed), Self::from_slice(tail, right_seed))
+    }
+
+    /// Append a partial hashed over the data that follows this hasher's data.
+    ///
+    /// `right` must have been built with seed 0 (as by
+    /// [`split_at`](Self::split_at)) unless this hasher has not folded any
+    /// data, in which case `right` is returned as-is. Folding `n` more bytes
+    /// multiplies the running sum by `256^n`, so the combined sum is
+    /// `self * 256^right.len + right` modulo the modulus, computed in
+    /// `O(log n)`.
+    ///
+    /// # Panics
+    /// Panics if the two hashers use different moduli.
+    #[must_use]
+    #[track_caller]
+    pub fn combine(&self, right: &Self) -> Self {
+        assert_eq!(self.modulus, right.modulus, "cannot combine hashers with different moduli");
+        if !self.initialized {
+            return right.clone();
+        }
+        if !right.initialized {
+            return self.clone();
+        }
+
+        let modulus = self.modulus as u64;
+        let mut shift = 1u64;
+        let mut base = 256 % modulus;
+        let mut exp = right.len;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                shift = shift * base % modulus;
+            }
+            base = base * base % modulus;
+            exp >>= 1;
+        }
+
+        let mut combined = self.clone();
+        combined.sum = ((self.sum as u64 * shift + right.sum as u64) % modulus) as u32;
+        combined.len += right.len;
+        combined
+    }
+
+    /// Resume from another implementation's raw running sum and length.
+    ///
+    /// `sum` is the pre-finalization register after folding `len` bytes with
+    /// [`MODULUS_16`]: the first byte XORed with the seed, then
+    /// `sum = ((sum << 8) + byte) % 65519` for each later byte, and none of
+    /// the two implicit zero bytes that finalization appends. It is therefore
+    /// always below [`MODULUS_16`]. If `len` is 0 no data has been folded and
+    /// `sum` is the seed, which must fit in a byte. The seed cannot be
+    /// recovered from a register that has folded data, so after
+    /// [`reset`](Self::reset) such a hasher starts over with seed 0.
+    ///
+    /// # Example
+    /// ```rust
+    /// use koopman_checksum::{koopman16, Koopman16};
+    ///
+    /// let mut hasher = Koopman16::with_seed(0xee);
+    /// hasher.update(b"Hello, ");
+    /// let (sum, len) = (hasher.running_sum() as u16, hasher.bytes_processed() as usize);
+    ///
+    /// let mut resumed = Koopman16::from_raw(sum, len);
+    /// resumed.update(b"World!");
+    /// assert_eq!(resumed.finalize(), koopman16(b"Hello, World!", 0xee));
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn from_raw(sum: u16, len: usize) -> Self {
+        debug_assert!(
+            if len == 0 { sum <= 0xFF } else { (sum as u32) < MODULUS_16 },
+            "raw sum {} is not a valid register for length {}",
+            sum,
+            len,
+        );
+        Self::from_state(KoopmanState16 {
+            sum: sum as u32,
+            len: len as u64,
+            initialized: len > 0,
+            seed: if len > 0 { 0 } else { sum as u8 },
+            modulus: MODULUS_16,
+        })
+    }
+}
+
+/// Incremental Koopman16 checksum with the default modulus hardcoded.
+///
+/// [`Koopman16`] carries its modulus, a fast-path flag, and a byte count so
+/// it can be configured at runtime. This type drops all of that: it always
+/// uses [`MODULUS_16`] and the fast reduction (the generic `%` with the
+/// `small` feature), with no branch on configuration, and is a third the
+/// size. It produces the same checksums as [`koopman16`] and a default
+/// [`Koopman16`].
+///
+/// # Example
+/// ```rust
+/// use koopman_checksum::{koopman16, Koopman16Default};
+///
+/// let mut hasher = Koopman16Default::with_seed(0xee);
+/// hasher.update(b"Hello, ");
+/// hasher.update(b"World!");
+/// assert_eq!(hasher.finalize(), koopman16(b"Hello, World!", 0xee));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Koopman16Default {
+    sum: u32,
+    seed: u8,
+    initialized: bool,
+}
+
+impl Koopman16Default {
+    /// Create a new hasher with seed 0.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new hasher with an initial seed.
+    #[inline]
+    pub fn with_seed(seed: u8) -> Self {
+        Self {
+            sum: seed as u32,
+            seed,
+            initialized: false,
+        }
+    }
+
+    /// Update the checksum with more data.
+    #[cfg_attr(not(feature = "small"), inline)]
+    pub fn update(&mut self, data: &[u8]) {
+        let rest = match data {
+            [] => return,
+            [first, rest @ ..] if !self.initialized => {
+                self.sum ^= *first as u32;
+                self.initialized = true;
+                rest
+            }
+            _ => data,
+        };
+
+        let mut sum = self.sum;
+        if cfg!(feature = "small") {
+            for &byte in rest {
+                sum = ((sum << 8) + byte as u32) % MODULUS_16;
+            }
+        } else {
+            // The same wide fold as `Koopman16`, then the remainder byte by byte
+            let (folded, rest) = fold_wide_65519(sum, rest, &mut Reductions::default());
+            sum = folded;
+            for &byte in rest {
+                sum = fast_mod_65519((sum << 8) + byte as u32);
+            }
+        }
+        self.sum = sum;
+    }
+
+    /// Finalize and return the checksum.
+    ///
+    /// Returns 0 if no data was provided.
+    #[cfg_attr(not(feature = "small"), inline)]
+    #[must_use]
+    pub fn finalize(self) -> u16 {
+        if !self.initialized {
+            return 0;
+        }
+        if cfg!(feature = "small") {
+            let sum = (self.sum << 8) % MODULUS_16;
+            return ((sum << 8) % MODULUS_16) as u16;
+        }
+        let sum = fast_mod_65519(self.sum << 8);
+        fast_mod_65519(sum << 8) as u16
+    }
+
+    /// Reset the hasher to its initial state, keeping the seed.
+    #[inline]
+    pub fn reset(&mut self) {
+        *self = Self::with_seed(self.seed);
+    }
+}
+
+impl KoopmanHasherState for Koopman16Default {
+    type Output = u16;
+
+    #[inline]
+    fn update(&mut self, data: &[u8]) {
+        Koopman16Default::update(self, data);
+    }
+
+    #[inline]
+    fn finalize(self) -> u16 {
+        Koopman16Default::finalize(self)
+    }
+
+    #[inline]
+    fn reset(&mut self) {
+        Koopman16Default::reset(self);
+    }
+}
+
+/// Incremental Koopman32 checksum calculator.
+///
+/// Allows computing checksums over data that arrives in chunks.
+/// Uses fast modular reduction when using the default modulus.
+///
+/// # Example
+/// ```rust
+/// use koopman_checksum::Koopman32;
+///
+/// let mut hasher = Koopman32::new();
+/// hasher.update(b"Hello, ");
+/// hasher.update(b"World!");
+/// let checksum = hasher.finalize();
+/// ```
+#[derive(Clone, Debug)]
+pub struct Koopman32 {
+    sum: u64,
+    modulus: u64,
+    seed: u64,
+    len: u64,
+    initialized: bool,
+    use_fast_mod: bool,
+}
+
+impl_streaming_hasher!(
+    Koopman32, u64, u32,
+    MODULUS_32, NonZeroU64,
+    4, fast_mod_4294967291, fold8_4294967291
+);
+
+// ============================================================================
+// Parity Streaming API
+// ============================================================================
+
+/// Macro to generate streaming parity checksum structs.
+macro_rules! impl_streaming_parity_hasher {
+    (
+        $name:ident,
+        $sum_type:ty,
+        $output_type:ty,
+        $default_modulus_raw:expr,
+        $nonzero_type:ty,
+        $finalize_shifts:expr,
+        $fast_mod:expr,
+        $fold_bytes:expr,
+        $max_modulus:expr
+    ) => {
+        impl Default for $name {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        /// Finalize the consumed hasher, so `let checksum: T = hasher.into();`
+        /// and `.map(Into::into)` work.
+        impl From<$name> for $output_type {
+            #[inline]
+            fn from(hasher: $name) -> Self {
+                hasher.finalize()
+            }
+        }
+
+        impl KoopmanHasherState for $name {
+            type Output = $output_type;
+
+            #[inline]
+            fn update(&mut self, data: &[u8]) {
+                $name::update(self, data);
+            }
+
+            #[inline]
+            fn finalize(self) -> Self::Output {
+                $name::finalize(self)
+            }
+
+            #[inline]
+            fn reset(&mut self) {
+                $name::reset(self);
+            }
+        }
+
+        impl $name {
+            /// Create a new hasher with the default modulus.
+            #[inline]
+            pub fn new() -> Self {
+                Self {
+                    sum: 0,
+                    psum: 0,
+                    modulus: $default_modulus_raw,
+                    seed: 0,
+                    len: 0,
+                    initialized: false,
+                    use_fast_mod: true,
+                }
+                .traced()
+            }
+
+            /// Create a new hasher with a custom modulus.
+            ///
+            /// # Arguments
+            /// * `modulus` - The modulus to use. Must be non-zero, and small enough
+            ///   that residues fit the checksum portion of the parity packing.
+            ///   Debug builds assert this bound.
+            #[inline]
+            #[track_caller]
+            pub fn with_modulus(modulus: $nonzero_type) -> Self {
+                debug_assert!(
+                    modulus.get() <= $max_modulus,
+                    "modulus exceeds the checksum portion of the parity packing"
+                );
+                Self {
+                    sum: 0,
+                    psum: 0,
+                    modulus: modulus.get(),
+                    seed: 0,
+                    len: 0,
+                    initialized: false,
+                    use_fast_mod: modulus.get() == $default_modulus_raw,
+                }
+                .traced()
+            }
+
+            /// Create a new hasher with an initial seed.
+            #[inline]
+            pub fn with_seed(seed: u8) -> Self {
+                Self {
+                    sum: seed as $sum_type,
+                    psum: seed,
+                    modulus: $default_modulus_raw,
+                    seed: seed as $sum_type,
+                    len: 0,
+                    initialized: false,
+                    use_fast_mod: true,
+                }
+                .traced()
+            }
+
+            /// Log the reduction strategy under the `trace` feature.
+            #[inline(always)]
+            fn traced(self) -> Self {
+                #[cfg(feature = "trace")]
+                log::debug!(
+                    "{}: modulus {}, {} reduction, use_fast_mod = {}",
+                    stringify!($name),
+                    self.modulus,
+                    if self.use_fast_mod && !cfg!(feature = "small") { "fast" } else { "generic" },
+                    self.use_fast_mod,
+                );
+                self
+            }
+
+            /// Update the checksum with more data.
+            #[cfg_attr(not(feature = "small"), inline)]
+            pub fn update(&mut self, data: &[u8]) {
+                debug_assert!(
+                    !self.use_fast_mod || self.modulus == $default_modulus_raw,
+                    "fast reduction enabled for non-default modulus {}",
+                    self.modulus,
+                );
+                let rest = match data {
+                    [] => return,
+                    [first, rest @ ..] if !self.initialized => {
+                        self.sum ^= *first as $sum_type;
+                        self.psum ^= *first;
+                        self.initialized = true;
+                        rest
+                    }
+                    _ => data,
+                };
+                self.len += data.len() as u64;
+
+                if self.use_fast_mod && !cfg!(feature = "small") {
+                    // Fold several bytes per reduction; the fast reductions
+                    // accept the wider intermediates
+                    let mut sum = self.sum;
+                    let mut psum = self.psum;
+                    let mut chunks = rest.chunks_exact($fold_bytes);
+                    for chunk in &mut chunks {
+                        let mut word: $sum_type = 0;
+                        for &byte in chunk {
+                            word = (word << 8) | byte as $sum_type;
+                            psum ^= byte;
+                        }
+                        sum = $fast_mod((sum << (8 * $fold_bytes)) + word);
+                    }
+                    for &byte in chunks.remainder() {
+                        sum = $fast_mod((sum << 8) + byte as $sum_type);
+                        psum ^= byte;
+                    }
+                    self.sum = sum;
+                    self.psum = psum;
+                } else {
+                    for &byte in rest {
+                        self.sum = ((self.sum << 8) + byte as $sum_type) % self.modulus;
+                        self.psum ^= byte;
+                    }
+                }
+            }
+
+            /// Finalize and return the checksum with parity.
+            ///
+            /// Returns 0 if no data was provided.
+            #[cfg_attr(not(feature = "small"), inline)]
+            #[must_use]
+            pub fn finalize(self) -> $output_type {
+                if !self.initialized {
+                    return 0;
+                }
+                let mut sum = self.sum;
+                if self.use_fast_mod && !cfg!(feature = "small") {
+                    for _ in 0..$finalize_shifts {
+                        sum = $fast_mod(sum << 8);
+                    }
+                } else {
+                    for _ in 0..$finalize_shifts {
+                        sum = (sum << 8) % self.modulus;
+                    }
+                }
+                // Pack: checksum in upper bits, parity in LSB
+                ((sum as $output_type) << 1) | (parity8(self.psum) as $output_type)
+            }
+
+            /// Return the packed checksum of the data so far without consuming the hasher.
+            ///
+            /// Equivalent to finalizing a clone; further updates are unaffected.
+            #[inline]
+            #[must_use]
+            pub fn peek(&self) -> $output_type {
+                self.clone().finalize()
+            }
+
+            /// Like [`peek`](Self::peek), but split into the checksum portion
+            /// (upper bits, shifted down) and the parity bit.
+            #[inline]
+            #[must_use]
+            pub fn peek_split(&self) -> ($output_type, bool) {
+                let packed = self.peek();
+                (packed >> 1, packed & 1 == 1)
+            }
+
+            /// The modulus this hasher was configured with.
+            #[inline]
+            #[must_use]
+            pub fn modulus(&self) -> $sum_type {
+                self.modulus
+            }
+
+            /// Number of data bytes folded since construction or the last [`reset`](Self::reset).
+            #[inline]
+            #[must_use]
+            pub fn bytes_processed(&self) -> u64 {
+                self.len
+            }
+
+            /// Reset the hasher to initial state.
+            ///
+            /// Preserves the configuration (seed and modulus). Clears the
+            /// accumulated state (sum, parity, byte count, and whether the first
+            /// byte has been folded).
+            #[inline]
+            pub fn reset(&mut self) {
+                self.sum = self.seed;
+                self.psum = self.seed as u8;
+                self.len = 0;
+                self.initialized = false;
+            }
+        }
+    };
+}
+
+/// Incremental Koopman8P checksum calculator (7-bit checksum + 1 parity bit).
+///
+/// Allows computing checksums over data that arrives in chunks.
+///
+/// # Example
+/// ```rust
+/// use koopman_checksum::Koopman8P;
+///
+/// let mut hasher = Koopman8P::new();
+/// hasher.update(b"Hello");
+/// let checksum = hasher.finalize();
+/// let parity_bit = checksum & 1;
+/// ```
+#[derive(Clone, Debug)]
+pub struct Koopman8P {
+    sum: u32,
+    psum: u8,
+    modulus: u32,
+    seed: u32,
+    len: u64,
+    initialized: bool,
+    use_fast_mod: bool,
+}
+
+impl_streaming_parity_hasher!(
+    Koopman8P, u32, u8,
+    MODULUS_7P, NonZeroU32,
+    // A constant `%` compiles to a multiply and shifts, unlike the generic
+    // path's division by the runtime modulus
+    1, |x: u32| x % MODULUS_7P, 1, MAX_MODULUS_7P
+);
+
+/// Incremental Koopman16P checksum calculator (15-bit checksum + 1 parity bit).
+///
+/// Allows computing checksums over data that arrives in chunks.
+///
+/// # Example
+/// ```rust
+/// use koopman_checksum::Koopman16P;
+///
+/// let mut hasher = Koopman16P::new();
+/// hasher.update(b"Hello, ");
+/// hasher.update(b"World!");
+/// let checksum = hasher.finalize();
+/// let parity_bit = checksum & 1;
+/// ```
+#[derive(Clone, Debug)]
+pub struct Koopman16P {
+    sum: u32,
+    psum: u8,
+    modulus: u32,
+    seed: u32,
+    len: u64,
+    initialized: bool,
+    use_fast_mod: bool,
+}
+
+impl_streaming_parity_hasher!(
+    Koopman16P, u32, u16,
+    MODULUS_15P, NonZeroU32,
+    2, fast_mod_32749, 2, MAX_MODULUS_15P
+);
+
+/// Incremental 16-bit Koopman checksum whose output format is chosen at
+/// construction.
+///
+/// Generic code can hold one type whether the checksum is plain or
+/// parity-packed. [`Plain`](Self::Plain) computes [`koopman16`].
+/// [`Parity`](Self::Parity) computes [`koopman16p`]: a 15-bit checksum over
+/// [`MODULUS_15P`] in the upper bits with the parity of all data bytes in the
+/// LSB. Giving a bit to parity halves the checksum range, so all 1-3 bit
+/// errors are detected only up to [`KOOPMAN16P_MAX_LEN`] bytes instead of all
+/// 1-2 bit errors up to [`KOOPMAN16_MAX_LEN`].
+///
+/// # Example
+/// ```rust
+/// use koopman_checksum::{koopman16p, Koopman16Hybrid};
+///
+/// let mut hasher = Koopman16Hybrid::with_seed(0xee, true);
+/// hasher.update(b"Hello, ");
+/// hasher.update(b"World!");
+/// assert_eq!(hasher.finalize(), koopman16p(b"Hello, World!", 0xee));
+/// ```
+#[derive(Clone, Debug)]
+pub enum Koopman16Hybrid {
+    /// Plain 16-bit checksum.
+    Plain(Koopman16),
+    /// 15-bit checksum with a parity bit in the LSB.
+    Parity(Koopman16P),
+}
+
+impl Koopman16Hybrid {
+    /// Create a new hasher with seed 0 and the default modulus of the mode.
+    #[inline]
+    pub fn new(parity: bool) -> Self {
+        Self::with_seed(0, parity)
+    }
+
+    /// Create a new hasher with an initial seed and the default modulus of
+    /// the mode.
+    #[inline]
+    pub fn with_seed(seed: u8, parity: bool) -> Self {
+        if parity {
+            Self::Parity(Koopman16P::with_seed(seed))
+        } else {
+            Self::Plain(Koopman16::with_seed(seed))
+        }
+    }
+
+    /// Whether the output is parity-packed.
+    #[inline]
+    #[must_use]
+    pub fn is_parity(&self) -> bool {
+        matches!(self, Self::Parity(_))
+    }
+
+    /// Update the checksum with more data.
+    #[inline]
+    pub fn update(&mut self, data: &[u8]) {
+        match self {
+            Self::Plain(hasher) => hasher.update(data),
+            Self::Parity(hasher) => hasher.update(data),
+        }
+    }
+
+    /// Finalize and return the checksum, parity-packed in parity mode.
+    ///
+    /// Returns 0 if no data was provided.
+    #[inline]
+    #[must_use]
+    pub fn finalize(self) -> u16 {
+        match self {
+            Self::Plain(hasher) => hasher.finalize(),
+            Self::Parity(hasher) => hasher.finalize(),
+        }
+    }
+
+    /// Return the checksum of the data so far without consuming the hasher.
+    #[inline]
+    #[must_use]
+    pub fn peek(&self) -> u16 {
+        match self {
+            Self::Plain(hasher) => hasher.peek(),
+            Self::Parity(hasher) => hasher.peek(),
+        }
+    }
+
+    /// The modulus this hasher was configured with.
+    #[inline]
+    #[must_use]
+    pub fn modulus(&self) -> u32 {
+        match self {
+            Self::Plain(hasher) => hasher.modulus(),
+            Self::Parity(hasher) => hasher.modulus(),
+        }
+    }
+
+    /// Longest data length in bytes with a known error-detection guarantee
+    /// for the mode and modulus; see [`Koopman16::max_len`].
+    #[inline]
+    #[must_use]
+    pub fn max_len(&self) -> usize {
+        match self {
+            Self::Plain(hasher) => hasher.max_len(),
+            Self::Parity(hasher) => hasher.max_len(),
+        }
+    }
+
+    /// Number of data bytes folded since construction or the last [`reset`](Self::reset).
+    #[inline]
+    #[must_use]
+    pub fn bytes_processed(&self) -> u64 {
+        match self {
+            Self::Plain(hasher) => hasher.bytes_processed(),
+            Self::Parity(hasher) => hasher.bytes_processed(),
+        }
+    }
+
+    /// Reset the hasher to initial state, keeping the seed, modulus, and mode.
+    #[inline]
+    pub fn reset(&mut self) {
+        match self {
+            Self::Plain(hasher) => hasher.reset(),
+            Self::Parity(hasher) => hasher.reset(),
+        }
+    }
+}
+
+impl From<Koopman16> for Koopman16Hybrid {
+    #[inline]
+    fn from(hasher: Koopman16) -> Self {
+        Self::Plain(hasher)
+    }
+}
+
+impl From<Koopman16P> for Koopman16Hybrid {
+    #[inline]
+    fn from(hasher: Koopman16P) -> Self {
+        Self::Parity(hasher)
+    }
+}
+
+/// Finalize the consumed hasher, so `let checksum: u16 = hasher.into();`
+/// works.
+impl From<Koopman16Hybrid> for u16 {
+    #[inline]
+    fn from(hasher: Koopman16Hybrid) -> Self {
+        hasher.finalize()
+    }
+}
+
+impl KoopmanHasherState for Koopman16Hybrid {
+    type Output = u16;
+
+    #[inline]
+    fn update(&mut self, data: &[u8]) {
+        Koopman16Hybrid::update(self, data);
+    }
+
+    #[inline]
+    fn finalize(self) -> Self::Output {
+        Koopman16Hybrid::finalize(self)
+    }
+
+    #[inline]
+    fn reset(&mut self) {
+        Koopman16Hybrid::reset(self);
+    }
+}
+
+/// Incremental Koopman32P checksum calculator (31-bit checksum + 1 parity bit).
+///
+/// Allows computing checksums over data that arrives in chunks.
+///
+/// # Example
+/// ```rust
+/// use koopman_checksum::Koopman32P;
+///
+/// let mut hasher = Koopman32P::new();
+/// hasher.update(b"Hello, ");
+/// hasher.update(b"World!");
+/// let checksum = hasher.finalize();
+/// let parity_bit = checksum & 1;
+/// ```
+#[derive(Clone, Debug)]
+pub struct Koopman32P {
+    sum: u64,
+    psum: u8,
+    modulus: u64,
+    seed: u64,
+    len: u64,
+    initialized: bool,
+    use_fast_mod: bool,
+}
+
+impl_streaming_parity_hasher!(
+    Koopman32P, u64, u32,
+    MODULUS_31P, NonZeroU64,
+    4, fast_mod_2147483629, 4, MAX_MODULUS_31P
+);
+
+macro_rules! impl_one_shot_digest {
+    ($hasher:ident, $output_type:ty, $one_shot:ident) => {
+        impl $hasher {
+            #[doc = concat!("Compute the checksum of `data` in one call; same as [`", stringify!($one_shot), "`].")]
+            #[inline]
+            #[must_use]
+            pub fn digest(data: &[u8], initial_seed: u8) -> $output_type {
+                $one_shot(data, initial_seed)
+            }
+        }
+    };
+}
+
+impl_one_shot_digest!(Koopman8, u8, koopman8);
+impl_one_shot_digest!(Koopman16, u16, koopman16);
+impl_one_shot_digest!(Koopman32, u32, koopman32);
+impl_one_shot_digest!(Koopman8P, u8, koopman8p);
+impl_one_shot_digest!(Koopman16P, u16, koopman16p);
+impl_one_shot_digest!(Koopman32P, u32, koopman32p);
+
+// ============================================================================
+// Length-Checked Streaming
+// ============================================================================
+
+/// Streaming hasher wrapper that refuses to produce a checksum once the
+/// HD-safe length for the variant has been exceeded.
+///
+/// Data is always accepted by `update`; the guard is applied by
+/// [`try_finalize`](CheckedDigest::try_finalize), which returns
+/// [`KoopmanError::DataTooLong`] if more bytes were folded than the wrapped
+/// hasher's `max_len`, which depends on its modulus.
+///
+/// With the `digest` feature enabled, `CheckedDigest` implements
+/// `digest::Update` so it can be driven by code written against that trait.
+///
+/// # Example
+/// ```rust
+/// use koopman_checksum::{CheckedDigest, Koopman16, KoopmanError, KOOPMAN16_MAX_LEN};
+///
+/// let mut digest = CheckedDigest::new(Koopman16::with_seed(0xee));
+/// digest.update(&[0u8; KOOPMAN16_MAX_LEN]);
+/// digest.update(b"!");
+/// assert!(matches!(digest.try_finalize(), Err(KoopmanError::DataTooLong { .. })));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct CheckedDigest<H> {
+    inner: H,
+}
+
+impl<H> CheckedDigest<H> {
+    /// Wrap a configured hasher.
+    #[inline]
+    pub fn new(inner: H) -> Self {
+        Self { inner }
+    }
+}
+
+macro_rules! impl_checked_digest {
+    ($hasher:ident, $output_type:ty $(, $moduli:expr)?) => {
+        impl CheckedDigest<$hasher> {
+            /// Update the checksum with more data.
+            #[inline]
+            pub fn update(&mut self, data: &[u8]) {
+                self.inner.update(data);
+            }
+
+            /// Returns `true` once more than the HD-safe length has been folded.
+            #[inline]
+            #[must_use]
+            pub fn exceeded(&self) -> bool {
+                self.inner.bytes_processed() > self.inner.max_len() as u64
+            }
+
+            /// Finalize and return the checksum if the HD-safe length was respected.
+            ///
+            /// # Errors
+            /// Returns [`KoopmanError::DataTooLong`] if the guarantee no longer holds.
+            #[inline]
+            pub fn try_finalize(self) -> Result<$output_type, KoopmanError> {
+                if self.exceeded() {
+                    let len = usize::try_from(self.inner.bytes_processed()).unwrap_or(usize::MAX);
+                    return Err(KoopmanError::DataTooLong { len, max: self.inner.max_len() });
+                }
+                Ok(self.inner.finalize())
+            }
+        }
+
+        impl $hasher {
+            $(
+            /// Longest data length in bytes for which this hasher's
+            /// configuration has a known error-detection guarantee.
+            ///
+            /// This is the variant's `*_MAX_LEN` for the default modulus and
+            /// the tabulated length for the other moduli [`min_modulus_for_length`]
+            /// recommends. Any other modulus has no known guarantee, and the
+            /// limit is 0.
+            #[inline]
+            #[must_use]
+            pub fn max_len(&self) -> usize {
+                tabulated_max_len($moduli, self.modulus as u64)
+            }
+            )?
+
+            /// Update the checksum, refusing data that would take the total
+            /// past the HD-safe length for the configuration.
+            ///
+            /// Like [`CheckedDigest`], the limit is [`max_len`](Self::max_len),
+            /// so it follows the modulus. Unlike `CheckedDigest`, the check
+            /// happens before any data is folded.
+            ///
+            /// # Errors
+            /// Returns [`KoopmanError::DataTooLong`], with `len` the total that
+            /// would have been reached, if the guarantee would no longer hold.
+            /// The hasher is left unchanged.
+            pub fn try_update(&mut self, data: &[u8]) -> Result<(), KoopmanError> {
+                let total = self.bytes_processed().saturating_add(data.len() as u64);
+                let max = self.max_len();
+                if total > max as u64 {
+                    let len = usize::try_from(total).unwrap_or(usize::MAX);
+                    return Err(KoopmanError::DataTooLong { len, max });
+                }
+                self.update(data);
+                Ok(())
+            }
+        }
+
+        #[cfg(feature = "digest")]
+        impl digest::Update for CheckedDigest<$hasher> {
+            #[inline]
+            fn update(&mut self, data: &[u8]) {
+                self.inner.update(data);
+            }
+        }
+    };
+}
+
+impl_checked_digest!(Koopman8, u8, MODULI_8);
+impl_checked_digest!(Koopman16, u16, MODULI_16);
+impl_checked_digest!(Koopman32, u32, MODULI_32);
+impl_checked_digest!(Koopman8P, u8, MODULI_8P);
+impl_checked_digest!(Koopman16P, u16, MODULI_16P);
+impl_checked_digest!(Koopman32P, u32, MODULI_32P);
+impl_checked_digest!(Koopman16Hybrid, u16);
+
+/// [`Koopman16`] that calls a function the first time the stream grows past
+/// its [`max_len`](Koopman16::max_len), returned by [`Koopman16::on_overflow`].
+///
+/// The callback runs once, from the `update` that crosses the limit, after
+/// that data has been folded. Checksums are unaffected. Plain `Koopman16`
+/// has no watermark and pays nothing for this.
+#[derive(Clone)]
+pub struct OverflowWatch16<F> {
+    inner: Koopman16,
+    callback: Option<F>,
+}
+
+impl Koopman16 {
+    /// Watch this hasher's length and call `f` once when it first exceeds
+    /// [`max_len`](Self::max_len), where HD=3 is no longer guaranteed.
+    ///
+    /// Bytes already folded count toward the limit; if it is already
+    /// exceeded, `f` runs on the next `update`. The limit follows the
+    /// modulus, so with no known guarantee `f` runs on the first data.
+    ///
+    /// # Example
+    /// ```rust
+    /// use koopman_checksum::{Koopman16, KOOPMAN16_MAX_LEN};
+    ///
+    /// let mut warnings = 0;
+    /// let mut hasher = Koopman16::new().on_overflow(|| warnings += 1);
+    /// hasher.update(&[0u8; KOOPMAN16_MAX_LEN]);
+    /// hasher.update(b"!");
+    /// hasher.update(b"!");
+    /// let _ = hasher.finalize();
+    /// assert_eq!(warnings, 1);
+    /// ```
+    #[inline]
+    pub fn on_overflow<F: FnMut()>(self, f: F) -> OverflowWatch16<F> {
+        OverflowWatch16 {
+            inner: self,
+            callback: Some(f),
+        }
+    }
+}
+
+impl<F: FnMut()> OverflowWatch16<F> {
+    /// Update the checksum, calling the callback if this crosses the limit.
+    #[inline]
+    pub fn update(&mut self, data: &[u8]) {
+        self.inner.update(data);
+        if self.inner.bytes_processed() > self.inner.max_len() as u64 {
+            if let Some(mut callback) = self.callback.take() {
+                callback();
+            }
+        }
+    }
+
+    /// Returns `true` once the callback has fired.
+    #[inline]
+    #[must_use]
+    pub fn overflowed(&self) -> bool {
+        self.callback.is_none()
+    }
+
+    /// Finalize and return the checksum.
+    #[inline]
+    #[must_use]
+    pub fn finalize(self) -> u16 {
+        self.inner.finalize()
+    }
+
+    /// Unwrap the hasher, dropping the callback.
+    #[inline]
+    pub fn into_inner(self) -> Koopman16 {
+        self.inner
+    }
+}
+
+impl<F> core::fmt::Debug for OverflowWatch16<F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("OverflowWatch16")
+            .field("inner", &self.inner)
+            .field("overflowed", &self.callback.is_none())
+            .finish()
+    }
+}
+
+/// How much a 16-bit checksum can be trusted, from
+/// [`Koopman16::finalize_with_guarantee`] or
+/// [`Koopman16Hybrid::finalize_with_guarantee`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Guarantee {
+    /// The default modulus was used and the stream stayed within the
+    /// HD-safe length: all 1-2 bit errors are detected (1-3 in parity mode).
+    Hd3,
+    /// The stream outgrew the HD-safe length or a custom modulus was used.
+    /// The checksum is still valid, but some 2-bit errors may go undetected.
+    Degraded,
+}
+
+impl Koopman16 {
+    /// Finalize and return the checksum together with the error-detection
+    /// guarantee it carries.
+    ///
+    /// The result is [`Guarantee::Hd3`] if the hasher uses [`MODULUS_16`] and
+    /// no more than [`KOOPMAN16_MAX_LEN`] bytes were folded, and
+    /// [`Guarantee::Degraded`] otherwise.
+    ///
+    /// # Example
+    /// ```rust
+    /// use koopman_checksum::{koopman16, Guarantee, Koopman16, KOOPMAN16_MAX_LEN};
+    ///
+    /// let mut hasher = Koopman16::with_seed(0xee);
+    /// hasher.update(b"test data");
+    /// assert_eq!(hasher.finalize_with_guarantee(), (koopman16(b"test data", 0xee), Guarantee::Hd3));
+    ///
+    /// let mut hasher = Koopman16::new();
+    /// hasher.update(&[0u8; KOOPMAN16_MAX_LEN + 1]);
+    /// assert_eq!(hasher.finalize_with_guarantee().1, Guarantee::Degraded);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn finalize_with_guarantee(self) -> (u16, Guarantee) {
+        let guarantee = guarantee_for(
+            self.modulus() == MODULUS_16,
+            self.bytes_processed(),
+            self.max_len(),
+        );
+        (self.finalize(), guarantee)
+    }
+}
+
+impl Koopman16Hybrid {
+    /// Finalize and return the checksum together with the error-detection
+    /// guarantee it carries.
+    ///
+    /// The result is [`Guarantee::Hd3`] if the mode's default modulus is used
+    /// and no more than [`KOOPMAN16_MAX_LEN`] bytes, or [`KOOPMAN16P_MAX_LEN`]
+    /// in parity mode, were folded, and [`Guarantee::Degraded`] otherwise.
+    ///
+    /// # Example
+    /// ```rust
+    /// use koopman_checksum::{Guarantee, Koopman16, KOOPMAN16P_MAX_LEN};
+    ///
+    /// let mut hasher = Koopman16::with_parity(true);
+    /// hasher.update(&[0u8; KOOPMAN16P_MAX_LEN + 1]);
+    /// assert_eq!(hasher.finalize_with_guarantee().1, Guarantee::Degraded);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn finalize_with_guarantee(self) -> (u16, Guarantee) {
+        match self {
+            Self::Plain(hasher) => hasher.finalize_with_guarantee(),
+            Self::Parity(hasher) => {
+                let guarantee = guarantee_for(
+                    hasher.modulus() == MODULUS_15P,
+                    hasher.bytes_processed(),
+                    hasher.max_len(),
+                );
+                (hasher.finalize(), guarantee)
+            }
+        }
+    }
+}
+
+fn guarantee_for(default_modulus: bool, len: u64, max_len: usize) -> Guarantee {
+    if default_modulus && len <= max_len as u64 {
+        Guarantee::Hd3
+    } else {
+        Guarantee::Degraded
+    }
+}
+
+// ============================================================================
+// Incremental Verification
+// ============================================================================
+
+/// Verifies a stream block by block while keeping a whole-stream checksum.
+///
+/// Each block carries its own Koopman16 checksum, checked by
+/// [`feed_block`](Self::feed_block) with a per-block hasher that is reset
+/// between blocks. Blocks that verify are also folded into a whole-stream
+/// hasher, so after the last block [`finalize_stream`](Self::finalize_stream)
+/// gives the checksum of all accepted payloads concatenated, for comparison
+/// with an end-of-stream trailer. Rejected blocks are not folded in.
+///
+/// # Example
+/// ```rust
+/// use koopman_checksum::{koopman16, StreamVerifier16};
+///
+/// let mut verifier = StreamVerifier16::new(0xee);
+/// verifier.feed_block(b"first ", koopman16(b"first ", 0xee)).unwrap();
+/// verifier.feed_block(b"second", koopman16(b"second", 0xee)).unwrap();
+/// assert_eq!(verifier.finalize_stream(), koopman16(b"first second", 0xee));
+/// ```
+#[derive(Clone, Debug)]
+pub struct StreamVerifier16 {
+    block: Koopman16,
+    stream: Koopman16,
+}
+
+impl StreamVerifier16 {
+    /// Create a verifier using `initial_seed` for both the blocks and the stream.
+    #[inline]
+    pub fn new(initial_seed: u8) -> Self {
+        Self {
+            block: Koopman16::with_seed(initial_seed),
+            stream: Koopman16::with_seed(initial_seed),
+        }
+    }
+
+    /// Verify one block against its checksum and, if it matches, fold it
+    /// into the whole-stream checksum.
+    ///
+    /// # Errors
+    /// [`KoopmanError::ChecksumMismatch`] if the block's checksum does not
+    /// match; the whole-stream checksum is left unchanged.
+    pub fn feed_block(&mut self, payload: &[u8], expected: u16) -> Result<(), KoopmanError> {
+        self.block.reset();
+        self.block.update(payload);
+        let actual = self.block.peek();
+        if actual != expected {
+            return Err(KoopmanError::ChecksumMismatch {
+                expected: expected as u32,
+                actual: actual as u32,
+            });
+        }
+
+        self.stream.update(payload);
+        Ok(())
+    }
+
+    /// Checksum of all accepted block payloads, in order.
+    #[inline]
+    #[must_use]
+    pub fn finalize_stream(self) -> u16 {
+        self.stream.finalize()
+    }
+}
+
+// ============================================================================
+// Verification Functions
+// ============================================================================
+
+/// Verify data integrity using Koopman8 checksum.
+///
+/// # Arguments
+/// * `data` - The data bytes (excluding checksum)
+/// * `expected` - The expected checksum value
+/// * `initial_seed` - Initial seed used when computing the checksum
+///
+/// # Returns
+/// `true` if the checksum matches, `false` otherwise
+///
+/// # Example
+/// ```rust
+/// use koopman_checksum::{koopman8, verify8};
+///
+/// let data = b"test data";
+/// let checksum = koopman8(data, 0xee);
+/// assert!(verify8(data, checksum, 0xee));
+/// assert!(!verify8(data, checksum.wrapping_add(1), 0));
+/// ```
+#[inline]
+#[must_use]
+pub fn verify8(data: &[u8], expected: u8, initial_seed: u8) -> bool {
+    koopman8(data, initial_seed) == expected
+}
+
+/// Verify data integrity using Koopman16 checksum.
+///
+/// # Arguments
+/// * `data` - The data bytes (excluding checksum)
+/// * `expected` - The expected checksum value
+/// * `initial_seed` - Initial seed used when computing the checksum
+///
+/// # Returns
+/// `true` if the checksum matches, `false` otherwise
+///
+/// # Example
+/// ```rust
+/// use koopman_checksum::{koopman16, verify16};
+///
+/// let data = b"test data";
+/// let checksum = koopman16(data, 0xee);
+/// assert!(verify16(data, checksum, 0xee));
+/// ```
+#[inline]
+#[must_use]
+pub fn verify16(data: &[u8], expected: u16, initial_seed: u8) -> bool {
+    koopman16(data, initial_seed) == expected
+}
+
+/// Verify a Koopman16 checksum, rejecting empty data.
+///
+/// Empty data checksums to 0 for every seed, so [`verify16`]`(&[], 0, seed)`
+/// is `true`: a payload dropped somewhere upstream, leaving an empty buffer and
+/// a zeroed checksum field, would pass. This folds "a message is present" and
+/// "it is intact" into a single check.
+///
+/// # Returns
+/// `false` if `data` is empty, regardless of `expected`; otherwise the same
+/// as [`verify16`]
+///
+/// # Example
+/// ```rust
+/// use koopman_checksum::{koopman16, verify16, verify16_nonempty};
+///
+/// assert!(verify16(&[], 0, 0xee));
+/// assert!(!verify16_nonempty(&[], 0, 0xee));
+/// assert!(verify16_nonempty(b"test data", koopman16(b"test data", 0xee), 0xee));
+/// ```
+#[inline]
+#[must_use]
+pub fn verify16_nonempty(data: &[u8], expected: u16, initial_seed: u8) -> bool {
+    !data.is_empty() && verify16(data, expected, initial_seed)
+}
+
+/// Verify a Koopman16 checksum, rejecting data shorter than `min_len`.
+///
+/// For protocols whose messages have a known minimum size. A frame truncated
+/// below it, including the empty frame that verifies against checksum 0, is
+/// rejected before the checksum is considered. `min_len = 1` is
+/// [`verify16_nonempty`]; `min_len = 0` is [`verify16`].
+///
+/// # Returns
+/// `false` if `data.len() < min_len`, regardless of `expected`; otherwise the
+/// same as [`verify16`]
+///
+/// # Example
+/// ```rust
+/// use koopman_checksum::{koopman16, verify16_min_len};
+///
+/// let checksum = koopman16(b"test data", 0xee);
+/// assert!(verify16_min_len(b"test data", checksum, 0xee, 8));
+/// assert!(!verify16_min_len(b"test data", checksum, 0xee, 10));
+/// ```
+#[inline]
+#[must_use]
+pub fn verify16_min_len(data: &[u8], expected: u16, initial_seed: u8, min_len: usize) -> bool {
+    data.len() >= min_len && verify16(data, expected, initial_seed)
+}
+
+/// Maximum number of trailing zero bytes [`verify16_padded`] will trim.
+pub const KOOPMAN16_MAX_PADDING_TRIM: usize = 64;
+
+/// Verify a Koopman16 checksum over a frame that may carry trailing zero padding.
+///
+/// Some framings zero-pad the payload up to a block size while the checksum
+/// covers only the unpadded payload. This tries the frame as-is and then with
+/// each additional trailing zero byte removed, up to
+/// [`KOOPMAN16_MAX_PADDING_TRIM`] bytes, and accepts if any candidate matches.
+///
+/// # Ambiguity
+/// Trailing zero bytes become invisible: a payload that legitimately ends in
+/// zeros verifies the same with or without them, so zero bytes appended to or
+/// dropped from the end of a frame are not detected. Each trimmed candidate is
+/// also another chance for a corrupted frame to match by accident, so the
+/// undetected error rate for random corruption rises by up to a factor of
+/// `KOOPMAN16_MAX_PADDING_TRIM + 1`. Prefer [`verify16`] with an explicit
+/// payload length whenever the framing records one.
+///
+/// # Arguments
+/// * `frame` - The possibly zero-padded frame bytes (excluding checksum)
+/// * `expected` - The expected checksum of the unpadded payload
+/// * `initial_seed` - Initial seed used when computing the checksum
+///
+/// # Returns
+/// `true` if the frame, or the frame with some of its trailing zeros trimmed,
+/// matches `expected`
+///
+/// # Example
+/// ```rust
+/// use koopman_checksum::{koopman16, verify16_padded};
+///
+/// let checksum = koopman16(b"payload", 0xee);
+/// assert!(verify16_padded(b"payload\0\0\0", checksum, 0xee));
+/// ```
+#[must_use]
+pub fn verify16_padded(frame: &[u8], expected: u16, initial_seed: u8) -> bool {
+    let zeros = frame
+        .iter()
+        .rev()
+        .take(KOOPMAN16_MAX_PADDING_TRIM)
+        .take_while(|&&b| b == 0)
+        .count();
+    let (payload, padding) = frame.split_at(frame.len() - zeros);
+
+    // Hash the shortest candidate once, then extend it one zero at a time.
+    let mut hasher = Koopman16::with_seed(initial_seed);
+    hasher.update(payload);
+    if hasher.peek() == expected {
+        return true;
+    }
+    for _ in padding {
+        hasher.update(&[0]);
+        if hasher.peek() == expected {
+            return true;
+        }
+    }
+    false
+}
+
+/// Outcome of verifying a checksum field that may be absent.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum VerifyResult {
+    /// The field holds a checksum that matches the data.
+    Match,
+    /// The field holds a checksum that does not match the data.
+    Mismatch,
+    /// The field holds the "not computed" sentinel; nothing was verified.
+    Skipped,
+}
+
+/// Verify a Koopman16 checksum field that may hold an "absent" sentinel.
+///
+/// For formats where the checksum is optional and a reserved value marks it
+/// as not computed, the sentinel yields [`VerifyResult::Skipped`] instead of a
+/// mismatch.
+///
+/// # Sentinel collisions
+/// If the sentinel is a possible checksum value, data whose real checksum
+/// equals it is never verified, and corruption that turns a stored checksum
+/// into the sentinel disables verification. Koopman16 checksums with the
+/// default modulus are always below [`MODULUS_16`], so sentinels from 65519
+/// to 65535 (such as the common all-ones `0xFFFF`) cannot collide with a
+/// real checksum; a single bit flip can still turn a stored checksum into one.
+///
+/// # Arguments
+/// * `data` - The data bytes (excluding checksum)
+/// * `field` - The stored checksum field
+/// * `initial_seed` - Initial seed used when computing the checksum
+/// * `absent_sentinel` - The field value meaning "not computed"
+///
+/// # Example
+/// ```rust
+/// use koopman_checksum::{koopman16, verify16_optional, VerifyResult};
+///
+/// let checksum = koopman16(b"data", 0xee);
+/// assert_eq!(verify16_optional(b"data", checksum, 0xee, 0xFFFF), VerifyResult::Match);
+/// assert_eq!(verify16_optional(b"data", 0xFFFF, 0xee, 0xFFFF), VerifyResult::Skipped);
+/// ```
+#[inline]
+#[must_use]
+pub fn verify16_optional(
+    data: &[u8],
+    field: u16,
+    initial_seed: u8,
+    absent_sentinel: u16,
+) -> VerifyResult {
+    if field == absent_sentinel {
+        VerifyResult::Skipped
+    } else if verify16(data, field, initial_seed) {
+        VerifyResult::Match
+    } else {
+        VerifyResult::Mismatch
+    }
+}
+
+/// Where a big-endian Koopman16 checksum sits in a frame, for [`verify16_at`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ChecksumPos {
+    /// The first two bytes, covering the rest of the frame.
+    Prefix,
+    /// The last two bytes, covering the rest of the frame.
+    Suffix,
+    /// Two bytes at this offset, embedded in the frame. The checksum covers
+    /// the whole frame with those two bytes zeroed.
+    At(usize),
+}
+
+/// Verify a frame carrying its own big-endian Koopman16 checksum.
+///
+/// Handles the checksum as a prefix, a suffix, or embedded at an offset; see
+/// [`ChecksumPos`] for what each layout covers. The embedded layout is
+/// checked without copying the frame.
+///
+/// # Returns
+/// `true` if the checksum matches; `false` on a mismatch or if the frame is
+/// too short to hold the checksum at the given position
+///
+/// # Example
+/// ```rust
+/// use koopman_checksum::{koopman16, verify16_at, ChecksumPos};
+///
+/// let mut frame = [0xa0, 0x00, 0x00, 0xa1, 0xa2];
+/// let checksum = koopman16(&frame, 0xee).to_be_bytes();
+/// frame[1..3].copy_from_slice(&checksum);
+/// assert!(verify16_at(&frame, ChecksumPos::At(1), 0xee));
+/// ```
+#[must_use]
+pub fn verify16_at(frame: &[u8], checksum_pos: ChecksumPos, initial_seed: u8) -> bool {
+    let offset = match checksum_pos {
+        ChecksumPos::Prefix => 0,
+        ChecksumPos::Suffix => match frame.len().checked_sub(2) {
+            Some(offset) => offset,
+            None => return false,
+        },
+        ChecksumPos::At(offset) => offset,
+    };
+    let Some(field) = frame.get(offset..).and_then(|rest| rest.get(..2)) else {
+        return false;
+    };
+    let expected = u16::from_be_bytes([field[0], field[1]]);
+    let (head, tail) = (&frame[..offset], &frame[offset + 2..]);
+
+    match checksum_pos {
+        ChecksumPos::Prefix => verify16(tail, expected, initial_seed),
+        ChecksumPos::Suffix => verify16(head, expected, initial_seed),
+        ChecksumPos::At(_) => {
+            let mut hasher = Koopman16::with_seed(initial_seed);
+            hasher.update(head);
+            hasher.update(&[0, 0]);
+            hasher.update(tail);
+            hasher.finalize() == expected
+        }
+    }
+}
+
+/// Verify data integrity using Koopman32 checksum.
+///
+/// # Arguments
+/// * `data` - The data bytes (excluding checksum)
+/// * `expected` - The expected checksum value
+/// * `initial_seed` - Initial seed used when computing the checksum
+///
+/// # Returns
+/// `true` if the checksum matches, `false` otherwise
+///
+/// # Example
+/// ```rust
+/// use koopman_checksum::{koopman32, verify32};
+///
+/// let data = b"test data";
+/// let checksum = koopman32(data, 0xee);
+/// assert!(verify32(data, checksum, 0xee));
+/// ```
+#[inline]
+#[must_use]
+pub fn verify32(data: &[u8], expected: u32, initial_seed: u8) -> bool {
+    koopman32(data, initial_seed) == expected
+}
+
+/// Verify data integrity using Koopman8P checksum (with parity).
+///
+/// # Arguments
+/// * `data` - The data bytes (excluding checksum)
+/// * `expected` - The expected checksum value (7-bit checksum + 1 parity bit)
+/// * `initial_seed` - Initial seed used when computing the checksum
+///
+/// # Returns
+/// `true` if the checksum matches, `false` otherwise
+///
+/// # Example
+/// ```rust
+/// use koopman_checksum::{koopman8p, verify8p};
+///
+/// let data = b"test";
+/// let checksum = koopman8p(data, 0xee);
+/// assert!(verify8p(data, checksum, 0xee));
+/// ```
+#[inline]
+#[must_use]
+pub fn verify8p(data: &[u8], expected: u8, initial_seed: u8) -> bool {
+    koopman8p(data, initial_seed) == expected
+}
+
+/// Verify data integrity using Koopman16P checksum (with parity).
+///
+/// # Arguments
+/// * `data` - The data bytes (excluding checksum)
+/// * `expected` - The expected checksum value (15-bit checksum + 1 parity bit)
+/// * `initial_seed` - Initial seed used when computing the checksum
+///
+/// # Returns
+/// `true` if the checksum matches, `false` otherwise
+///
+/// # Example
+/// ```rust
+/// use koopman_checksum::{koopman16p, verify16p};
+///
+/// let data = b"test data";
+/// let checksum = koopman16p(data, 0xee);
+/// assert!(verify16p(data, checksum, 0xee));
+/// ```
+#[inline]
+#[must_use]
+pub fn verify16p(data: &[u8], expected: u16, initial_seed: u8) -> bool {
+    koopman16p(data, initial_seed) == expected
+}
+
+/// Verify data integrity using Koopman32P checksum (with parity).
+///
+/// # Arguments
+/// * `data` - The data bytes (excluding checksum)
+/// * `expected` - The expected checksum value (31-bit checksum + 1 parity bit)
+/// * `initial_seed` - Initial seed used when computing the checksum
+///
+/// # Returns
+/// `true` if the checksum matches, `false` otherwise
+///
+/// # Example
+/// ```rust
+/// use koopman_checksum::{koopman32p, verify32p};
+///
+/// let data = b"test data";
+/// let checksum = koopman32p(data, 0xee);
+/// assert!(verify32p(data, checksum, 0xee));
+/// ```
+#[inline]
+#[must_use]
+pub fn verify32p(data: &[u8], expected: u32, initial_seed: u8) -> bool {
+    koopman32p(data, initial_seed) == expected
+}
+
+/// Verify data integrity using Koopman8 checksum, returning the
+/// recomputed checksum on mismatch. See [`verify16_detailed`].
+#[inline]
+pub fn verify8_detailed(data: &[u8], expected: u8, initial_seed: u8) -> Result<(), u8> {
+    let actual = koopman8(data, initial_seed);
+    if actual == expected { Ok(()) } else { Err(actual) }
+}
+
+/// Verify data integrity using Koopman16 checksum, returning the
+/// recomputed checksum on mismatch.
+///
+/// Like [`verify16`], but the error path carries the actual checksum so it
+/// can be logged next to `expected` without recomputing it.
+///
+/// # Arguments
+/// * `data` - The data bytes (excluding checksum)
+/// * `expected` - The expected checksum value
+/// * `initial_seed` - Initial seed used when computing the checksum
+///
+/// # Returns
+/// `Ok(())` if the checksum matches, otherwise `Err` with the recomputed checksum
+///
+/// # Example
+/// ```rust
+/// use koopman_checksum::{koopman16, verify16_detailed};
+///
+/// let checksum = koopman16(b"test data", 0xee);
+/// assert_eq!(verify16_detailed(b"test data", checksum, 0xee), Ok(()));
+/// assert_eq!(verify16_detailed(b"test data", 0, 0xee), Err(checksum));
+/// ```
+#[inline]
+pub fn verify16_detailed(data: &[u8], expected: u16, initial_seed: u8) -> Result<(), u16> {
+    let actual = koopman16(data, initial_seed);
+    if actual == expected { Ok(()) } else { Err(actual) }
+}
+
+/// Verify data integrity using Koopman32 checksum, returning the
+/// recomputed checksum on mismatch. See [`verify16_detailed`].
+#[inline]
+pub fn verify32_detailed(data: &[u8], expected: u32, initial_seed: u8) -> Result<(), u32> {
+    let actual = koopman32(data, initial_seed);
+    if actual == expected { Ok(()) } else { Err(actual) }
+}
+
+/// Verify data integrity using Koopman8P checksum, returning the
+/// recomputed checksum on mismatch. See [`verify16_detailed`].
+#[inline]
+pub fn verify8p_detailed(data: &[u8], expected: u8, initial_seed: u8) -> Result<(), u8> {
+    let actual = koopman8p(data, initial_seed);
+    if actual == expected { Ok(()) } else { Err(actual) }
+}
+
+/// Verify data integrity using Koopman16P checksum, returning the
+/// recomputed checksum on mismatch. See [`verify16_detailed`].
+#[inline]
+pub fn verify16p_detailed(data: &[u8], expected: u16, initial_seed: u8) -> Result<(), u16> {
+    let actual = koopman16p(data, initial_seed);
+    if actual == expected { Ok(()) } else { Err(actual) }
+}
+
+/// Verify data integrity using Koopman32P checksum, returning the
+/// recomputed checksum on mismatch. See [`verify16_detailed`].
+#[inline]
+pub fn verify32p_detailed(data: &[u8], expected: u32, initial_seed: u8) -> Result<(), u32> {
+    let actual = koopman32p(data, initial_seed);
+    if actual == expected { Ok(()) } else { Err(actual) }
+}
+
+/// Which portions of a parity-packed checksum matched, from [`parity_consistent16`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ParityCheck {
+    /// Both the checksum portion and the parity bit match.
+    Valid,
+    /// The checksum portion differs; the parity bit matches.
+    ChecksumMismatch,
+    /// The parity bit differs; the checksum portion matches.
+    ParityMismatch,
+    /// Both portions differ.
+    BothMismatch,
+}
+
+/// Check the checksum and parity portions of a [`koopman16p`] value independently.
+///
+/// For mismatch triage: a parity-only mismatch with an intact checksum points
+/// at corruption of the stored value's LSB or an odd number of flipped data
+/// bits the checksum happened to miss, while a checksum-only mismatch points
+/// at an even number of flipped data bits or corruption of the stored
+/// checksum portion.
+///
+/// # Arguments
+/// * `packed` - The received parity-packed checksum
+/// * `data` - The data bytes (excluding checksum)
+/// * `initial_seed` - Initial seed used when computing the checksum
+///
+/// # Example
+/// ```rust
+/// use koopman_checksum::{koopman16p, parity_consistent16, ParityCheck};
+///
+/// let packed = koopman16p(b"data", 0xee);
+/// assert_eq!(parity_consistent16(packed, b"data", 0xee), ParityCheck::Valid);
+/// assert_eq!(parity_consistent16(packed ^ 1, b"data", 0xee), ParityCheck::ParityMismatch);
+/// ```
+#[inline]
+#[must_use]
+pub fn parity_consistent16(packed: u16, data: &[u8], initial_seed: u8) -> ParityCheck {
+    let actual = koopman16p(data, initial_seed);
+    let checksum_ok = (actual >> 1) == (packed >> 1);
+    let parity_ok = (actual & 1) == (packed & 1);
+    match (checksum_ok, parity_ok) {
+        (true, true) => ParityCheck::Valid,
+        (false, true) => ParityCheck::ChecksumMismatch,
+        (true, false) => ParityCheck::ParityMismatch,
+        (false, false) => ParityCheck::BothMismatch,
+    }
+}
+
+// ============================================================================
+// Batch Verification
+// ============================================================================
+
+/// Verify many `(data, checksum)` records using Koopman16, reporting each result.
+///
+/// With the `parallel` feature enabled, records are verified on the rayon
+/// thread pool.
+///
+/// # Returns
+/// One `bool` per record, in input order: `true` if that record's checksum matches
+///
+/// # Example
+/// ```rust
+/// use koopman_checksum::{koopman16, verify16_batch};
+///
+/// let good: &[u8] = b"record one";
+/// let bad: &[u8] = b"record two";
+/// let items = [(good, koopman16(good, 0xee)), (bad, 0)];
+/// assert_eq!(verify16_batch(&items, 0xee), vec![true, false]);
+/// ```
+#[cfg(feature = "alloc")]
+#[must_use]
+pub fn verify16_batch(items: &[(&[u8], u16)], initial_seed: u8) -> alloc::vec::Vec<bool> {
+    #[cfg(feature = "parallel")]
+    {
+        use rayon::prelude::*;
+        items
+            .par_iter()
+            .map(|&(data, expected)| verify16(data, expected, initial_seed))
+            .collect()
+    }
+
+    #[cfg(not(feature = "parallel"))]
+    {
+        items
+            .iter()
+            .map(|&(data, expected)| verify16(data, expected, initial_seed))
+            .collect()
+    }
+}
+
+/// Verify that every `(data, checksum)` record matches using Koopman16.
+///
+/// Stops at the first mismatch. Does not allocate.
+///
+/// # Example
+/// ```rust
+/// use koopman_checksum::{koopman16, verify16_batch_all};
+///
+/// let a: &[u8] = b"record one";
+/// let b: &[u8] = b"record two";
+/// let items = [(a, koopman16(a, 0xee)), (b, koopman16(b, 0xee))];
+/// assert!(verify16_batch_all(&items, 0xee));
+/// ```
+#[inline]
+#[must_use]
+pub fn verify16_batch_all(items: &[(&[u8], u16)], initial_seed: u8) -> bool {
+    items
+        .iter()
+        .all(|&(data, expected)| verify16(data, expected, initial_seed))
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "alloc")]
+    use alloc::{format, vec, vec::Vec};
+    use core::num::{NonZeroU32, NonZeroU64};
+
+    // Test vectors based on the C reference implementation
+    const TEST_DATA: &[u8] = b"123456789";
+
+    #[test]
+    fn test_koopman8_empty() {
+        assert_eq!(koopman8(&[], 0), 0);
+        assert_eq!(koopman8(&[], 42), 0); // Empty data returns 0 regardless of initial seed
+    }
+
+    #[test]
+    fn test_koopman8_single_byte() {
+        // For single byte 0x12: sum = 0x12, then append zero: (0x12 << 8) % 253 = 4608 % 253 = 54
+        assert_eq!(koopman8(&[0x12], 0), ((0x12u32 << 8) % MODULUS_8) as u8);
+    }
+
+    /// Generate a test per reduction checking it against `%` over its input
+    /// domain: every value below `exhaustive`, a stride of about a million
+    /// samples up to `max`, the top 2^17 values, and both sides of sampled
+    /// multiples of the modulus. `max` is the documented bound, the largest
+    /// intermediate the callers can produce. Add a line here for each new
+    /// reduction.
+    macro_rules! fast_mod_tests {
+        ($($name:ident: $f:ident($ty:ty) % $modulus:expr, max = $max:expr, exhaustive = $low:expr;)*) => {$(
+            #[test]
+            fn $name() {
+                let (m, max): ($ty, $ty) = ($modulus, $max);
+                let check = |x: $ty| assert_eq!($f(x), x % m, "{}: x = {}", stringify!($f), x);
+
+                (0..$low).for_each(check);
+                (0..=max).step_by(((max / 1_000_003) as usize).max(1)).for_each(check);
+                (max - max.min(1 << 17)..=max).for_each(check);
+                let multiples = max / m;
+                for k in (1..=multiples).step_by(((multiples / 10_000) as usize).max(1)) {
+                    [k * m - 1, k * m, k * m + 1].into_iter().filter(|&x| x <= max).for_each(check);
+                }
+            }
+        )*};
+    }
+
+    fast_mod_tests! {
+        // sum < 253 and one appended byte
+        test_fast_mod_253: fast_mod_253(u32) % MODULUS_8,
+            max = ((MODULUS_8 - 1) << 8) + 0xFF, exhaustive = 1 << 16;
+        // Two bytes folded per reduction
+        test_fast_mod_65519: fast_mod_65519(u32) % MODULUS_16,
+            max = ((MODULUS_16 - 1) << 16) + 0xFFFF, exhaustive = 1 << 20;
+        // Documented bound x < 2^48, above the eight-byte fold's 31 * 2^32
+        test_fast_mod_4294967291: fast_mod_4294967291(u64) % MODULUS_32,
+            max = (1 << 48) - 1, exhaustive = 1 << 20;
+        // Two bytes folded per reduction; every single-byte fold input is below 2^23
+        test_fast_mod_32749: fast_mod_32749(u32) % MODULUS_15P,
+            max = ((MODULUS_15P - 1) << 16) + 0xFFFF, exhaustive = 1 << 23;
+        // Four bytes folded per reduction
+        test_fast_mod_2147483629: fast_mod_2147483629(u64) % MODULUS_31P,
+            max = ((MODULUS_31P - 1) << 32) + 0xFFFF_FFFF, exhaustive = 1 << 20;
+    }
+
+    #[test]
+    fn test_branchless_correction_matches_branchy() {
+        // Every value the final correction can see: r2 <= 264 for 253, r2 < 65570 for 65519
+        for (m, bound) in [(MODULUS_8, 265), (MODULUS_16, 65570)] {
+            for r in 0..bound {
+                let branchy = if r >= m { r - m } else { r };
+                assert_eq!(correct_u32_branchless(r, m), branchy, "r = {}, m = {}", r, m);
+                assert_eq!(correct_u32(r, m), branchy, "r = {}, m = {}", r, m);
+            }
+        }
+
+        // For 2^32 - 5 the correction sees r < 5 * 2^8 + 2^32; cover both ends of that range
+        let low = 0..(1u64 << 20);
+        let high = (MODULUS_32 - (1 << 20))..(5 * 256 + (1u64 << 32));
+        for r in low.chain(high) {
+            let branchy = if r >= MODULUS_32 { r - MODULUS_32 } else { r };
+            assert_eq!(correct_u64_branchless(r, MODULUS_32), branchy, "r = {}", r);
+            assert_eq!(correct_u64(r, MODULUS_32), branchy, "r = {}", r);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_koopman16p_matches_generic_modulus() {
+        let mut state: u32 = 0x9e37_79b9;
+        let data: Vec<u8> = (0..4096)
+            .map(|_| {
+                state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+                (state >> 24) as u8
+            })
+            .collect();
+        for len in [1, 2, 3, 100, 2044, 4096] {
+            for seed in [0u8, 1, 0xee, 0xff] {
+                assert_eq!(
+                    koopman16p(&data[..len], seed),
+                    koopman16p_with_modulus(&data[..len], seed, NONZERO_MODULUS_15P)
+                );
+            }
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_koopman32_wide_fold_matches_scalar() {
+        let mut state: u32 = 0x9e37_79b9;
+        let random: Vec<u8> = (0..64)
+            .map(|_| {
+                state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+                (state >> 24) as u8
+            })
+            .collect();
+        // All-ones data keeps the sum near the modulus, maximizing the fold input
+        for data in [&random[..], &[0xFF; 64]] {
+            for len in 0..=64 {
+                for seed in [0u8, 1, 0xee] {
+                    let expected = koopman32_with_modulus(&data[..len], seed, NONZERO_MODULUS_32);
+                    assert_eq!(koopman32(&data[..len], seed), expected, "len = {len}");
+
+                    // Split so the streaming wide fold sees every alignment
+                    let split = len / 3;
+                    let mut hasher = Koopman32::with_seed(seed);
+                    hasher.update(&data[..split]);
+                    hasher.update(&data[split..len]);
+                    assert_eq!(hasher.finalize(), expected, "len = {len}");
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_koopman16_wide_fold_random_chunkings() {
+        let mut state: u32 = 0x9e37_79b9;
+        let mut next = move || {
+            state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            state >> 16
+        };
+        let random: Vec<u8> = (0..2053).map(|_| next() as u8).collect();
+
+        // All-ones data keeps the sum near the modulus, maximizing the fold input
+        for data in [random, vec![0xFF; 2053]] {
+            for seed in [0u8, 1, 0xee, 0xff] {
+                let expected = koopman16_with_modulus(&data, seed, NONZERO_MODULUS_16);
+                for _ in 0..32 {
+                    // Chunks up to 40 bytes: some skip the fold, some use it
+                    // several times, and boundaries land at every offset mod 8
+                    let mut hasher = Koopman16::with_seed(seed);
+                    let mut rest = &data[..];
+                    while !rest.is_empty() {
+                        let n = (next() as usize % 41).min(rest.len());
+                        let (chunk, tail) = rest.split_at(n);
+                        hasher.update(chunk);
+                        rest = tail;
+                    }
+                    assert_eq!(hasher.finalize(), expected);
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_koopman32p_matches_generic_modulus() {
+        let mut state: u32 = 0x9e37_79b9;
+        let data: Vec<u8> = (0..4096)
+            .map(|_| {
+                state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+                (state >> 24) as u8
+            })
+            .collect();
+        for len in [1, 2, 4, 5, 7, 100, 4096] {
+            for seed in [0u8, 1, 0xee, 0xff] {
+                assert_eq!(
+                    koopman32p(&data[..len], seed),
+                    koopman32p_with_modulus(&data[..len], seed, NONZERO_MODULUS_31P)
+                );
+            }
+        }
+        assert_eq!(
+            koopman32p(&[0xFF; 64], 0),
+            koopman32p_with_modulus(&[0xFF; 64], 0, NONZERO_MODULUS_31P)
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_koopman8_matches_generic_modulus() {
+        let data: Vec<u8> = (0..1024u32).map(|i| (i * 131 + 17) as u8).collect();
+        for len in [1, 2, 13, 100, 1024] {
+            for seed in [0u8, 1, 0xee, 0xff] {
+                assert_eq!(
+                    koopman8(&data[..len], seed),
+                    koopman8_with_modulus(&data[..len], seed, NONZERO_MODULUS_8)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_koopman16_empty() {
+        assert_eq!(koopman16(&[], 0), 0);
+        assert_eq!(koopman16(&[], 42), 0); // Empty data returns 0 regardless of initial seed
+    }
+
+    #[test]
+    fn test_koopman32_empty() {
+        assert_eq!(koopman32(&[], 0), 0);
+        assert_eq!(koopman32(&[], 42), 0); // Empty data returns 0 regardless of initial seed
+    }
+
+    #[test]
+    fn test_streaming_koopman8() {
+        let full = koopman8(TEST_DATA, 0);
+
+        let mut hasher = Koopman8::new();
+        hasher.update(&TEST_DATA[..4]);
+        hasher.update(&TEST_DATA[4..]);
+        let streaming = hasher.finalize();
+
+        assert_eq!(full, streaming);
+    }
+
+    #[test]
+    fn test_streaming_koopman16() {
+        let full = koopman16(TEST_DATA, 0);
+
+        let mut hasher = Koopman16::new();
+        hasher.update(&TEST_DATA[..4]);
+        hasher.update(&TEST_DATA[4..]);
+        let streaming = hasher.finalize();
+
+        assert_eq!(full, streaming);
+    }
+
+    #[test]
+    fn test_streaming_koopman32() {
+        let full = koopman32(TEST_DATA, 0);
+
+        let mut hasher = Koopman32::new();
+        hasher.update(&TEST_DATA[..4]);
+        hasher.update(&TEST_DATA[4..]);
+        let streaming = hasher.finalize();
+
+        assert_eq!(full, streaming);
+    }
+
+    #[test]
+    fn test_seed_affects_result() {
+        let result0 = koopman16(TEST_DATA, 0);
+        let result1 = koopman16(TEST_DATA, 1);
+        assert_ne!(result0, result1);
+    }
+
+    #[test]
+    fn test_single_bit_detection() {
+        let original = koopman16(TEST_DATA, 0);
+
+        for i in 0..TEST_DATA.len() {
+            for bit in 0..8 {
+                let mut corrupted = TEST_DATA.to_vec();
+                corrupted[i] ^= 1 << bit;
+                let corrupted_checksum = koopman16(&corrupted, 0);
+                assert_ne!(original, corrupted_checksum,
+                    "Failed to detect single bit flip at byte {} bit {}", i, bit);
+            }
+        }
+    }
+
+    #[test]
+    fn test_reference_calculation() {
+        // Input: [0x12, 0x34, 0x56] with initial seed 0, modulus 253
+        // Step 1: sum = 0x12 = 18
+        // Step 2: sum = ((18 << 8) + 0x34) % 253 = 4660 % 253 = 106
+        // Step 3: sum = ((106 << 8) + 0x56) % 253 = 27222 % 253 = 151
+        // Final:  sum = (151 << 8) % 253 = 38656 % 253 = 200
+
+        let data = [0x12u8, 0x34, 0x56];
+        let result = koopman8(&data, 0);
+        assert_eq!(result, 200);
+    }
+
+    // ========================================================================
+    // Additional tests for parity variants
+    // ========================================================================
+
+    #[test]
+    fn test_koopman8p_parity_correctness() {
+        // Verify that the parity bit correctly reflects the parity of data bytes only
+        // (per the reference C implementation, checksum is NOT included in parity)
+        let data = b"Test";
+        let result = koopman8p(data, 0);
+
+        // The checksum is in upper 7 bits
+        let _checksum = result >> 1;
+        let parity_bit = result & 1;
+
+        // Compute expected parity: XOR all data bytes (NOT including checksum)
+        let mut expected_parity: u8 = 0;
+        for &byte in data {
+            expected_parity ^= byte;
+        }
+        let expected_parity_bit = expected_parity.count_ones() & 1;
+
+        assert_eq!(parity_bit as u32, expected_parity_bit);
+    }
+
+    #[test]
+    fn test_parity_variants_detect_single_bit_errors() {
+        let data = b"Test";
+        let original = koopman16p(data, 0);
+
+        for i in 0..data.len() {
+            for bit in 0..8 {
+                let mut corrupted = data.to_vec();
+                corrupted[i] ^= 1 << bit;
+                let corrupted_checksum = koopman16p(&corrupted, 0);
+                assert_ne!(original, corrupted_checksum,
+                    "Failed to detect single bit flip at byte {} bit {}", i, bit);
+            }
+        }
+    }
+
+    // ========================================================================
+    // Tests for custom moduli
+    // ========================================================================
+
+    #[test]
+    fn test_custom_modulus_8() {
+        const MODULUS_8_ALT: u32 = 239;
+        let data = b"test";
+        let result1 = koopman8_with_modulus(data, 0, NONZERO_MODULUS_8);
+        let modulus_alt = NonZeroU32::new(MODULUS_8_ALT).unwrap();
+        let result2 = koopman8_with_modulus(data, 0, modulus_alt);
+
+        // Different moduli should (usually) produce different results
+        // Note: They could theoretically be equal, but very unlikely
+        assert_ne!(result1, result2);
+    }
+
+    #[test]
+    fn test_custom_modulus_matches_default() {
+        let data = b"test data";
+
+        assert_eq!(
+            koopman8(data, 0),
+            koopman8_with_modulus(data, 0, NONZERO_MODULUS_8)
+        );
+        assert_eq!(
+            koopman16(data, 0),
+            koopman16_with_modulus(data, 0, NONZERO_MODULUS_16)
+        );
+        assert_eq!(
+            koopman32(data, 0),
+            koopman32_with_modulus(data, 0, NONZERO_MODULUS_32)
+        );
+    }
+
+    #[test]
+    fn test_parity_custom_modulus_matches_default() {
+        let data = b"test data";
+
+        assert_eq!(
+            koopman8p(data, 0),
+            koopman8p_with_modulus(data, 0, NONZERO_MODULUS_7P)
+        );
+        assert_eq!(
+            koopman16p(data, 0),
+            koopman16p_with_modulus(data, 0, NONZERO_MODULUS_15P)
+        );
+        assert_eq!(
+            koopman32p(data, 0),
+            koopman32p_with_modulus(data, 0, NONZERO_MODULUS_31P)
+        );
+    }
+
+    #[test]
+    fn test_streaming_with_seed() {
+        let data = b"test data";
+        let seed = 42u8;
+
+        // One-shot with seed
+        let expected = koopman16(data, seed);
+
+        // Streaming with seed
+        let mut hasher = Koopman16::with_seed(seed);
+        hasher.update(data);
+        let streaming = hasher.finalize();
+
+        assert_eq!(expected, streaming);
+    }
+
+    #[test]
+    fn test_streaming_with_seed_chunked() {
+        let data = b"test data for chunked processing";
+        let seed = 123u8;
+
+        let expected = koopman16(data, seed);
+
+        let mut hasher = Koopman16::with_seed(seed);
+        hasher.update(&data[..10]);
+        hasher.update(&data[10..20]);
+        hasher.update(&data[20..]);
+        let streaming = hasher.finalize();
+
+        assert_eq!(expected, streaming);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_koopman16_default_matches_koopman16() {
+        let data: Vec<u8> = (0..1000u32).map(|i| (i * 131 + 7) as u8).collect();
+        for len in [0, 1, 2, 5, 64, 1000] {
+            for seed in [0u8, 1, 0xee, 0xff] {
+                // Short chunks stay in the byte loop, long ones take the wide fold
+                for chunk_len in [7, 64, 1000] {
+                    let mut hasher = Koopman16Default::with_seed(seed);
+                    for chunk in data[..len].chunks(chunk_len) {
+                        hasher.update(chunk);
+                    }
+                    assert_eq!(hasher.finalize(), koopman16(&data[..len], seed));
+                }
+            }
+        }
+
+        let mut hasher = Koopman16Default::with_seed(0xee);
+        hasher.update(b"discarded");
+        hasher.reset();
+        hasher.update(TEST_DATA);
+        assert_eq!(hasher.finalize(), koopman16(TEST_DATA, 0xee));
+        assert_eq!(Koopman16Default::new().finalize(), 0);
+    }
+
+    #[test]
+    fn test_koopman16_default_is_smaller() {
+        assert!(core::mem::size_of::<Koopman16Default>() < core::mem::size_of::<Koopman16>());
+        assert_eq!(core::mem::size_of::<Koopman16Default>(), 8);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_streaming_into_finalizes() {
+        let mut hasher = Koopman16::with_modulus(NonZeroU32::new(65521).unwrap());
+        hasher.update(TEST_DATA);
+        assert_eq!(Into::<u16>::into(hasher.clone()), hasher.finalize());
+
+        macro_rules! check_into {
+            ($hasher:ident, $output:ty) => {{
+                let mut hasher = $hasher::with_seed(0xee);
+                hasher.update(TEST_DATA);
+                let checksum: $output = hasher.clone().into();
+                assert_eq!(checksum, hasher.finalize());
+            }};
+        }
+        check_into!(Koopman8, u8);
+        check_into!(Koopman32, u32);
+        check_into!(Koopman8P, u8);
+        check_into!(Koopman16P, u16);
+        check_into!(Koopman32P, u32);
+
+        let chunks = [&b"ab"[..], b"cd"];
+        let sums: Vec<u16> = chunks
+            .iter()
+            .map(|chunk| {
+                let mut hasher = Koopman16::new();
+                hasher.update(chunk);
+                hasher
+            })
+            .map(Into::into)
+            .collect();
+        assert_eq!(sums, [koopman16(b"ab", 0), koopman16(b"cd", 0)]);
+    }
+
+    // ========================================================================
+    // Tests for the fast reduction invariant
+    // ========================================================================
+
+    #[test]
+    fn test_fast_mod_flag_matches_modulus() {
+        macro_rules! check {
+            ($hasher:expr, $default:expr) => {{
+                let mut hasher = $hasher;
+                assert!(!hasher.use_fast_mod || hasher.modulus == $default);
+                hasher.update(TEST_DATA);
+                hasher.update(TEST_DATA);
+                assert!(!hasher.use_fast_mod || hasher.modulus == $default);
+                hasher.reset();
+                assert!(!hasher.use_fast_mod || hasher.modulus == $default);
+            }};
+        }
+
+        let m32 = NonZeroU32::new(239).unwrap();
+        check!(Koopman8::new(), MODULUS_8);
+        check!(Koopman8::default(), MODULUS_8);
+        check!(Koopman8::with_seed(0xee), MODULUS_8);
+        check!(Koopman8::with_modulus(NONZERO_MODULUS_8), MODULUS_8);
+        check!(Koopman8::with_modulus(m32), MODULUS_8);
+
+        let m16 = NonZeroU32::new(65521).unwrap();
+        check!(Koopman16::new(), MODULUS_16);
+        check!(Koopman16::default(), MODULUS_16);
+        check!(Koopman16::with_seed(0xee), MODULUS_16);
+        check!(Koopman16::default_seeded(), MODULUS_16);
+        check!(Koopman16::with_modulus(NONZERO_MODULUS_16), MODULUS_16);
+        check!(Koopman16::with_modulus(m16), MODULUS_16);
+        let mut resumed = Koopman16::new();
+        resumed.update(TEST_DATA);
+        check!(Koopman16::from_state(resumed.into_state()), MODULUS_16);
+
+        let m64 = NonZeroU64::new(4294967279).unwrap();
+        check!(Koopman32::new(), MODULUS_32);
+        check!(Koopman32::default(), MODULUS_32);
+        check!(Koopman32::with_seed(0xee), MODULUS_32);
+        check!(Koopman32::with_modulus(NONZERO_MODULUS_32), MODULUS_32);
+        check!(Koopman32::with_modulus(m64), MODULUS_32);
+
+        check!(Koopman8P::new(), MODULUS_7P);
+        check!(Koopman8P::with_seed(0xee), MODULUS_7P);
+        check!(Koopman8P::with_modulus(NONZERO_MODULUS_7P), MODULUS_7P);
+        check!(Koopman8P::with_modulus(NonZeroU32::new(113).unwrap()), MODULUS_7P);
+        check!(Koopman16P::new(), MODULUS_15P);
+        check!(Koopman16P::with_seed(0xee), MODULUS_15P);
+        check!(Koopman16P::with_modulus(NONZERO_MODULUS_15P), MODULUS_15P);
+        check!(Koopman16P::with_modulus(NonZeroU32::new(32719).unwrap()), MODULUS_15P);
+        check!(Koopman32P::new(), MODULUS_31P);
+        check!(Koopman32P::with_seed(0xee), MODULUS_31P);
+        check!(Koopman32P::with_modulus(NONZERO_MODULUS_31P), MODULUS_31P);
+        check!(Koopman32P::with_modulus(NonZeroU64::new(2147483647).unwrap()), MODULUS_31P);
+    }
+
+    // ========================================================================
+    // Tests for reset behavior
+    // ========================================================================
+
+    #[test]
+    fn test_reset_without_seed() {
+        let data = b"test";
+
+        let mut hasher = Koopman16::new();
+        hasher.update(data);
+        let first = hasher.finalize();
+
+        let mut hasher = Koopman16::new();
+        hasher.update(b"other data");
+        hasher.reset();
+        hasher.update(data);
+        let after_reset = hasher.finalize();
+
+        assert_eq!(first, after_reset);
+    }
+
+    #[test]
+    fn test_reset_preserves_seed() {
+        let data = b"test";
+        let seed = 42u8;
+
+        // First computation with seed
+        let mut hasher = Koopman16::with_seed(seed);
+        hasher.update(data);
+        let first = hasher.finalize();
+
+        // Computation after reset should produce same result
+        let mut hasher = Koopman16::with_seed(seed);
+        hasher.update(b"garbage data");
+        hasher.reset();
+        hasher.update(data);
+        let after_reset = hasher.finalize();
+
+        assert_eq!(first, after_reset);
+    }
+
+    #[test]
+    fn test_reset_all_variants() {
+        let data = b"test";
+
+        // Koopman8
+        let mut h8 = Koopman8::with_seed(10);
+        h8.update(b"junk");
+        h8.reset();
+        h8.update(data);
+        assert_eq!(h8.finalize(), koopman8(data, 10));
+
+        // Koopman16
+        let mut h16 = Koopman16::with_seed(20);
+        h16.update(b"junk");
+        h16.reset();
+        h16.update(data);
+        assert_eq!(h16.finalize(), koopman16(data, 20));
+
+        // Koopman32
+        let mut h32 = Koopman32::with_seed(30);
+        h32.update(b"junk");
+        h32.reset();
+        h32.update(data);
+        assert_eq!(h32.finalize(), koopman32(data, 30));
+    }
+
+    #[test]
+    fn test_reset_clears_length() {
+        let data = b"test data";
+
+        macro_rules! check {
+            ($hasher:ty, $oneshot:ident) => {{
+                let mut h = <$hasher>::with_seed(7);
+                h.update(b"junk");
+                h.update(b"more junk");
+                assert_eq!(h.bytes_processed(), 13);
+                h.reset();
+                assert_eq!(h.bytes_processed(), 0);
+                h.update(data);
+                assert_eq!(h.bytes_processed(), data.len() as u64);
+                assert_eq!(h.finalize(), $oneshot(data, 7));
+            }};
+        }
+
+        check!(Koopman8, koopman8);
+        check!(Koopman16, koopman16);
+        check!(Koopman32, koopman32);
+        check!(Koopman8P, koopman8p);
+        check!(Koopman16P, koopman16p);
+        check!(Koopman32P, koopman32p);
+    }
+
+    #[test]
+    fn test_reset_preserves_modulus() {
+        let modulus = NonZeroU32::new(32749).unwrap();
+        let data = b"test data";
+
+        let mut h = Koopman16::with_modulus(modulus);
+        h.update(b"junk");
+        h.reset();
+        h.update(data);
+        assert_eq!(h.finalize(), koopman16_with_modulus(data, 0, modulus));
+    }
+
+    #[test]
+    fn test_modulus_accessor() {
+        let m32 = NonZeroU32::new(239).unwrap();
+        let m64 = NonZeroU64::new(4294967279).unwrap();
+
+        assert_eq!(Koopman8::with_modulus(m32).modulus(), m32.get());
+        assert_eq!(Koopman16::with_modulus(m32).modulus(), m32.get());
+        assert_eq!(Koopman32::with_modulus(m64).modulus(), m64.get());
+        // Parity moduli must fit the checksum portion of the packing
+        let m7 = NonZeroU32::new(113).unwrap();
+        let m31 = NonZeroU64::new(2147483587).unwrap();
+        assert_eq!(Koopman8P::with_modulus(m7).modulus(), m7.get());
+        assert_eq!(Koopman16P::with_modulus(m32).modulus(), m32.get());
+        assert_eq!(Koopman32P::with_modulus(m31).modulus(), m31.get());
+
+        assert_eq!(Koopman16::new().modulus(), MODULUS_16);
+        assert_eq!(Koopman32P::with_seed(1).modulus(), MODULUS_31P);
+    }
+
+    #[test]
+    fn test_koopman32_update_worst_case_bound() {
+        // Largest steady-state input to the reduction: sum = MODULUS_32 - 1, byte = 0xFF
+        let worst = ((MODULUS_32 - 1) << 8) + 0xFF;
+        assert!(worst < 1 << 40);
+        assert_eq!(fast_mod_4294967291(worst), worst % MODULUS_32);
+
+        let mut hasher = Koopman32::new();
+        hasher.update(&[0]);
+        hasher.sum = MODULUS_32 - 1;
+        hasher.update(&[0xFF]);
+        assert_eq!(hasher.sum, worst % MODULUS_32);
+
+        // Finalization shifts from the largest residue
+        let mut hasher = Koopman32::new();
+        hasher.update(&[0]);
+        hasher.sum = MODULUS_32 - 1;
+        let mut expected = MODULUS_32 - 1;
+        for _ in 0..4 {
+            expected = (expected << 8) % MODULUS_32;
+        }
+        assert_eq!(hasher.finalize(), expected as u32);
+    }
+
+    #[test]
+    fn test_hasher_state_trait_all_variants() {
+        fn drive<H, F>(mut hasher: H, seed: u8, one_shot: F)
+        where
+            H: KoopmanHasherState,
+            H::Output: PartialEq + core::fmt::Debug,
+            F: Fn(&[u8], u8) -> H::Output,
+        {
+            let data = b"generic streaming data";
+
+            hasher.update(b"junk");
+            hasher.reset();
+            for chunk in data.chunks(5) {
+                KoopmanHasherState::update(&mut hasher, chunk);
+            }
+            assert_eq!(KoopmanHasherState::finalize(hasher), one_shot(data, seed));
+        }
+
+        drive(Koopman8::with_seed(9), 9, koopman8);
+        drive(Koopman16::with_seed(9), 9, koopman16);
+        drive(Koopman32::with_seed(9), 9, koopman32);
+        drive(Koopman8P::with_seed(9), 9, koopman8p);
+        drive(Koopman16P::with_seed(9), 9, koopman16p);
+        drive(Koopman32P::with_seed(9), 9, koopman32p);
+    }
+
+    // ========================================================================
+    // Tests for two-bit error detection
+    // ========================================================================
+
+    #[test]
+    fn test_two_bit_error_detection() {
+        // Test that most two-bit errors are detected
+        // Note: HD=3 means we detect ALL 1-bit and 2-bit errors
+        let data = b"Test";
+        let original = koopman16(data, 0);
+        let mut detected = 0;
+        let mut total = 0;
+
+        for i in 0..data.len() {
+            for j in i..data.len() {
+                for bit_i in 0..8 {
+                    for bit_j in 0..8 {
+                        if i == j && bit_i == bit_j {
+                            continue; // Skip single-bit errors
+                        }
+                        total += 1;
+                        let mut corrupted = data.to_vec();
+                        corrupted[i] ^= 1 << bit_i;
+                        corrupted[j] ^= 1 << bit_j;
+                        if koopman16(&corrupted, 0) != original {
+                            detected += 1;
+                        }
+                    }
+                }
+            }
+        }
+
+        // Should detect all two-bit errors for data within HD=3 length
+        assert_eq!(detected, total, "Should detect all two-bit errors");
+    }
+
+    // ========================================================================
+    // Tests for streaming API edge cases
+    // ========================================================================
+
+    #[test]
+    fn test_streaming_empty_updates() {
+        let data = b"test";
+
+        let mut hasher = Koopman16::new();
+        hasher.update(&[]);  // Empty update
+        hasher.update(data);
+        hasher.update(&[]);  // Another empty update
+
+        assert_eq!(hasher.finalize(), koopman16(data, 0));
+    }
+
+    #[test]
+    fn test_streaming_byte_by_byte() {
+        let data = b"test data";
+
+        let mut hasher = Koopman16::new();
+        for &byte in data {
+            hasher.update(&[byte]);
+        }
+
+        assert_eq!(hasher.finalize(), koopman16(data, 0));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_streaming_random_chunkings() {
+        // Deterministic LCG so chunk boundaries land at every offset modulo 4.
+        let mut state: u32 = 0x1234_5678;
+        let mut next = move || {
+            state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            state >> 16
+        };
+        let data: Vec<u8> = (0..1031).map(|_| next() as u8).collect();
+
+        for _ in 0..64 {
+            let mut h8 = Koopman8::with_seed(0xee);
+            let mut h16 = Koopman16::with_seed(0xee);
+            let mut h32 = Koopman32::with_seed(0xee);
+            let mut rest = &data[..];
+            while !rest.is_empty() {
+                let n = (next() as usize % 11).min(rest.len());
+                let (chunk, tail) = rest.split_at(n);
+                h8.update(chunk);
+                h16.update(chunk);
+                h32.update(chunk);
+                rest = tail;
+            }
+            assert_eq!(h8.finalize(), koopman8(&data, 0xee));
+            assert_eq!(h16.finalize(), koopman16(&data, 0xee));
+            assert_eq!(h32.finalize(), koopman32(&data, 0xee));
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_streaming_parity_random_chunkings() {
+        let mut state: u32 = 0x0bad_5eed;
+        let mut next = move || {
+            state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            state >> 16
+        };
+        let data: Vec<u8> = (0..1031).map(|_| next() as u8).collect();
+
+        for _ in 0..64 {
+            let mut h8 = Koopman8P::with_seed(0xee);
+            let mut h16 = Koopman16P::with_seed(0xee);
+            let mut h32 = Koopman32P::with_seed(0xee);
+            let mut rest = &data[..];
+            while !rest.is_empty() {
+                let n = (next() as usize % 11).min(rest.len());
+                let (chunk, tail) = rest.split_at(n);
+                h8.update(chunk);
+                h16.update(chunk);
+                h32.update(chunk);
+                rest = tail;
+            }
+            assert_eq!(h8.finalize(), koopman8p(&data, 0xee));
+            assert_eq!(h16.finalize(), koopman16p(&data, 0xee));
+            assert_eq!(h32.finalize(), koopman32p(&data, 0xee));
+        }
+    }
+
+    #[test]
+    fn test_finalize_without_data() {
+        let hasher = Koopman16::new();
+        assert_eq!(hasher.finalize(), 0);
+
+        let hasher_with_seed = Koopman16::with_seed(42);
+        assert_eq!(hasher_with_seed.finalize(), 0);
+    }
+
+    #[test]
+    fn test_streaming_parity_koopman8p() {
+        let data = b"test";
+        let expected = koopman8p(data, 0);
+
+        let mut hasher = Koopman8P::new();
+        hasher.update(&data[..2]);
+        hasher.update(&data[2..]);
+        let streaming = hasher.finalize();
+
+        assert_eq!(expected, streaming);
+    }
+
+    #[test]
+    fn test_streaming_parity_koopman16p() {
+        let data = b"test data";
+        let expected = koopman16p(data, 0);
+
+        let mut hasher = Koopman16P::new();
+        hasher.update(&data[..4]);
+        hasher.update(&data[4..]);
+        let streaming = hasher.finalize();
+
+        assert_eq!(expected, streaming);
+    }
+
+    #[test]
+    fn test_streaming_parity_koopman32p() {
+        let data = b"test data for streaming";
+        let expected = koopman32p(data, 0);
+
+        let mut hasher = Koopman32P::new();
+        hasher.update(&data[..10]);
+        hasher.update(&data[10..]);
+        let streaming = hasher.finalize();
+
+        assert_eq!(expected, streaming);
+    }
+
+    #[test]
+    fn test_streaming_parity_with_seed() {
+        let data = b"test";
+        let seed = 42u8;
+
+        let expected = koopman16p(data, seed);
+
+        let mut hasher = Koopman16P::with_seed(seed);
+        hasher.update(data);
+        let streaming = hasher.finalize();
+
+        assert_eq!(expected, streaming);
+    }
+
+    #[test]
+    fn test_parity_peek_matches_finalize() {
+        macro_rules! check {
+            ($hasher:ty, $oneshot:ident) => {{
+                let data = b"test data for peeking";
+                let mut h = <$hasher>::with_seed(5);
+                h.update(&data[..7]);
+                assert_eq!(h.peek(), $oneshot(&data[..7], 5));
+
+                // Updating after a peek still works
+                h.update(&data[7..]);
+                let peeked = h.peek();
+                let (checksum, parity) = h.peek_split();
+                assert_eq!(checksum, peeked >> 1);
+                assert_eq!(parity, peeked & 1 == 1);
+                assert_eq!(peeked, h.finalize());
+                assert_eq!(peeked, $oneshot(data, 5));
+            }};
+        }
+
+        check!(Koopman8P, koopman8p);
+        check!(Koopman16P, koopman16p);
+        check!(Koopman32P, koopman32p);
+    }
+
+    #[test]
+    fn test_peek_matches_finalize() {
+        let mut h = Koopman16::new();
+        assert_eq!(h.peek(), 0);
+        h.update(&TEST_DATA[..3]);
+        assert_eq!(h.peek(), koopman16(&TEST_DATA[..3], 0));
+        h.update(&TEST_DATA[3..]);
+        assert_eq!(h.peek(), h.clone().finalize());
+    }
+
+    #[test]
+    fn test_digest_matches_free_functions() {
+        for seed in [0u8, 0xee] {
+            assert_eq!(Koopman8::digest(TEST_DATA, seed), koopman8(TEST_DATA, seed));
+            assert_eq!(Koopman16::digest(TEST_DATA, seed), koopman16(TEST_DATA, seed));
+            assert_eq!(Koopman32::digest(TEST_DATA, seed), koopman32(TEST_DATA, seed));
+            assert_eq!(Koopman8P::digest(TEST_DATA, seed), koopman8p(TEST_DATA, seed));
+            assert_eq!(Koopman16P::digest(TEST_DATA, seed), koopman16p(TEST_DATA, seed));
+            assert_eq!(Koopman32P::digest(TEST_DATA, seed), koopman32p(TEST_DATA, seed));
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_koopman16_split_combine() {
+        let data: Vec<u8> = (0..300u32).map(|i| (i * 131 + 7) as u8).collect();
+        for seed in [0u8, 1, 0xee] {
+            let expected = koopman16(&data, seed);
+            for mid in (0..=data.len()).step_by(7).chain([1, 299, 300]) {
+                let (left, right) = Koopman16::split_at(&data, mid, seed);
+                assert_eq!(left.bytes_processed() + right.bytes_processed(), 300);
+
+                let combined = left.combine(&right);
+                assert_eq!(combined.bytes_processed(), 300);
+                assert_eq!(combined.finalize(), expected, "mid = {mid}");
+            }
+        }
+
+        // Recursive: split the right half again
+        let (left, right) = Koopman16::split_at(&data, 100, 0xee);
+        let (middle, last) = Koopman16::split_at(&data[100..], 150, 0);
+        assert_eq!(middle.combine(&last).peek(), right.peek());
+        assert_eq!(left.combine(&middle).combine(&last).finalize(), koopman16(&data, 0xee));
+
+        // The combined hasher keeps streaming
+        let mut combined = left.combine(&right);
+        combined.update(b"tail");
+        assert_eq!(combined.finalize(), koopman16(&[&data[..], b"tail"].concat(), 0xee));
+    }
+
+    #[test]
+    fn test_xor_assign_adjusts_seed() {
+        let mut h16 = Koopman16::with_seed(0xee);
+        let mut h32 = Koopman32::with_seed(0xee);
+        h16 ^= 0x11;
+        h32 ^= 0x11;
+        assert_eq!(h16.running_sum(), 0xff);
+
+        h16.update(TEST_DATA);
+        h32.update(TEST_DATA);
+        // No effect once data has been folded
+        h16 ^= 0x5a;
+        h32 ^= 0x5a;
+        assert_eq!(h16.finalize(), koopman16(TEST_DATA, 0xee ^ 0x11));
+        assert_eq!(h32.finalize(), koopman32(TEST_DATA, 0xee ^ 0x11));
+    }
+
+    #[test]
+    fn test_koopman16_from_slice() {
+        let (a, b) = (&b"first chunk, "[..], &b"second chunk"[..]);
+        for seed in [0u8, 1, 0xee] {
+            let mut hasher = Koopman16::from_slice(a, seed);
+            assert_eq!(hasher.bytes_processed(), a.len() as u64);
+            hasher.update(b);
+            assert_eq!(hasher.finalize(), koopman16(&[a, b].concat(), seed));
+
+            assert_eq!(Koopman16::from_slice(&[], seed).finalize(), 0);
+        }
+    }
+
+    // ========================================================================
+    // Tests for parity verification
+    // ========================================================================
+
+    #[test]
+    fn test_parity_consistent16() {
+        let packed = koopman16p(TEST_DATA, 0xee);
+        assert_eq!(parity_consistent16(packed, TEST_DATA, 0xee), ParityCheck::Valid);
+
+        // Corrupt stored value: checksum portion, parity bit, or both
+        assert_eq!(parity_consistent16(packed ^ 0x10, TEST_DATA, 0xee), ParityCheck::ChecksumMismatch);
+        assert_eq!(parity_consistent16(packed ^ 0x01, TEST_DATA, 0xee), ParityCheck::ParityMismatch);
+        assert_eq!(parity_consistent16(packed ^ 0x11, TEST_DATA, 0xee), ParityCheck::BothMismatch);
+
+        // Corrupt data: one flipped bit changes both portions
+        let mut data = TEST_DATA.to_vec();
+        data[2] ^= 0x08;
+        assert_eq!(parity_consistent16(packed, &data, 0xee), ParityCheck::BothMismatch);
+
+        // Two flipped bits preserve parity
+        data[5] ^= 0x40;
+        assert_eq!(parity_consistent16(packed, &data, 0xee), ParityCheck::ChecksumMismatch);
+    }
+
+    #[test]
+    fn test_verify_parity() {
+        let data = b"test data";
+
+        let cs8p = koopman8p(data, 0);
+        assert!(verify8p(data, cs8p, 0));
+        assert!(!verify8p(data, cs8p.wrapping_add(1), 0));
+
+        let cs16p = koopman16p(data, 0);
+        assert!(verify16p(data, cs16p, 0));
+        assert!(!verify16p(data, cs16p.wrapping_add(1), 0));
+
+        let cs32p = koopman32p(data, 0);
+        assert!(verify32p(data, cs32p, 0));
+        assert!(!verify32p(data, cs32p.wrapping_add(1), 0));
+    }
+
+    // ========================================================================
+    // Tests for streaming with custom modulus
+    // ========================================================================
+
+    #[test]
+    fn test_streaming_with_custom_modulus() {
+        let data = b"test data";
+
+        // Test that streaming with default modulus matches one-shot
+        let mut hasher = Koopman16::with_modulus(NONZERO_MODULUS_16);
+        hasher.update(data);
+        assert_eq!(hasher.finalize(), koopman16(data, 0));
+
+        // Test with a different modulus
+        let alt_modulus = NonZeroU32::new(32749).unwrap();
+        let mut hasher = Koopman16::with_modulus(alt_modulus);
+        hasher.update(data);
+        let streaming = hasher.finalize();
+
+        // Should produce a valid result (just verify it's deterministic)
+        let mut hasher2 = Koopman16::with_modulus(alt_modulus);
+        hasher2.update(data);
+        assert_eq!(streaming, hasher2.finalize());
+    }
+
+    // ========================================================================
+    // Tests for length limits and checked API
+    // ========================================================================
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_error_display_includes_values() {
+        let msg = KoopmanError::DataTooLong { len: 5000, max: 4092 }.to_string();
+        assert_eq!(msg, "data too long: 5000 bytes exceeds HD-safe maximum of 4092");
+
+        let msg = KoopmanError::ModulusTooLarge { modulus: 40000, max: 32767 }.to_string();
+        assert!(msg.contains("40000") && msg.contains("32767"), "{msg}");
+
+        let msg = KoopmanError::Truncated { len: 1, min: 2 }.to_string();
+        assert!(msg.contains('1') && msg.contains('2'), "{msg}");
+
+        let msg = KoopmanError::ChecksumMismatch { expected: 0xbeef, actual: 0x1234 }.to_string();
+        assert!(msg.contains("0xbeef") && msg.contains("0x1234"), "{msg}");
+
+        let msg = KoopmanError::BadLength { len: 3, expected: 2 }.to_string();
+        assert_eq!(msg, "bad length: 3 bytes, expected exactly 2");
+
+        let msg = KoopmanError::UnknownTag { tag: 0x20 }.to_string();
+        assert_eq!(msg, "unknown width tag: 0x20");
+
+        let err: Box<dyn std::error::Error> = Box::new(KoopmanError::Truncated { len: 0, min: 2 });
+        assert!(err.source().is_none());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_checked_api_uses_max_len_constants() {
+        fn check<C: core::fmt::Debug + PartialEq>(
+            max: usize,
+            checked: fn(&[u8], u8) -> Result<C, KoopmanError>,
+            unchecked: fn(&[u8], u8) -> C,
+        ) {
+            let data = vec![0x5au8; max + 1];
+            assert_eq!(checked(&data[..max], 1), Ok(unchecked(&data[..max], 1)));
+            assert_eq!(
+                checked(&data, 1),
+                Err(KoopmanError::DataTooLong { len: max + 1, max })
+            );
+        }
+
+        check(KOOPMAN8_MAX_LEN, koopman8_checked, koopman8);
+        check(KOOPMAN16_MAX_LEN, koopman16_checked, koopman16);
+        check(KOOPMAN8P_MAX_LEN, koopman8p_checked, koopman8p);
+        check(KOOPMAN16P_MAX_LEN, koopman16p_checked, koopman16p);
+
+        // The 32-bit limits are about 128 MiB; check the length guard alone
+        // instead of allocating and hashing that much
+        for max in [KOOPMAN32_MAX_LEN, KOOPMAN32P_MAX_LEN] {
+            assert_eq!(check_len(max, max), Ok(()));
+            assert_eq!(check_len(max + 1, max), Err(KoopmanError::DataTooLong { len: max + 1, max }));
+        }
+        assert_eq!(koopman32_checked(TEST_DATA, 1), Ok(koopman32(TEST_DATA, 1)));
+        assert_eq!(koopman32p_checked(TEST_DATA, 1), Ok(koopman32p(TEST_DATA, 1)));
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "7-bit checksum portion")]
+    fn test_parity_modulus_debug_assert_empty_input() {
+        let m200 = NonZeroU32::new(200).unwrap();
+        let _ = koopman8p_with_modulus(&[], 0, m200);
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "checksum portion of the parity packing")]
+    fn test_parity_streaming_modulus_debug_assert() {
+        let _ = Koopman8P::with_modulus(NonZeroU32::new(200).unwrap());
+    }
+
+    #[test]
+    fn test_parity_modulus_checked() {
+        let m200 = NonZeroU32::new(200).unwrap();
+        assert_eq!(
+            koopman8p_with_modulus_checked(TEST_DATA, 0, m200),
+            Err(KoopmanError::ModulusTooLarge { modulus: 200, max: 127 })
+        );
+
+        let m127 = NonZeroU32::new(127).unwrap();
+        assert_eq!(
+            koopman8p_with_modulus_checked(TEST_DATA, 0, m127),
+            Ok(koopman8p_with_modulus(TEST_DATA, 0, m127))
+        );
+
+        let m32768 = NonZeroU32::new(32768).unwrap();
+        assert!(koopman16p_with_modulus_checked(TEST_DATA, 0, m32768).is_err());
+        assert_eq!(
+            koopman16p_with_modulus_checked(TEST_DATA, 0, NONZERO_MODULUS_15P),
+            Ok(koopman16p(TEST_DATA, 0))
+        );
+
+        let m2_31 = NonZeroU64::new(1 << 31).unwrap();
+        assert!(koopman32p_with_modulus_checked(TEST_DATA, 0, m2_31).is_err());
+        assert_eq!(
+            koopman32p_with_modulus_checked(TEST_DATA, 0, NONZERO_MODULUS_31P),
+            Ok(koopman32p(TEST_DATA, 0))
+        );
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "7-bit checksum portion")]
+    fn test_parity_modulus_debug_assert() {
+        let m200 = NonZeroU32::new(200).unwrap();
+        let _ = koopman8p_with_modulus(TEST_DATA, 0, m200);
+    }
+
+    #[test]
+    fn test_checked_api_empty() {
+        assert_eq!(koopman16_checked(&[], 0), Ok(0));
+        assert_eq!(koopman32p_checked(&[], 0), Ok(0));
+    }
+
+    // ========================================================================
+    // Tests for length-checked streaming
+    // ========================================================================
+
+    #[test]
+    fn test_checked_digest_within_limit() {
+        let data = [0xa5u8; KOOPMAN16_MAX_LEN];
+        let mut digest = CheckedDigest::new(Koopman16::with_seed(3));
+        digest.update(&data[..1000]);
+        digest.update(&data[1000..]);
+        assert!(!digest.exceeded());
+        assert_eq!(digest.try_finalize(), Ok(koopman16(&data, 3)));
+    }
+
+    #[test]
+    fn test_checked_digest_too_long() {
+        let mut digest = CheckedDigest::new(Koopman8P::new());
+        digest.update(&[1, 2, 3, 4, 5]);
+        assert!(!digest.exceeded());
+        digest.update(&[6]);
+        assert!(digest.exceeded());
+        assert_eq!(
+            digest.try_finalize(),
+            Err(KoopmanError::DataTooLong { len: 6, max: KOOPMAN8P_MAX_LEN })
+        );
+    }
+
+    #[test]
+    fn test_checked_digest_follows_modulus() {
+        // 239 is tabulated for one byte more than the default 253
+        let mut digest = CheckedDigest::new(Koopman8::with_modulus(NonZeroU32::new(239).unwrap()));
+        digest.update(&[0xa5; 14]);
+        assert!(!digest.exceeded());
+        digest.update(&[0xa5]);
+        assert_eq!(digest.try_finalize(), Err(KoopmanError::DataTooLong { len: 15, max: 14 }));
+
+        // No known guarantee for an untabulated modulus
+        let mut digest = CheckedDigest::new(Koopman16::with_modulus(NonZeroU32::new(65521).unwrap()));
+        assert!(!digest.exceeded());
+        digest.update(b"x");
+        assert_eq!(digest.try_finalize(), Err(KoopmanError::DataTooLong { len: 1, max: 0 }));
+
+        let mut digest = CheckedDigest::new(Koopman16::with_parity(true));
+        digest.update(&[0xa5; KOOPMAN16P_MAX_LEN]);
+        assert!(!digest.exceeded());
+        digest.update(&[0xa5]);
+        assert!(digest.exceeded());
+    }
+
+    #[test]
+    fn test_try_update_refuses_past_limit() {
+        let data = [0xa5u8; KOOPMAN16_MAX_LEN + 1];
+        let mut hasher = Koopman16::with_seed(3);
+        assert_eq!(hasher.try_update(&data[..4000]), Ok(()));
+
+        let before = hasher.peek();
+        assert_eq!(
+            hasher.try_update(&data[4000..]),
+            Err(KoopmanError::DataTooLong { len: KOOPMAN16_MAX_LEN + 1, max: KOOPMAN16_MAX_LEN })
+        );
+        assert_eq!(hasher.peek(), before);
+        assert_eq!(hasher.bytes_processed(), 4000);
+
+        // Exactly reaching the limit is fine
+        assert_eq!(hasher.try_update(&data[4000..KOOPMAN16_MAX_LEN]), Ok(()));
+        assert_eq!(hasher.finalize(), koopman16(&data[..KOOPMAN16_MAX_LEN], 3));
+
+        let mut hasher = Koopman8P::new();
+        assert!(hasher.try_update(&[0; KOOPMAN8P_MAX_LEN + 1]).is_err());
+        assert_eq!(hasher.bytes_processed(), 0);
+    }
+
+    #[test]
+    fn test_try_update_custom_modulus_and_parity() {
+        let mut hasher = Koopman16::with_modulus(NonZeroU32::new(65521).unwrap());
+        assert_eq!(hasher.max_len(), 0);
+        assert_eq!(hasher.try_update(b"x"), Err(KoopmanError::DataTooLong { len: 1, max: 0 }));
+        assert_eq!(hasher.try_update(&[]), Ok(()));
+
+        let data = [0xa5u8; KOOPMAN16P_MAX_LEN + 1];
+        let mut hasher = Koopman16::with_parity(true);
+        assert_eq!(hasher.max_len(), KOOPMAN16P_MAX_LEN);
+        assert_eq!(
+            hasher.try_update(&data),
+            Err(KoopmanError::DataTooLong { len: KOOPMAN16P_MAX_LEN + 1, max: KOOPMAN16P_MAX_LEN })
+        );
+        assert_eq!(hasher.try_update(&data[..KOOPMAN16P_MAX_LEN]), Ok(()));
+        assert_eq!(hasher.finalize(), koopman16p(&data[..KOOPMAN16P_MAX_LEN], 0));
+    }
+
+    #[test]
+    fn test_on_overflow_fires_once_at_boundary() {
+        let data = [0xa5u8; KOOPMAN16_MAX_LEN + 10];
+        let mut fired = 0;
+        let mut hasher = Koopman16::with_seed(3).on_overflow(|| fired += 1);
+
+        // Reaching the limit exactly is still covered
+        hasher.update(&data[..KOOPMAN16_MAX_LEN - 1]);
+        hasher.update(&data[KOOPMAN16_MAX_LEN - 1..KOOPMAN16_MAX_LEN]);
+        assert!(!hasher.overflowed());
+
+        hasher.update(&data[KOOPMAN16_MAX_LEN..KOOPMAN16_MAX_LEN + 1]);
+        assert!(hasher.overflowed());
+        hasher.update(&data[KOOPMAN16_MAX_LEN + 1..]);
+        hasher.update(&[]);
+        assert_eq!(hasher.finalize(), koopman16(&data, 3));
+        assert_eq!(fired, 1);
+
+        // Crossing in the middle of one update
+        let mut fired = 0;
+        let mut hasher = Koopman16::new().on_overflow(|| fired += 1);
+        hasher.update(&data);
+        let _ = hasher.finalize();
+        assert_eq!(fired, 1);
+    }
+
+    #[test]
+    fn test_on_overflow_follows_modulus() {
+        let mut fired = 0;
+        let modulus = NonZeroU32::new(65521).unwrap();
+        let mut hasher = Koopman16::with_modulus(modulus).on_overflow(|| fired += 1);
+        hasher.update(&[]);
+        assert!(!hasher.overflowed());
+        hasher.update(b"x");
+        assert!(hasher.overflowed());
+        let _ = hasher.finalize();
+        assert_eq!(fired, 1);
+    }
+
+    #[test]
+    fn test_finalize_with_guarantee_within_bound() {
+        let data = [0xa5u8; KOOPMAN16_MAX_LEN];
+        let mut hasher = Koopman16::with_seed(3);
+        for chunk in data.chunks(1000) {
+            hasher.update(chunk);
+        }
+        assert_eq!(hasher.finalize_with_guarantee(), (koopman16(&data, 3), Guarantee::Hd3));
+
+        assert_eq!(Koopman16::new().finalize_with_guarantee(), (0, Guarantee::Hd3));
+    }
+
+    #[test]
+    fn test_finalize_with_guarantee_over_bound() {
+        let data = [0xa5u8; KOOPMAN16_MAX_LEN + 1];
+        let mut hasher = Koopman16::with_seed(3);
+        hasher.update(&data[..KOOPMAN16_MAX_LEN]);
+        hasher.update(&data[KOOPMAN16_MAX_LEN..]);
+        assert_eq!(hasher.finalize_with_guarantee(), (koopman16(&data, 3), Guarantee::Degraded));
+    }
+
+    #[test]
+    fn test_finalize_with_guarantee_custom_modulus() {
+        let modulus = NonZeroU32::new(65521).unwrap();
+        assert_eq!(Koopman16::with_modulus(modulus).finalize_with_guarantee(), (0, Guarantee::Degraded));
+
+        let mut hasher = Koopman16::with_modulus(modulus);
+        hasher.update(TEST_DATA);
+        assert_eq!(
+            hasher.finalize_with_guarantee(),
+            (koopman16_with_modulus(TEST_DATA, 0, modulus), Guarantee::Degraded)
+        );
+    }
+
+    #[test]
+    fn test_finalize_with_guarantee_parity() {
+        let data = [0xa5u8; KOOPMAN16P_MAX_LEN + 1];
+        let mut hasher = Koopman16::with_parity(true);
+        hasher.update(&data[..KOOPMAN16P_MAX_LEN]);
+        let (checksum, guarantee) = hasher.clone().finalize_with_guarantee();
+        assert_eq!(checksum, koopman16p(&data[..KOOPMAN16P_MAX_LEN], 0));
+        assert_eq!(guarantee, Guarantee::Hd3);
+
+        hasher.update(&data[KOOPMAN16P_MAX_LEN..]);
+        assert_eq!(hasher.finalize_with_guarantee(), (koopman16p(&data, 0), Guarantee::Degraded));
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use core::num::NonZeroU32;
-    use core::num::NonZeroU64;
-    const NONZERO_MODULUS_16: NonZeroU32 = NonZeroU32::new(MODULUS_16).unwrap();
-    const NONZERO_MODULUS_32: NonZeroU64 = NonZeroU64::new(MODULUS_32).unwrap();
+    #[cfg(feature = "digest")]
+    #[test]
+    fn test_checked_digest_update_trait() {
+        fn feed<D: digest::Update>(d: &mut D, data: &[u8]) {
+            d.update(data);
+        }
 
-    // Test vectors based on the C reference implementation
-    const TEST_DATA: &[u8] = b"123456789";
+        let mut digest = CheckedDigest::<Koopman32>::default();
+        feed(&mut digest, TEST_DATA);
+        assert_eq!(digest.try_finalize(), Ok(koopman32(TEST_DATA, 0)));
+    }
+
+    // ========================================================================
+    // Tests for typed checksums
+    // ========================================================================
 
+    #[cfg(feature = "alloc")]
     #[test]
-    fn test_koopman8_empty() {
-        assert_eq!(koopman8(&[], 0), 0);
-        assert_eq!(koopman8(&[], 42), 0); // Empty data returns 0 regardless of initial seed
+    fn test_typed_checksums_sort_numerically() {
+        let records: [&[u8]; 6] = [b"a", b"bb", b"ccc", b"dddd", b"eeeee", b"ffffff"];
+
+        let mut typed: Vec<Checksum16> = records.iter().map(|r| koopman16_typed(r, 1)).collect();
+        let mut raw: Vec<u16> = records.iter().map(|r| koopman16(r, 1)).collect();
+        typed.sort();
+        raw.sort();
+
+        assert_eq!(typed.iter().map(|c| c.get()).collect::<Vec<_>>(), raw);
+        assert!(typed.windows(2).all(|w| w[0] <= w[1]));
     }
 
     #[test]
-    fn test_koopman8_single_byte() {
-        // For single byte 0x12: sum = 0x12, then append zero: (0x12 << 8) % 253 = 4608 % 253 = 54
-        assert_eq!(koopman8(&[0x12], 0), ((0x12u32 << 8) % MODULUS_8) as u8);
+    fn test_typed_checksums_match_raw() {
+        assert_eq!(koopman8_typed(TEST_DATA, 2).get(), koopman8(TEST_DATA, 2));
+        assert_eq!(u16::from(koopman16_typed(TEST_DATA, 2)), koopman16(TEST_DATA, 2));
+        assert_eq!(koopman32_typed(TEST_DATA, 2), Checksum32::from(koopman32(TEST_DATA, 2)));
     }
 
     #[test]
-    fn test_koopman16_empty() {
-        assert_eq!(koopman16(&[], 0), 0);
-        assert_eq!(koopman16(&[], 42), 0); // Empty data returns 0 regardless of initial seed
+    fn test_typed_checksums_compare_with_raw() {
+        let raw = koopman16(TEST_DATA, 0xee);
+        assert_eq!(koopman16_typed(TEST_DATA, 0xee), raw);
+        assert_eq!(raw, koopman16_typed(TEST_DATA, 0xee));
+        assert_ne!(koopman16_typed(TEST_DATA, 0xee), raw ^ 1);
+
+        assert_eq!(Checksum8(0x12), 0x12u8);
+        assert_eq!(0x1234u16, Checksum16(0x1234));
+        assert_eq!(Checksum32(0xdead_beef), 0xdead_beefu32);
+        assert_ne!(Checksum32(0), 1u32);
     }
 
     #[test]
-    fn test_koopman32_empty() {
-        assert_eq!(koopman32(&[], 0), 0);
-        assert_eq!(koopman32(&[], 42), 0); // Empty data returns 0 regardless of initial seed
+    fn test_typed_checksums_layout() {
+        assert_eq!(core::mem::size_of::<Checksum16>(), 2);
+        assert_eq!(core::mem::align_of::<Checksum16>(), core::mem::align_of::<u16>());
+        assert_eq!(core::mem::size_of::<Checksum32>(), 4);
+        assert_eq!(core::mem::align_of::<Checksum32>(), core::mem::align_of::<u32>());
     }
 
     #[test]
-    fn test_streaming_koopman8() {
-        let full = koopman8(TEST_DATA, 0);
+    fn test_typed_checksums_wire_bytes() {
+        let wire = [0x12u8, 0x34, 0x56, 0x78, 0x9a];
 
-        let mut hasher = Koopman8::new();
-        hasher.update(&TEST_DATA[..4]);
-        hasher.update(&TEST_DATA[4..]);
-        let streaming = hasher.finalize();
+        assert_eq!(Checksum16::try_from(&wire[..2]), Ok(Checksum16(0x1234)));
+        assert_eq!(Checksum32::try_from(&wire[1..]), Ok(Checksum32(0x3456_789a)));
+        assert_eq!(Checksum16(0x1234).to_be_bytes(), [0x12, 0x34]);
 
-        assert_eq!(full, streaming);
+        let cs = koopman32_typed(TEST_DATA, 0xee);
+        assert_eq!(Checksum32::try_from(&cs.to_be_bytes()[..]), Ok(cs));
     }
 
     #[test]
-    fn test_streaming_koopman16() {
-        let full = koopman16(TEST_DATA, 0);
+    fn test_typed_checksums_wrong_length() {
+        let wire = [0u8; 5];
 
-        let mut hasher = Koopman16::new();
-        hasher.update(&TEST_DATA[..4]);
-        hasher.update(&TEST_DATA[4..]);
-        let streaming = hasher.finalize();
+        assert_eq!(
+            Checksum16::try_from(&wire[..3]),
+            Err(KoopmanError::BadLength { len: 3, expected: 2 })
+        );
+        assert_eq!(
+            Checksum32::try_from(&wire[..]),
+            Err(KoopmanError::BadLength { len: 5, expected: 4 })
+        );
+        assert_eq!(
+            Checksum32::try_from(&wire[..0]),
+            Err(KoopmanError::BadLength { len: 0, expected: 4 })
+        );
+    }
 
-        assert_eq!(full, streaming);
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn test_typed_checksums_bytemuck_cast() {
+        let sums = [koopman16_typed(b"a", 1), koopman16_typed(b"bb", 1), Checksum16(0xbeef)];
+
+        let bytes: &[u8] = bytemuck::cast_slice(&sums);
+        assert_eq!(bytes.len(), 6);
+        assert_eq!(&bytes[4..], &0xbeefu16.to_ne_bytes());
+
+        let back: &[Checksum16] = bytemuck::cast_slice(bytes);
+        assert_eq!(back, &sums);
     }
 
+    // ========================================================================
+    // Tests for runtime width selection
+    // ========================================================================
+
     #[test]
-    fn test_streaming_koopman32() {
-        let full = koopman32(TEST_DATA, 0);
+    fn test_koopman_dyn_matches_concrete() {
+        for seed in [0u8, 1, 0xee] {
+            assert_eq!(koopman_dyn(Width::Bits8, TEST_DATA, seed), koopman8(TEST_DATA, seed) as u64);
+            assert_eq!(koopman_dyn(Width::Bits16, TEST_DATA, seed), koopman16(TEST_DATA, seed) as u64);
+            assert_eq!(koopman_dyn(Width::Bits32, TEST_DATA, seed), koopman32(TEST_DATA, seed) as u64);
+        }
+        assert_eq!(Width::Bits8.bits() + Width::Bits16.bits(), 24);
+        assert_eq!(Width::Bits32.bits(), 32);
+    }
 
-        let mut hasher = Koopman32::new();
-        hasher.update(&TEST_DATA[..4]);
-        hasher.update(&TEST_DATA[4..]);
-        let streaming = hasher.finalize();
+    // ========================================================================
+    // Tests for modulus selection
+    // ========================================================================
 
-        assert_eq!(full, streaming);
+    #[test]
+    fn test_min_modulus_for_length() {
+        assert_eq!(min_modulus_for_length(Width::Bits8, 0), Some(239));
+        assert_eq!(min_modulus_for_length(Width::Bits8, 13), Some(239));
+        assert_eq!(min_modulus_for_length(Width::Bits8, 14), Some(239));
+        assert_eq!(min_modulus_for_length(Width::Bits8, 15), None);
+        assert_eq!(min_modulus_for_length(Width::Bits16, 4092), Some(65519));
+        assert_eq!(min_modulus_for_length(Width::Bits16, 4093), None);
+        assert_eq!(min_modulus_for_length(Width::Bits32, KOOPMAN32_MAX_LEN), Some(4294967291));
+        assert_eq!(min_modulus_for_length(Width::Bits32, KOOPMAN32_MAX_LEN + 1), None);
     }
 
     #[test]
-    fn test_seed_affects_result() {
-        let result0 = koopman16(TEST_DATA, 0);
-        let result1 = koopman16(TEST_DATA, 1);
-        assert_ne!(result0, result1);
+    fn test_moduli_8_table_lengths() {
+        // A two-bit error 2^b * (2^d ± 1) goes undetected iff 2^d ≡ ∓1 (mod m),
+        // so the guaranteed length is the smallest such d, in whole bytes.
+        for &(modulus, max_len) in MODULI_8 {
+            let mut d = 1;
+            let mut x = 2 % modulus;
+            while x != 1 && x != modulus - 1 {
+                x = x * 2 % modulus;
+                d += 1;
+            }
+            assert_eq!(d / 8, max_len, "modulus = {}", modulus);
+        }
+
+        // And every two-bit error in 14 bytes of zeros is caught by 239
+        let m = NonZeroU32::new(239).unwrap();
+        let data = [0u8; 14];
+        let base = koopman8_with_modulus(&data, 1, m);
+        for a in 0..data.len() * 8 {
+            for b in (a + 1)..data.len() * 8 {
+                let mut corrupted = data;
+                corrupted[a / 8] ^= 1 << (a % 8);
+                corrupted[b / 8] ^= 1 << (b % 8);
+                assert_ne!(koopman8_with_modulus(&corrupted, 1, m), base, "bits {} and {}", a, b);
+            }
+        }
     }
 
+    // ========================================================================
+    // Tests for word slices
+    // ========================================================================
+
+    #[cfg(feature = "alloc")]
     #[test]
-    fn test_single_bit_detection() {
-        let original = koopman16(TEST_DATA, 0);
+    fn test_koopman32_u32_slice_matches_flattened() {
+        let words: Vec<u32> = (0..257u32).map(|i| i.wrapping_mul(0x9E37_79B9)).collect();
+
+        for n in [0, 1, 2, 3, 257] {
+            let be: Vec<u8> = words[..n].iter().flat_map(|w| w.to_be_bytes()).collect();
+            let le: Vec<u8> = words[..n].iter().flat_map(|w| w.to_le_bytes()).collect();
+            assert_eq!(koopman32_u32_slice(&words[..n], 0xee, Endian::Big), koopman32(&be, 0xee));
+            assert_eq!(koopman32_u32_slice(&words[..n], 0xee, Endian::Little), koopman32(&le, 0xee));
+        }
+    }
 
-        for i in 0..TEST_DATA.len() {
-            for bit in 0..8 {
-                let mut corrupted = TEST_DATA.to_vec();
-                corrupted[i] ^= 1 << bit;
-                let corrupted_checksum = koopman16(&corrupted, 0);
-                assert_ne!(original, corrupted_checksum,
-                    "Failed to detect single bit flip at byte {} bit {}", i, bit);
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_koopman32_word16_reference() {
+        // Straightforward word loop with a generic reduction
+        fn reference(words: &[u16], seed: u8) -> u32 {
+            let Some((&first, rest)) = words.split_first() else {
+                return 0;
+            };
+            let mut sum = (first ^ ((seed as u16) << 8)) as u64;
+            for &word in rest {
+                sum = ((sum << 16) + word as u64) % MODULUS_32;
             }
+            sum = (sum << 16) % MODULUS_32;
+            sum = (sum << 16) % MODULUS_32;
+            sum as u32
+        }
+
+        let words: Vec<u16> = (0..513u32).map(|i| i.wrapping_mul(0x9E37_79B9) as u16).collect();
+        for n in [0, 1, 2, 3, 513] {
+            let big = koopman32_word16(&words[..n], 0xee, Endian::Big);
+            assert_eq!(big, reference(&words[..n], 0xee));
+
+            let be: Vec<u8> = words[..n].iter().flat_map(|w| w.to_be_bytes()).collect();
+            let le: Vec<u8> = words[..n].iter().flat_map(|w| w.to_le_bytes()).collect();
+            assert_eq!(big, koopman32(&be, 0xee));
+            assert_eq!(koopman32_word16(&words[..n], 0xee, Endian::Little), koopman32(&le, 0xee));
         }
+
+        // Largest possible intermediates
+        let max = [0xFFFFu16; 64];
+        assert_eq!(koopman32_word16(&max, 0xff, Endian::Big), reference(&max, 0xff));
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
-    fn test_reference_calculation() {
-        // Input: [0x12, 0x34, 0x56] with initial seed 0, modulus 253
-        // Step 1: sum = 0x12 = 18
-        // Step 2: sum = ((18 << 8) + 0x34) % 253 = 4660 % 253 = 106
-        // Step 3: sum = ((106 << 8) + 0x56) % 253 = 27222 % 253 = 151
-        // Final:  sum = (151 << 8) % 253 = 38656 % 253 = 200
+    fn test_koopman32_records_matches_flattened() {
+        let records: Vec<[u8; 8]> =
+            (0..100u64).map(|i| i.wrapping_mul(0x9E37_79B9_7F4A_7C15).to_be_bytes()).collect();
+
+        for n in [0, 1, 2, 3, 100] {
+            let mut flat = Vec::new();
+            for record in &records[..n] {
+                flat.extend_from_slice(record);
+            }
+            assert_eq!(koopman32_records(&records[..n], 0xee), koopman32(&flat, 0xee));
+        }
 
-        let data = [0x12u8, 0x34, 0x56];
-        let result = koopman8(&data, 0);
-        assert_eq!(result, 200);
+        let odd: [[u8; 3]; 3] = [[1, 2, 3], [4, 5, 6], [7, 8, 9]];
+        assert_eq!(koopman32_records(&odd, 0), koopman32(&[1, 2, 3, 4, 5, 6, 7, 8, 9], 0));
+        assert_eq!(koopman32_records::<0>(&[[]; 4], 0xee), 0);
     }
 
     // ========================================================================
-    // Additional tests for parity variants
+    // Tests for reversed byte order
     // ========================================================================
 
+    #[cfg(feature = "alloc")]
     #[test]
-    fn test_koopman8p_parity_correctness() {
-        // Verify that the parity bit correctly reflects the parity of data bytes only
-        // (per the reference C implementation, checksum is NOT included in parity)
-        let data = b"Test";
-        let result = koopman8p(data, 0);
+    fn test_koopman16_reversed_matches_reversed_buffer() {
+        let data: Vec<u8> = (0..300u32).map(|i| (i * 31 + 7) as u8).collect();
+
+        for len in [0, 1, 2, 3, 9, 64, 300] {
+            let slice = &data[..len];
+            let reversed: Vec<u8> = slice.iter().rev().copied().collect();
+            for seed in [0u8, 1, 0xee] {
+                assert_eq!(
+                    koopman16_reversed(slice, seed),
+                    koopman16(&reversed, seed),
+                    "len = {}, seed = {}", len, seed
+                );
+            }
+        }
+    }
 
-        // The checksum is in upper 7 bits
-        let _checksum = result >> 1;
-        let parity_bit = result & 1;
+    #[test]
+    fn test_koopman16_refout() {
+        for data in [&b""[..], b"a", TEST_DATA, &[0xFF; 300]] {
+            for seed in [0u8, 1, 0xee] {
+                let normal = koopman16(data, seed);
+                let reflected = koopman16_refout(data, seed);
+                assert_eq!(reflected, normal.reverse_bits());
+                assert_eq!(reflected.reverse_bits(), normal);
+            }
+        }
+    }
 
-        // Compute expected parity: XOR all data bytes (NOT including checksum)
-        let mut expected_parity: u8 = 0;
-        for &byte in data {
-            expected_parity ^= byte;
+    // ========================================================================
+    // Tests for ring buffers
+    // ========================================================================
+
+    #[test]
+    fn test_koopman16_wrapped_matches_concatenation() {
+        let msg = b"a message that wraps around the ring";
+
+        for split in 0..=msg.len() {
+            let (head, tail) = msg.split_at(split);
+            assert_eq!(koopman16_wrapped(head, tail, 0xee), koopman16(msg, 0xee), "split = {}", split);
         }
-        let expected_parity_bit = expected_parity.count_ones() & 1;
 
-        assert_eq!(parity_bit as u32, expected_parity_bit);
+        // Empty head: the seed lands on the tail's first byte
+        assert_eq!(koopman16_wrapped(&[], b"tail", 0xee), koopman16(b"tail", 0xee));
+        // Empty tail
+        assert_eq!(koopman16_wrapped(b"head", &[], 0xee), koopman16(b"head", 0xee));
+        // Both empty
+        assert_eq!(koopman16_wrapped(&[], &[], 0xee), 0);
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
-    fn test_parity_variants_detect_single_bit_errors() {
-        let data = b"Test";
-        let original = koopman16p(data, 0);
+    fn test_koopman16_header_payload_matches_concatenation() {
+        let msg: Vec<u8> = (0..100u32).map(|i| (i * 131 + 7) as u8).collect();
+
+        // Splits at every offset, so each part hits the wide fold and the tail
+        for split in 0..=msg.len() {
+            let (header, payload) = msg.split_at(split);
+            for seed in [0u8, 0xee] {
+                assert_eq!(
+                    koopman16_header_payload(header, payload, seed),
+                    koopman16(&msg, seed),
+                    "split = {split}"
+                );
+            }
+        }
 
-        for i in 0..data.len() {
-            for bit in 0..8 {
-                let mut corrupted = data.to_vec();
-                corrupted[i] ^= 1 << bit;
-                let corrupted_checksum = koopman16p(&corrupted, 0);
-                assert_ne!(original, corrupted_checksum,
-                    "Failed to detect single bit flip at byte {} bit {}", i, bit);
+        // Empty header: the seed lands on the payload's first byte
+        assert_eq!(koopman16_header_payload(&[], b"payload", 0xee), koopman16(b"payload", 0xee));
+        // Empty payload
+        assert_eq!(koopman16_header_payload(b"header", &[], 0xee), koopman16(b"header", 0xee));
+        // Both empty
+        assert_eq!(koopman16_header_payload(&[], &[], 0xee), 0);
+        // All-ones keeps the sum near the modulus
+        let ones = [0xFF; 40];
+        assert_eq!(koopman16_header_payload(&ones[..13], &ones[13..], 1), koopman16(&ones, 1));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_koopman16_nonced_varies_with_nonce() {
+        let payload = b"telemetry frame";
+
+        // Consecutive counters never collide within a window of 65519
+        let mut seen = vec![false; 1 << 16];
+        for nonce in 0..MODULUS_16 {
+            let checksum = koopman16_nonced(payload, 0xee, nonce) as usize;
+            assert!(!seen[checksum], "nonce = {nonce}");
+            seen[checksum] = true;
+        }
+        // The derived register is periodic in the modulus
+        assert_eq!(koopman16_nonced(payload, 0xee, 7), koopman16_nonced(payload, 0xee, 7 + MODULUS_16));
+
+        assert_eq!(koopman16_nonced(payload, 0xee, 0x0102_0304), {
+            let mut framed = vec![1, 2, 3, 4];
+            framed.extend_from_slice(payload);
+            koopman16(&framed, 0xee)
+        });
+        assert_eq!(koopman16_nonced(&[], 0xee, 5), 0);
+    }
+
+    // ========================================================================
+    // Tests for sampled checksums
+    // ========================================================================
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_koopman16_sampled_matches_strided_subsequence() {
+        let data: Vec<u8> = (0..1000u32).map(|i| (i * 73 + 11) as u8).collect();
+
+        for stride in [1, 2, 3, 7, 999, 1000, 5000] {
+            let subsequence: Vec<u8> = data.iter().step_by(stride).copied().collect();
+            assert_eq!(
+                koopman16_sampled(&data, 0xee, stride),
+                koopman16(&subsequence, 0xee),
+                "stride = {}",
+                stride
+            );
+        }
+        assert_eq!(koopman16_sampled(&[], 0xee, 4), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "stride must be non-zero")]
+    fn test_koopman16_sampled_zero_stride() {
+        let _ = koopman16_sampled(TEST_DATA, 0xee, 0);
+    }
+
+    // ========================================================================
+    // Tests for storage pages
+    // ========================================================================
+
+    #[test]
+    fn test_koopman16_page_matches_koopman16() {
+        let mut state: u64 = 0x9e37_79b9_7f4a_7c15;
+        let mut page = [0u8; KOOPMAN_PAGE_SIZE];
+        for _ in 0..64 {
+            for byte in page.iter_mut() {
+                state = state.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1);
+                *byte = (state >> 56) as u8;
+            }
+            for seed in [0u8, 1, 0xee, 0xff] {
+                assert_eq!(koopman16_page(&page, seed), koopman16(&page, seed));
             }
         }
+
+        // Largest possible words at every position
+        for page in [[0x00; KOOPMAN_PAGE_SIZE], [0xFF; KOOPMAN_PAGE_SIZE]] {
+            assert_eq!(koopman16_page(&page, 0xff), koopman16(&page, 0xff));
+        }
     }
 
     // ========================================================================
-    // Tests for custom moduli
+    // Tests for caller-owned scratch
     // ========================================================================
 
+    #[cfg(feature = "alloc")]
     #[test]
-    fn test_custom_modulus_8() {
-        const MODULUS_8_ALT: u32 = 239;
-        let data = b"test";
-        let result1 = koopman8_with_modulus(data, 0, NONZERO_MODULUS_8);
-        let modulus_alt = NonZeroU32::new(MODULUS_8_ALT).unwrap();
-        let result2 = koopman8_with_modulus(data, 0, modulus_alt);
+    fn test_koopman16_in_matches_koopman16() {
+        let data: Vec<u8> = (0..1000u32).map(|i| (i * 131 + 7) as u8).collect();
+        let mut scratch = ReductionScratch::new();
+        for data in [&b""[..], b"a", b"ab", b"abc", TEST_DATA, &data, &[0xFF; 300]] {
+            for seed in [0u8, 1, 0xee, 0xff] {
+                assert_eq!(koopman16_in(data, seed, &mut scratch), koopman16(data, seed));
+            }
+        }
 
-        // Different moduli should (usually) produce different results
-        // Note: They could theoretically be equal, but very unlikely
-        assert_ne!(result1, result2);
+        // Leftover state from a previous call does not leak into the next
+        let _ = koopman16_in(&data, 0xee, &mut scratch);
+        assert_eq!(koopman16_in(TEST_DATA, 0, &mut scratch), koopman16(TEST_DATA, 0));
+        assert_eq!(scratch.running_sum(), koopman16_with_trailer(TEST_DATA, 0, 0) as u32);
     }
 
+    // ========================================================================
+    // Tests for custom trailers
+    // ========================================================================
+
     #[test]
-    fn test_custom_modulus_matches_default() {
-        let data = b"test data";
+    fn test_koopman16_with_trailer() {
+        for data in [&b""[..], b"a", TEST_DATA, &[0xFF; 300]] {
+            for seed in [0u8, 1, 0xee] {
+                assert_eq!(koopman16_with_trailer(data, seed, 2), koopman16(data, seed));
+            }
+        }
 
+        // Each extra zero byte is one more shift-and-reduce step
+        let raw = koopman16_with_trailer(TEST_DATA, 0xee, 0) as u32;
+        assert!(raw < MODULUS_16);
+        assert_eq!(koopman16_with_trailer(TEST_DATA, 0xee, 1) as u32, (raw << 8) % MODULUS_16);
         assert_eq!(
-            koopman8(data, 0),
-            koopman8_with_modulus(data, 0, NONZERO_MODULUS_8)
-        );
-        assert_eq!(
-            koopman16(data, 0),
-            koopman16_with_modulus(data, 0, NONZERO_MODULUS_16)
+            koopman16_with_trailer(TEST_DATA, 0xee, 4),
+            koopman16(&[TEST_DATA, &[0, 0][..]].concat(), 0xee)
         );
+
+        // Huge counts finish quickly; 65519 is prime, so 256^65518 = 1
+        let step = |count: usize| (0..count).fold(raw, |sum, _| (sum << 8) % MODULUS_16) as u16;
+        assert_eq!(koopman16_with_trailer(TEST_DATA, 0xee, 1000), step(1000));
         assert_eq!(
-            koopman32(data, 0),
-            koopman32_with_modulus(data, 0, NONZERO_MODULUS_32)
+            koopman16_with_trailer(TEST_DATA, 0xee, usize::MAX),
+            step(usize::MAX % 65518)
         );
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
-    fn test_parity_custom_modulus_matches_default() {
-        let data = b"test data";
+    fn test_koopman16_region_aligned() {
+        let frame: Vec<u8> = (0..64u32).map(|i| (i * 131 + 7) as u8).collect();
+
+        // end + 2 already on the boundary: plain koopman16 of the region
+        assert_eq!(koopman16_region(&frame, 5, 30, 4, 0xee), Ok(koopman16(&frame[5..30], 0xee)));
+        assert_eq!(koopman16_region(&frame, 0, 14, 8, 0xee), Ok(koopman16(&frame[..14], 0xee)));
+        for (start, end) in [(0, 64), (3, 17), (10, 11)] {
+            assert_eq!(
+                koopman16_region(&frame, start, end, 1, 1),
+                Ok(koopman16(&frame[start..end], 1))
+            );
+        }
+    }
 
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_koopman16_region_unaligned() {
+        let frame: Vec<u8> = (0..64u32).map(|i| (i * 131 + 7) as u8).collect();
+
+        // Manual: the region followed by its zero trailer, up to the boundary
+        let manual = |start: usize, end: usize, boundary: usize| {
+            let mut padded = frame[start..end].to_vec();
+            padded.resize(boundary - start, 0);
+            // koopman16 appends the last two zeros itself
+            padded.truncate(padded.len() - 2);
+            koopman16(&padded, 0xee)
+        };
+
+        // end = 13: 15 rounds up to 16, one extra zero
+        assert_eq!(koopman16_region(&frame, 2, 13, 4, 0xee), Ok(manual(2, 13, 16)));
+        // end = 19: 21 rounds up to 24 for 8-byte words, 32 for 16-byte
+        assert_eq!(koopman16_region(&frame, 2, 19, 8, 0xee), Ok(manual(2, 19, 24)));
+        assert_eq!(koopman16_region(&frame, 2, 19, 16, 0xee), Ok(manual(2, 19, 32)));
+        // end = 31: 33 rounds up to 36
+        assert_eq!(koopman16_region(&frame, 0, 31, 4, 0xee), Ok(manual(0, 31, 36)));
+        assert_ne!(koopman16_region(&frame, 0, 31, 4, 0xee), Ok(koopman16(&frame[..31], 0xee)));
+
+        assert_eq!(koopman16_region(&frame, 7, 7, 4, 0xee), Ok(0));
+    }
+
+    #[test]
+    fn test_koopman16_region_end_out_of_range() {
+        let frame = [0u8; 16];
+        for end in [17, usize::MAX - 2, usize::MAX - 1, usize::MAX] {
+            assert_eq!(
+                koopman16_region(&frame, 0, end, 4, 0xee),
+                Err(KoopmanError::Truncated { len: 16, min: end })
+            );
+        }
+    }
+
+    #[test]
+    fn test_koopman16_region_huge_align() {
+        // Aligning to usize::MAX pads with usize::MAX - 16 zeros after the
+        // standard two; 65519 is prime, so only the count mod 65518 matters
+        let frame = [0x5au8; 16];
         assert_eq!(
-            koopman8p(data, 0),
-            koopman8p_with_modulus(data, 0, NONZERO_MODULUS_7P)
-        );
-        assert_eq!(
-            koopman16p(data, 0),
-            koopman16p_with_modulus(data, 0, NONZERO_MODULUS_15P)
-        );
-        assert_eq!(
-            koopman32p(data, 0),
-            koopman32p_with_modulus(data, 0, NONZERO_MODULUS_31P)
+            koopman16_region(&frame, 0, 14, usize::MAX, 0xee),
+            Ok(koopman16_with_trailer(&frame[..14], 0xee, 2 + (usize::MAX - 16) % 65518))
         );
     }
 
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_koopman32_len_bound_appends_length() {
+        let data: Vec<u8> = (0..300u32).map(|i| (i * 131 + 7) as u8).collect();
+        let mut framed = data.clone();
+        framed.extend_from_slice(&300u32.to_be_bytes());
+        assert_eq!(koopman32_len_bound(&data, 0xee), koopman32(&framed, 0xee));
+        assert_eq!(koopman32_len_bound(&[], 0xee), 0);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_koopman32_len_bound_separates_colliding_lengths() {
+        // With seed 0 a leading zero byte leaves the sum unchanged
+        let short = b"test data".to_vec();
+        let long = [&[0u8, 0][..], &short].concat();
+        assert_eq!(koopman32(&long, 0), koopman32(&short, 0));
+        assert_ne!(koopman32_len_bound(&long, 0), koopman32_len_bound(&short, 0));
+
+        // With a seed, a first byte equal to it zeroes the sum the same way
+        let mut long = vec![0xee, short[0] ^ 0xee];
+        long.extend_from_slice(&short[1..]);
+        assert_eq!(koopman32(&long, 0xee), koopman32(&short, 0xee));
+        assert_ne!(koopman32_len_bound(&long, 0xee), koopman32_len_bound(&short, 0xee));
+    }
+
+    #[test]
+    #[should_panic(expected = "align_to must be non-zero")]
+    fn test_koopman16_region_zero_align() {
+        let _ = koopman16_region(TEST_DATA, 0, 4, 0, 0);
+    }
+
+    // ========================================================================
+    // Tests for content keys
+    // ========================================================================
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_koopman32_key_matches_hex() {
+        for data in [&b""[..], b"a", TEST_DATA, b"test data for content keys"] {
+            for seed in [0u8, 1, 0xee] {
+                let key = koopman32_key(data, seed);
+                let expected = format!("{:08X}", koopman32(data, seed));
+                assert_eq!(&key[..], expected.as_bytes());
+            }
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_koopman32_tag_round_trip() {
+        for data in [&b""[..], b"a", TEST_DATA, b"test data for content keys"] {
+            for seed in [0u8, 1, 0xee] {
+                let tag = koopman32_tag(data, seed);
+                assert_eq!(tag.len(), 7);
+                assert!(verify32_tag(data, &tag, seed));
+                assert!(verify32_tag(data, &tag.to_ascii_lowercase(), seed));
+            }
+        }
+
+        assert_eq!(koopman32_tag(&[], 0xee), "0000000");
+
+        // O decodes as 0 and I/L as 1
+        assert!(verify32_tag(&[], "OoOOOOO", 0xee));
+        let (data, tag) = (0..=255u8)
+            .map(|b| ([b], koopman32_tag(&[b], 0)))
+            .find(|(_, tag)| tag.contains('1'))
+            .unwrap();
+        assert!(verify32_tag(&data, &tag.replace('1', "l"), 0));
+        assert!(verify32_tag(&data, &tag.replace('1', "I"), 0));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_verify32_tag_rejects_malformed() {
+        let tag = koopman32_tag(TEST_DATA, 0xee);
+
+        assert!(!verify32_tag(b"other data", &tag, 0xee));
+        assert!(!verify32_tag(TEST_DATA, &tag[..6], 0xee));
+        assert!(!verify32_tag(TEST_DATA, &format!("{tag}0"), 0xee));
+        assert!(!verify32_tag(TEST_DATA, "", 0xee));
+        // U is excluded from the alphabet; punctuation and non-ASCII are invalid
+        assert!(!verify32_tag(TEST_DATA, &format!("U{}", &tag[1..]), 0xee));
+        assert!(!verify32_tag(TEST_DATA, &format!("{}!", &tag[..6]), 0xee));
+        assert!(!verify32_tag(TEST_DATA, "\u{fc}00000", 0xee));
+        // Seven digits hold 35 bits; values past u32::MAX are rejected
+        assert!(!verify32_tag(&[], "4000000", 0xee));
+    }
+
+    // ========================================================================
+    // Tests for detailed verification
+    // ========================================================================
+
+    #[test]
+    fn test_verify_detailed_reports_actual() {
+        let corrupt = b"Tesd data";
+
+        assert_eq!(verify8_detailed(TEST_DATA, koopman8(TEST_DATA, 1), 1), Ok(()));
+        assert_eq!(verify16_detailed(TEST_DATA, koopman16(TEST_DATA, 1), 1), Ok(()));
+        assert_eq!(verify32_detailed(TEST_DATA, koopman32(TEST_DATA, 1), 1), Ok(()));
+        assert_eq!(verify8p_detailed(TEST_DATA, koopman8p(TEST_DATA, 1), 1), Ok(()));
+        assert_eq!(verify16p_detailed(TEST_DATA, koopman16p(TEST_DATA, 1), 1), Ok(()));
+        assert_eq!(verify32p_detailed(TEST_DATA, koopman32p(TEST_DATA, 1), 1), Ok(()));
+
+        assert_eq!(verify8_detailed(corrupt, koopman8(TEST_DATA, 1), 1), Err(koopman8(corrupt, 1)));
+        assert_eq!(verify16_detailed(corrupt, koopman16(TEST_DATA, 1), 1), Err(koopman16(corrupt, 1)));
+        assert_eq!(verify32_detailed(corrupt, koopman32(TEST_DATA, 1), 1), Err(koopman32(corrupt, 1)));
+        assert_eq!(verify8p_detailed(corrupt, koopman8p(TEST_DATA, 1), 1), Err(koopman8p(corrupt, 1)));
+        assert_eq!(verify16p_detailed(corrupt, koopman16p(TEST_DATA, 1), 1), Err(koopman16p(corrupt, 1)));
+        assert_eq!(verify32p_detailed(corrupt, koopman32p(TEST_DATA, 1), 1), Err(koopman32p(corrupt, 1)));
+    }
+
+    // ========================================================================
+    // Tests for non-empty verification
+    // ========================================================================
+
     #[test]
-    fn test_streaming_with_seed() {
-        let data = b"test data";
-        let seed = 42u8;
+    fn test_verify16_nonempty() {
+        for seed in [0x00, 0x01, 0xee] {
+            for expected in [0, 1, 0xffff, koopman16(&[], seed)] {
+                assert!(!verify16_nonempty(&[], expected, seed));
+            }
 
-        // One-shot with seed
-        let expected = koopman16(data, seed);
+            let checksum = koopman16(TEST_DATA, seed);
+            assert!(verify16_nonempty(TEST_DATA, checksum, seed));
+            assert!(!verify16_nonempty(TEST_DATA, checksum ^ 1, seed));
+        }
 
-        // Streaming with seed
-        let mut hasher = Koopman16::with_seed(seed);
-        hasher.update(data);
-        let streaming = hasher.finalize();
+        // Non-empty data that happens to checksum to zero still verifies
+        assert_eq!(koopman16(&[0, 0], 0), 0);
+        assert!(verify16_nonempty(&[0, 0], 0, 0));
+    }
 
-        assert_eq!(expected, streaming);
+    #[test]
+    fn test_verify16_min_len() {
+        let min_len = TEST_DATA.len();
+        for seed in [0x00, 0x01, 0xee] {
+            // Below the minimum: rejected even with the right checksum
+            let short = &TEST_DATA[..min_len - 1];
+            assert!(!verify16_min_len(short, koopman16(short, seed), seed, min_len));
+            assert!(!verify16_min_len(&[], 0, seed, min_len));
+
+            // At and above the minimum: an ordinary verify
+            let checksum = koopman16(TEST_DATA, seed);
+            assert!(verify16_min_len(TEST_DATA, checksum, seed, min_len));
+            assert!(!verify16_min_len(TEST_DATA, checksum ^ 1, seed, min_len));
+            assert!(verify16_min_len(TEST_DATA, checksum, seed, min_len - 1));
+        }
+
+        assert!(verify16_min_len(&[], 0, 0xee, 0));
+        assert!(!verify16_min_len(&[], 0, 0xee, 1));
     }
 
+    // ========================================================================
+    // Tests for padded verification
+    // ========================================================================
+
     #[test]
-    fn test_streaming_with_seed_chunked() {
-        let data = b"test data for chunked processing";
-        let seed = 123u8;
+    fn test_verify16_padded() {
+        let payload = b"block payload";
+        let checksum = koopman16(payload, 0xee);
+
+        // Exact frame
+        assert!(verify16_padded(payload, checksum, 0xee));
+
+        // Zero-padded up to a block size, including the maximum trim
+        let mut frame = payload.to_vec();
+        frame.resize(32, 0);
+        assert!(verify16_padded(&frame, checksum, 0xee));
+        frame.resize(payload.len() + KOOPMAN16_MAX_PADDING_TRIM, 0);
+        assert!(verify16_padded(&frame, checksum, 0xee));
+
+        // Padding beyond the limit is not trimmed
+        frame.push(0);
+        assert!(!verify16_padded(&frame, checksum, 0xee));
+
+        // Genuinely corrupt payload, with and without padding
+        let mut corrupt = payload.to_vec();
+        corrupt[3] ^= 0x10;
+        assert!(!verify16_padded(&corrupt, checksum, 0xee));
+        corrupt.resize(32, 0);
+        assert!(!verify16_padded(&corrupt, checksum, 0xee));
+    }
 
-        let expected = koopman16(data, seed);
+    // ========================================================================
+    // Tests for optional checksums
+    // ========================================================================
 
-        let mut hasher = Koopman16::with_seed(seed);
-        hasher.update(&data[..10]);
-        hasher.update(&data[10..20]);
-        hasher.update(&data[20..]);
-        let streaming = hasher.finalize();
+    #[test]
+    fn test_verify16_optional_outcomes() {
+        let checksum = koopman16(TEST_DATA, 0xee);
 
-        assert_eq!(expected, streaming);
+        assert_eq!(verify16_optional(TEST_DATA, checksum, 0xee, 0xFFFF), VerifyResult::Match);
+        assert_eq!(verify16_optional(TEST_DATA, checksum ^ 1, 0xee, 0xFFFF), VerifyResult::Mismatch);
+        assert_eq!(verify16_optional(TEST_DATA, 0xFFFF, 0xee, 0xFFFF), VerifyResult::Skipped);
+
+        // A sentinel equal to the real checksum shadows it
+        assert_eq!(verify16_optional(TEST_DATA, checksum, 0xee, checksum), VerifyResult::Skipped);
     }
 
     // ========================================================================
-    // Tests for reset behavior
+    // Tests for incremental verification
     // ========================================================================
 
     #[test]
-    fn test_reset_without_seed() {
-        let data = b"test";
+    fn test_stream_verifier16_rejects_corrupt_block() {
+        let blocks: [&[u8]; 4] = [b"alpha", b"bravo", b"", b"charlie"];
+        let mut verifier = StreamVerifier16::new(0xee);
+        for block in blocks {
+            assert_eq!(verifier.feed_block(block, koopman16(block, 0xee)), Ok(()));
+        }
 
-        let mut hasher = Koopman16::new();
-        hasher.update(data);
-        let first = hasher.finalize();
+        let mut corrupt = *b"delta";
+        let expected = koopman16(&corrupt, 0xee);
+        corrupt[2] ^= 0x04;
+        assert_eq!(
+            verifier.feed_block(&corrupt, expected),
+            Err(KoopmanError::ChecksumMismatch {
+                expected: expected as u32,
+                actual: koopman16(&corrupt, 0xee) as u32,
+            })
+        );
 
-        let mut hasher = Koopman16::new();
-        hasher.update(b"other data");
-        hasher.reset();
-        hasher.update(data);
-        let after_reset = hasher.finalize();
+        // The rejected block is not part of the stream checksum
+        assert_eq!(verifier.finalize_stream(), koopman16(&blocks.concat(), 0xee));
+    }
 
-        assert_eq!(first, after_reset);
+    // ========================================================================
+    // Tests for positioned checksums
+    // ========================================================================
+
+    #[test]
+    fn test_verify16_at_all_positions() {
+        let payload = b"positioned payload";
+        let checksum = koopman16(payload, 0xee).to_be_bytes();
+
+        let prefixed = [&checksum[..], payload].concat();
+        assert!(verify16_at(&prefixed, ChecksumPos::Prefix, 0xee));
+        assert!(!verify16_at(&prefixed, ChecksumPos::Suffix, 0xee));
+
+        let suffixed = [&payload[..], &checksum].concat();
+        assert!(verify16_at(&suffixed, ChecksumPos::Suffix, 0xee));
+        assert!(!verify16_at(&suffixed, ChecksumPos::Prefix, 0xee));
+
+        for offset in [0, 1, 5, payload.len()] {
+            let mut frame = [&payload[..offset], &[0, 0], &payload[offset..]].concat();
+            let embedded = koopman16(&frame, 0xee).to_be_bytes();
+            frame[offset..offset + 2].copy_from_slice(&embedded);
+            assert!(verify16_at(&frame, ChecksumPos::At(offset), 0xee), "offset = {offset}");
+
+            let flipped = frame.len() - 1 - offset;
+            frame[flipped] ^= 0x01;
+            assert!(!verify16_at(&frame, ChecksumPos::At(offset), 0xee), "offset = {offset}");
+        }
     }
 
     #[test]
-    fn test_reset_preserves_seed() {
-        let data = b"test";
-        let seed = 42u8;
+    fn test_verify16_at_short_frames() {
+        for pos in [ChecksumPos::Prefix, ChecksumPos::Suffix, ChecksumPos::At(0)] {
+            assert!(!verify16_at(&[], pos, 0xee));
+            assert!(!verify16_at(&[0x12], pos, 0xee));
+        }
+        assert!(!verify16_at(&[0; 4], ChecksumPos::At(3), 0xee));
+        assert!(!verify16_at(&[0; 4], ChecksumPos::At(usize::MAX), 0xee));
 
-        // First computation with seed
-        let mut hasher = Koopman16::with_seed(seed);
-        hasher.update(data);
-        let first = hasher.finalize();
+        // A bare checksum over empty data: the empty checksum is 0
+        assert!(verify16_at(&[0, 0], ChecksumPos::Prefix, 0xee));
+        assert!(verify16_at(&[0, 0], ChecksumPos::Suffix, 0xee));
+    }
 
-        // Computation after reset should produce same result
-        let mut hasher = Koopman16::with_seed(seed);
-        hasher.update(b"garbage data");
-        hasher.reset();
-        hasher.update(data);
-        let after_reset = hasher.finalize();
+    // ========================================================================
+    // Tests for batch verification
+    // ========================================================================
 
-        assert_eq!(first, after_reset);
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_verify16_batch_mixed() {
+        let records: [&[u8]; 4] = [b"alpha", b"bravo", b"charlie", b"delta"];
+        let seed = 0xee;
+        let mut items: Vec<(&[u8], u16)> =
+            records.iter().map(|&r| (r, koopman16(r, seed))).collect();
+
+        // Corrupt the checksums of records 1 and 3
+        items[1].1 ^= 0x0100;
+        items[3].1 = items[3].1.wrapping_add(1);
+
+        assert_eq!(verify16_batch(&items, seed), vec![true, false, true, false]);
+        assert!(!verify16_batch_all(&items, seed));
+
+        items[1].1 ^= 0x0100;
+        items[3].1 = items[3].1.wrapping_sub(1);
+        assert_eq!(verify16_batch(&items, seed), vec![true; 4]);
+        assert!(verify16_batch_all(&items, seed));
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
-    fn test_reset_all_variants() {
-        let data = b"test";
+    fn test_verify16_batch_empty() {
+        assert!(verify16_batch(&[], 0).is_empty());
+        assert!(verify16_batch_all(&[], 0));
+    }
 
-        // Koopman8
-        let mut h8 = Koopman8::with_seed(10);
-        h8.update(b"junk");
-        h8.reset();
-        h8.update(data);
-        assert_eq!(h8.finalize(), koopman8(data, 10));
+    // ========================================================================
+    // Tests for the size-optimized build
+    // ========================================================================
 
-        // Koopman16
-        let mut h16 = Koopman16::with_seed(20);
-        h16.update(b"junk");
-        h16.reset();
-        h16.update(data);
-        assert_eq!(h16.finalize(), koopman16(data, 20));
+    #[cfg(feature = "small")]
+    #[test]
+    fn test_small_feature_results_unchanged() {
+        // Known answers from the default (fast reduction) build
+        assert_eq!(koopman8(TEST_DATA, 0xee), 28);
+        assert_eq!(koopman16(TEST_DATA, 0xee), 45600);
+        assert_eq!(koopman32(TEST_DATA, 0xee), 4128546630);
+        assert_eq!(koopman8p(TEST_DATA, 0xee), 163);
+        assert_eq!(koopman16p(TEST_DATA, 0xee), 37459);
+        assert_eq!(koopman32p(TEST_DATA, 0xee), 1803512457);
+
+        let mut h16 = Koopman16::with_seed(0xee);
+        h16.update(&TEST_DATA[..4]);
+        h16.update(&TEST_DATA[4..]);
+        assert_eq!(h16.finalize(), 45600);
+
+        let mut h32 = Koopman32::with_seed(0xee);
+        h32.update(&TEST_DATA[..4]);
+        h32.update(&TEST_DATA[4..]);
+        assert_eq!(h32.finalize(), 4128546630);
+    }
 
-        // Koopman32
-        let mut h32 = Koopman32::with_seed(30);
-        h32.update(b"junk");
-        h32.reset();
-        h32.update(data);
-        assert_eq!(h32.finalize(), koopman32(data, 30));
+    #[test]
+    fn test_default_seeded() {
+        let mut hasher = Koopman16::default_seeded();
+        hasher.update(TEST_DATA);
+        assert_eq!(hasher.finalize(), koopman16(TEST_DATA, DEFAULT_SEED));
+
+        // Default and new keep seed 0
+        let mut hasher = Koopman16::default();
+        hasher.update(TEST_DATA);
+        assert_eq!(hasher.finalize(), koopman16(TEST_DATA, 0));
     }
 
     // ========================================================================
-    // Tests for two-bit error detection
+    // Tests for folding checksums
     // ========================================================================
 
     #[test]
-    fn test_two_bit_error_detection() {
-        // Test that most two-bit errors are detected
-        // Note: HD=3 means we detect ALL 1-bit and 2-bit errors
-        let data = b"Test";
-        let original = koopman16(data, 0);
-        let mut detected = 0;
-        let mut total = 0;
-
-        for i in 0..data.len() {
-            for j in i..data.len() {
-                for bit_i in 0..8 {
-                    for bit_j in 0..8 {
-                        if i == j && bit_i == bit_j {
-                            continue; // Skip single-bit errors
-                        }
-                        total += 1;
-                        let mut corrupted = data.to_vec();
-                        corrupted[i] ^= 1 << bit_i;
-                        corrupted[j] ^= 1 << bit_j;
-                        if koopman16(&corrupted, 0) != original {
-                            detected += 1;
-                        }
-                    }
-                }
-            }
+    fn test_update_checksum_matches_be_bytes() {
+        for x in [0u16, 1, 0x00ff, 0xff00, 0x1234, MODULUS_16 as u16 - 1, u16::MAX] {
+            let mut a = Koopman16::with_seed(3);
+            a.update(b"prefix");
+            let mut b = a.clone();
+
+            a.update_checksum(x);
+            b.update(&x.to_be_bytes());
+            assert_eq!(a.finalize(), b.finalize(), "x = {:#06x}", x);
         }
 
-        // Should detect all two-bit errors for data within HD=3 length
-        assert_eq!(detected, total, "Should detect all two-bit errors");
+        // Also as the very first bytes folded
+        let mut a = Koopman16::with_seed(3);
+        a.update_checksum(0xbeef);
+        assert_eq!(a.finalize(), koopman16(&[0xbe, 0xef], 3));
     }
 
     // ========================================================================
-    // Tests for streaming API edge cases
+    // Tests for uninitialized buffers
     // ========================================================================
 
+    #[cfg(feature = "uninit")]
     #[test]
-    fn test_streaming_empty_updates() {
-        let data = b"test";
+    fn test_update_init_matches_safe_path() {
+        use core::mem::MaybeUninit;
 
-        let mut hasher = Koopman16::new();
-        hasher.update(&[]);  // Empty update
-        hasher.update(data);
-        hasher.update(&[]);  // Another empty update
+        let mut buf = [MaybeUninit::<u8>::uninit(); 64];
+        for (slot, &byte) in buf.iter_mut().zip(TEST_DATA) {
+            slot.write(byte);
+        }
 
-        assert_eq!(hasher.finalize(), koopman16(data, 0));
+        let mut hasher = Koopman16::with_seed(0xee);
+        // SAFETY: the first TEST_DATA.len() bytes were written above
+        unsafe { hasher.update_init(&buf, 4) };
+        unsafe { hasher.update_init(&buf[4..], TEST_DATA.len() - 4) };
+        assert_eq!(hasher.finalize(), koopman16(TEST_DATA, 0xee));
     }
 
+    #[cfg(feature = "uninit")]
     #[test]
-    fn test_streaming_byte_by_byte() {
-        let data = b"test data";
-
+    #[should_panic]
+    fn test_update_init_filled_out_of_bounds() {
+        let buf = [core::mem::MaybeUninit::<u8>::uninit(); 4];
         let mut hasher = Koopman16::new();
-        for &byte in data {
-            hasher.update(&[byte]);
-        }
-
-        assert_eq!(hasher.finalize(), koopman16(data, 0));
+        // SAFETY: panics on the bounds check before reading any byte
+        unsafe { hasher.update_init(&buf, 5) };
     }
 
+    // ========================================================================
+    // Tests for hybrid parity mode
+    // ========================================================================
+
     #[test]
-    fn test_finalize_without_data() {
-        let hasher = Koopman16::new();
-        assert_eq!(hasher.finalize(), 0);
+    fn test_koopman16_with_parity_modes() {
+        for data in [&b""[..], b"x", TEST_DATA, &[0xA5; 2044]] {
+            let mut plain = Koopman16::with_parity(false);
+            let mut packed = Koopman16::with_parity(true);
+            assert!(!plain.is_parity() && packed.is_parity());
+            for chunk in data.chunks(7) {
+                plain.update(chunk);
+                packed.update(chunk);
+            }
+            assert_eq!(plain.finalize(), koopman16(data, 0));
+            assert_eq!(packed.clone().finalize(), koopman16p(data, 0));
 
-        let hasher_with_seed = Koopman16::with_seed(42);
-        assert_eq!(hasher_with_seed.finalize(), 0);
+            // Reset keeps parity mode
+            packed.reset();
+            packed.update(data);
+            assert_eq!(packed.finalize(), koopman16p(data, 0));
+        }
     }
 
     #[test]
-    fn test_streaming_parity_koopman8p() {
-        let data = b"test";
-        let expected = koopman8p(data, 0);
+    fn test_koopman16_hybrid_keeps_seed() {
+        for parity in [false, true] {
+            let expected = if parity {
+                koopman16p(TEST_DATA, 0xee)
+            } else {
+                koopman16(TEST_DATA, 0xee)
+            };
+            let mut hasher = Koopman16Hybrid::with_seed(0xee, parity);
+            hasher.update(b"discarded");
+            hasher.reset();
+            hasher.update(TEST_DATA);
+            assert_eq!(hasher.finalize(), expected);
+        }
+    }
 
-        let mut hasher = Koopman8P::new();
-        hasher.update(&data[..2]);
-        hasher.update(&data[2..]);
-        let streaming = hasher.finalize();
+    // ========================================================================
+    // Tests for reduction profiling
+    // ========================================================================
 
-        assert_eq!(expected, streaming);
+    #[cfg(feature = "profiling")]
+    #[test]
+    fn test_update_instrumented_counts_reductions() {
+        let data = [0x5au8; 1000];
+
+        // Generic path: one reduction per byte, none for the first byte
+        let mut hasher = Koopman16::with_modulus(NonZeroU32::new(65521).unwrap());
+        assert_eq!(hasher.update_instrumented(&[]), 0);
+        assert_eq!(hasher.update_instrumented(&data), 999);
+        assert_eq!(hasher.update_instrumented(&data[..3]), 3);
+
+        // Fast path on the 999 bytes after the first
+        let simd = cfg!(all(feature = "simd", target_arch = "x86_64"));
+        let expected = if cfg!(feature = "small") {
+            Some(999)
+        } else if simd {
+            // 15 SIMD chunks, 4 eight-byte folds, 7 bytes
+            Some(15 + 4 + 7)
+        } else if cfg!(feature = "simd") {
+            // Depends on runtime SIMD support
+            None
+        } else {
+            // 124 eight-byte folds, 7 bytes
+            Some(124 + 7)
+        };
+        let mut hasher = Koopman16::with_seed(0xee);
+        let reductions = hasher.update_instrumented(&data);
+        if let Some(expected) = expected {
+            assert_eq!(reductions, expected);
+        }
+        // Two bytes after the first go straight to the byte loop
+        assert_eq!(hasher.update_instrumented(&data[..2]), 2);
+        assert_eq!(hasher.finalize(), koopman16(&[&data[..], &data[..2]].concat(), 0xee));
     }
 
+    // ========================================================================
+    // Tests for implicit zero folding
+    // ========================================================================
+
     #[test]
-    fn test_streaming_parity_koopman16p() {
-        let data = b"test data";
-        let expected = koopman16p(data, 0);
+    fn test_append_zeros_matches_finalize() {
+        for modulus in [None, Some(NonZeroU32::new(65521).unwrap())] {
+            let mut hasher = match modulus {
+                Some(m) => Koopman16::with_modulus(m),
+                None => Koopman16::with_seed(0xee),
+            };
+            hasher.update(TEST_DATA);
+            let expected = hasher.clone().finalize();
+
+            hasher.append_zeros(2);
+            assert_eq!(hasher.running_sum(), expected as u32);
+            assert_eq!(hasher.bytes_processed(), TEST_DATA.len() as u64);
+        }
 
-        let mut hasher = Koopman16P::new();
-        hasher.update(&data[..4]);
-        hasher.update(&data[4..]);
-        let streaming = hasher.finalize();
+        // Custom modulus, one step at a time against the closed form
+        let modulus = NonZeroU32::new(251).unwrap();
+        let mut stepped = Koopman16::with_modulus(modulus);
+        stepped.update(TEST_DATA);
+        let mut folded = stepped.clone();
+        for _ in 0..300 {
+            stepped.append_zeros(1);
+        }
+        folded.append_zeros(300);
+        assert_eq!(folded.running_sum(), stepped.running_sum());
 
-        assert_eq!(expected, streaming);
+        // No data folded yet: nothing to append to
+        let mut empty = Koopman16::with_seed(0xee);
+        empty.append_zeros(2);
+        assert_eq!(empty.finalize(), 0);
     }
 
-    #[test]
-    fn test_streaming_parity_koopman32p() {
-        let data = b"test data for streaming";
-        let expected = koopman32p(data, 0);
+    // ========================================================================
+    // Tests for 32-bit targets
+    // ========================================================================
 
-        let mut hasher = Koopman32P::new();
-        hasher.update(&data[..10]);
-        hasher.update(&data[10..]);
-        let streaming = hasher.finalize();
+    #[cfg(target_pointer_width = "32")]
+    #[test]
+    fn test_streaming_length_past_usize_on_32bit() {
+        // Mock a stream that has already folded almost usize::MAX bytes, then
+        // push it past 2^32 with a chunked source. The running sum does not
+        // depend on the length, so a resumed short stream is the reference.
+        let data: Vec<u8> = (0..4096u32).map(|i| (i * 131 + 7) as u8).collect();
+        let start = usize::MAX - 100;
+        let mut hasher = Koopman16::from_raw(0x1234, start);
+        let mut reference = Koopman16::from_raw(0x1234, 1);
+        for chunk in data.chunks(1000) {
+            hasher.update(chunk);
+            reference.update(chunk);
+        }
 
-        assert_eq!(expected, streaming);
+        assert_eq!(hasher.bytes_processed(), start as u64 + 4096);
+        assert!(hasher.bytes_processed() > u32::MAX as u64);
+        assert_eq!(hasher.clone().finalize(), reference.finalize());
+        assert_eq!(
+            hasher.try_update(b"x"),
+            Err(KoopmanError::DataTooLong { len: usize::MAX, max: KOOPMAN16_MAX_LEN })
+        );
     }
 
+    // ========================================================================
+    // Tests for state tokens
+    // ========================================================================
+
     #[test]
-    fn test_streaming_parity_with_seed() {
-        let data = b"test";
-        let seed = 42u8;
+    fn test_state_token_round_trip() {
+        let data = b"test data for a distributed checksum";
+        let seed = 0xee;
 
-        let expected = koopman16p(data, seed);
+        for mid in 0..=data.len() {
+            let mut hasher = Koopman16::with_seed(seed);
+            hasher.update(&data[..mid]);
+            let bytes = hasher.into_state().to_bytes();
 
-        let mut hasher = Koopman16P::with_seed(seed);
-        hasher.update(data);
-        let streaming = hasher.finalize();
+            let state = KoopmanState16::from_bytes(&bytes).unwrap();
+            let mut resumed = Koopman16::from_state(state);
+            resumed.update(&data[mid..]);
 
-        assert_eq!(expected, streaming);
+            assert_eq!(resumed.finalize(), koopman16(data, seed), "mid = {}", mid);
+        }
     }
 
-    // ========================================================================
-    // Tests for parity verification
-    // ========================================================================
+    #[test]
+    fn test_state_token_keeps_seed_across_reset() {
+        let mut hasher = Koopman16::with_seed(0xee);
+        hasher.update(b"first message");
+        let bytes = hasher.into_state().to_bytes();
+
+        let mut resumed = Koopman16::from_state(KoopmanState16::from_bytes(&bytes).unwrap());
+        resumed.reset();
+        resumed.update(TEST_DATA);
+        assert_eq!(resumed.finalize(), koopman16(TEST_DATA, 0xee));
+    }
 
     #[test]
-    fn test_verify_parity() {
-        let data = b"test data";
+    fn test_state_token_keeps_modulus() {
+        let modulus = NonZeroU32::new(65521).unwrap();
+        let mut hasher = Koopman16::with_modulus(modulus);
+        hasher.update(&TEST_DATA[..4]);
+        let bytes = hasher.into_state().to_bytes();
 
-        let cs8p = koopman8p(data, 0);
-        assert!(verify8p(data, cs8p, 0));
-        assert!(!verify8p(data, cs8p.wrapping_add(1), 0));
+        let mut resumed = Koopman16::from_state(KoopmanState16::from_bytes(&bytes).unwrap());
+        assert_eq!(resumed.modulus(), 65521);
+        resumed.update(&TEST_DATA[4..]);
+        assert_eq!(resumed.finalize(), koopman16_with_modulus(TEST_DATA, 0, modulus));
+    }
 
-        let cs16p = koopman16p(data, 0);
-        assert!(verify16p(data, cs16p, 0));
-        assert!(!verify16p(data, cs16p.wrapping_add(1), 0));
+    #[test]
+    fn test_state_token_rejects_invalid() {
+        let mut bytes = Koopman16::new().into_state().to_bytes();
+        bytes[12] = 2;
+        assert_eq!(KoopmanState16::from_bytes(&bytes), None);
 
-        let cs32p = koopman32p(data, 0);
-        assert!(verify32p(data, cs32p, 0));
-        assert!(!verify32p(data, cs32p.wrapping_add(1), 0));
+        let mut hasher = Koopman16::new();
+        hasher.update(b"x");
+        let mut bytes = hasher.into_state().to_bytes();
+        bytes[0..4].copy_from_slice(&MODULUS_16.to_be_bytes());
+        assert_eq!(KoopmanState16::from_bytes(&bytes), None);
+
+        let mut bytes = Koopman16::new().into_state().to_bytes();
+        bytes[14..18].fill(0);
+        assert_eq!(KoopmanState16::from_bytes(&bytes), None);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_from_raw_resumes_split_computation() {
+        let data: Vec<u8> = (0..500u32).map(|i| (i * 29 + 3) as u8).collect();
+
+        for split in [0, 1, 2, 250, 499, 500] {
+            let mut first = Koopman16::with_seed(0xee);
+            first.update(&data[..split]);
+            let sum = first.running_sum() as u16;
+
+            let mut resumed = Koopman16::from_raw(sum, split);
+            resumed.update(&data[split..]);
+            assert_eq!(resumed.bytes_processed(), data.len() as u64);
+            assert_eq!(resumed.finalize(), koopman16(&data, 0xee), "split = {}", split);
+        }
     }
 
     // ========================================================================
-    // Tests for streaming with custom modulus
+    // Tests for generic input API
     // ========================================================================
 
+    #[cfg(feature = "alloc")]
     #[test]
-    fn test_streaming_with_custom_modulus() {
-        let data = b"test data";
+    fn test_generic_input_vec() {
+        let data: Vec<u8> = TEST_DATA.to_vec();
+
+        assert_eq!(koopman8_of(&data, 7), koopman8(TEST_DATA, 7));
+        assert_eq!(koopman16_of(&data, 7), koopman16(TEST_DATA, 7));
+        assert_eq!(koopman32_of(&data, 7), koopman32(TEST_DATA, 7));
+        assert_eq!(koopman8p_of(&data, 7), koopman8p(TEST_DATA, 7));
+        assert_eq!(koopman16p_of(&data, 7), koopman16p(TEST_DATA, 7));
+        assert_eq!(koopman32p_of(data, 7), koopman32p(TEST_DATA, 7));
+    }
 
-        // Test that streaming with default modulus matches one-shot
-        let mut hasher = Koopman16::with_modulus(NONZERO_MODULUS_16);
-        hasher.update(data);
-        assert_eq!(hasher.finalize(), koopman16(data, 0));
+    #[test]
+    fn test_generic_input_array() {
+        let data: [u8; 9] = *b"123456789";
 
-        // Test with a different modulus
-        let alt_modulus = NonZeroU32::new(32749).unwrap();
-        let mut hasher = Koopman16::with_modulus(alt_modulus);
-        hasher.update(data);
-        let streaming = hasher.finalize();
+        assert_eq!(koopman8_of(data, 0), koopman8(TEST_DATA, 0));
+        assert_eq!(koopman16_of(data, 0), koopman16(TEST_DATA, 0));
+        assert_eq!(koopman32_of(data, 0), koopman32(TEST_DATA, 0));
+        assert_eq!(koopman16p_of(data, 0), koopman16p(TEST_DATA, 0));
+    }
 
-        // Should produce a valid result (just verify it's deterministic)
-        let mut hasher2 = Koopman16::with_modulus(alt_modulus);
-        hasher2.update(data);
-        assert_eq!(streaming, hasher2.finalize());
+    #[test]
+    fn test_generic_input_str() {
+        let text = "123456789";
+
+        assert_eq!(koopman16_of(text, 1), koopman16(TEST_DATA, 1));
+        assert_eq!(koopman32_of(text.as_bytes(), 1), koopman32(TEST_DATA, 1));
+        assert_eq!(koopman8p_of(text, 1), koopman8p(TEST_DATA, 1));
     }
 }
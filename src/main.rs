@@ -2,14 +2,13 @@ use koopman_checksum::{koopman16, koopman8};
 
 // counterexamples from TethysSvensson
 fn main() {
-    // These two messages are 4095 bytes and have a hamming distance of 2
-    // The crate claims to be able to detect hamming distances of up to 3
-    // for messages up to 4096 bytes using this checksum
+    // These two messages have a hamming distance of 2. They collide at
+    // 4095 bytes, but are still distinguished at the documented 4092 byte limit
     let mut a = [0; 4092];
     a[0] = 0x80;
     let mut b = [0; 4092];
     b[4091] = 1;
-    // assert_eq!(koopman16(&a, 0), koopman16(&b, 0));
+    assert_ne!(koopman16(&a, 0), koopman16(&b, 0));
 
     // These two messages are 2 bytes and have a hamming distance of 3
     // The crate claims to be able to detect hamming distances of up to 3
@@ -21,5 +20,4 @@ fn main() {
             println!("failure with seed {i:b}");
         }
     }
-
 }
@@ -0,0 +1,177 @@
+//! Recycling of streaming hashers for loops that create and drop many of them.
+//!
+//! A [`Koopman16Pool`] hands out [`PooledKoopman16`] guards. Dropping or
+//! finalizing a guard returns its hasher to the pool, and the next
+//! [`acquire`](Koopman16Pool::acquire) resets and reuses it.
+
+// Copyright (c) 2025 the koopman-checksum authors, all rights reserved.
+// See README.md for licensing information.
+
+use std::cell::{RefCell, RefMut};
+use std::ops::{Deref, DerefMut};
+
+use crate::Koopman16;
+
+/// Pool of [`Koopman16`] hashers sharing one configuration.
+///
+/// Every hasher handed out is configured like the template given to
+/// [`new`](Self::new) (seed and modulus) and starts empty. The pool holds on
+/// to as many hashers as were ever in use at once.
+///
+/// The pool is not `Sync`; give each thread its own.
+///
+/// # Example
+/// ```rust
+/// use koopman_checksum::koopman16;
+/// use koopman_checksum::pool::Koopman16Pool;
+/// use koopman_checksum::Koopman16;
+///
+/// let pool = Koopman16Pool::new(Koopman16::with_seed(0xee));
+/// for msg in [&b"first"[..], b"second", b"third"] {
+///     let mut hasher = pool.acquire();
+///     hasher.update(msg);
+///     assert_eq!(hasher.finalize(), koopman16(msg, 0xee));
+/// }
+/// assert_eq!(pool.idle(), 1);
+/// ```
+#[derive(Debug)]
+pub struct Koopman16Pool {
+    template: Koopman16,
+    free: RefCell<Vec<Koopman16>>,
+}
+
+impl Koopman16Pool {
+    /// Create an empty pool whose hashers are configured like `template`.
+    ///
+    /// Any data already folded into `template` is discarded.
+    pub fn new(mut template: Koopman16) -> Self {
+        template.reset();
+        Self {
+            template,
+            free: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Take an empty hasher from the pool, or build one if none is idle.
+    pub fn acquire(&self) -> PooledKoopman16<'_> {
+        let hasher = match self.free_list().pop() {
+            Some(mut hasher) => {
+                hasher.reset();
+                hasher
+            }
+            None => self.template.clone(),
+        };
+        PooledKoopman16 {
+            hasher: Some(hasher),
+            pool: self,
+        }
+    }
+
+    /// Number of hashers waiting to be reused.
+    #[must_use]
+    pub fn idle(&self) -> usize {
+        self.free_list().len()
+    }
+
+    // Borrows never outlive a single push, pop, or len, so they cannot overlap
+    fn free_list(&self) -> RefMut<'_, Vec<Koopman16>> {
+        self.free.borrow_mut()
+    }
+}
+
+impl Default for Koopman16Pool {
+    fn default() -> Self {
+        Self::new(Koopman16::new())
+    }
+}
+
+/// A [`Koopman16`] on loan from a [`Koopman16Pool`].
+///
+/// Dereferences to the hasher. The hasher goes back to the pool when the
+/// guard is dropped or [`finalize`](Self::finalize)d.
+#[derive(Debug)]
+pub struct PooledKoopman16<'a> {
+    // Only `None` while being returned in `drop`
+    hasher: Option<Koopman16>,
+    pool: &'a Koopman16Pool,
+}
+
+impl PooledKoopman16<'_> {
+    /// Finalize and return the checksum, returning the hasher to the pool.
+    #[must_use]
+    pub fn finalize(self) -> u16 {
+        self.peek()
+    }
+}
+
+impl Deref for PooledKoopman16<'_> {
+    type Target = Koopman16;
+
+    fn deref(&self) -> &Koopman16 {
+        self.hasher.as_ref().expect("hasher present until drop")
+    }
+}
+
+impl DerefMut for PooledKoopman16<'_> {
+    fn deref_mut(&mut self) -> &mut Koopman16 {
+        self.hasher.as_mut().expect("hasher present until drop")
+    }
+}
+
+impl Drop for PooledKoopman16<'_> {
+    fn drop(&mut self) {
+        if let Some(hasher) = self.hasher.take() {
+            self.pool.free_list().push(hasher);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::koopman16;
+
+    #[test]
+    fn test_recycled_hashers_are_reset() {
+        let pool = Koopman16Pool::new(Koopman16::with_seed(0xee));
+
+        // Abandon a hasher mid-stream; its state must not leak into the next one
+        let mut hasher = pool.acquire();
+        hasher.update(b"left over");
+        drop(hasher);
+        assert_eq!(pool.idle(), 1);
+
+        let mut hasher = pool.acquire();
+        assert_eq!(pool.idle(), 0);
+        assert_eq!(hasher.bytes_processed(), 0);
+        hasher.update(b"test data");
+        assert_eq!(hasher.finalize(), koopman16(b"test data", 0xee));
+
+        let hasher = pool.acquire();
+        assert_eq!(hasher.finalize(), 0);
+        assert_eq!(pool.idle(), 1);
+    }
+
+    #[test]
+    fn test_concurrent_loans_get_distinct_hashers() {
+        let pool = Koopman16Pool::default();
+        let mut a = pool.acquire();
+        let mut b = pool.acquire();
+        a.update(b"first");
+        b.update(b"second");
+        assert_eq!(a.finalize(), koopman16(b"first", 0));
+        assert_eq!(b.finalize(), koopman16(b"second", 0));
+        assert_eq!(pool.idle(), 2);
+    }
+
+    #[test]
+    fn test_template_data_is_discarded() {
+        let mut template = Koopman16::with_seed(7);
+        template.update(b"ignored");
+        let pool = Koopman16Pool::new(template);
+
+        let mut hasher = pool.acquire();
+        hasher.update(b"data");
+        assert_eq!(hasher.finalize(), koopman16(b"data", 7));
+    }
+}
@@ -0,0 +1,178 @@
+//! SIMD wide fold for the Koopman16 streaming hasher (`simd` feature).
+//!
+//! A 64-byte chunk folded into the running sum is
+//!
+//! ```text
+//! sum' = sum * 256^64 + Σ data[i] * 256^(63 - i)   (mod 65519)
+//! ```
+//!
+//! The weighted byte sum does not depend on `sum`, so it is computed with
+//! vector multiply-adds, 16 bytes per load; only the recombination with the
+//! running sum is serial, once per chunk. On x86 the SSE2 path is used (part
+//! of the x86_64 baseline, detected at runtime on 32-bit x86 with `std`).
+//! Other targets fold nothing here and fall through to the scalar folds.
+
+// Copyright (c) 2025 the koopman-checksum authors, all rights reserved.
+// See README.md for licensing information.
+
+use crate::Reductions;
+
+/// Fold 64-byte chunks into a Koopman16 running sum with SIMD, returning
+/// the new sum and the unprocessed tail. Returns the input unchanged if no
+/// SIMD path is available. `sum` must be below `MODULUS_16`.
+#[inline]
+pub(crate) fn fold64_65519<'a>(
+    sum: u32,
+    data: &'a [u8],
+    reductions: &mut Reductions,
+) -> (u32, &'a [u8]) {
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    if x86::has_sse2() {
+        // SAFETY: SSE2 support was just checked
+        return unsafe { x86::fold64_65519_sse2(sum, data, reductions) };
+    }
+    let _ = reductions;
+    (sum, data)
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod x86 {
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::*;
+
+    use crate::{fast_mod_65519, Reductions, MODULUS_16};
+
+    const CHUNK: usize = 64;
+
+    /// Weight of byte `i` in a chunk, `256^(63 - i) mod 65519`, as the
+    /// representative in `-32759..=32759` so it fits the signed `madd` lanes.
+    const WEIGHTS: [i16; CHUNK] = {
+        let mut weights = [0i16; CHUNK];
+        let mut power = 1u32;
+        let mut i = CHUNK;
+        while i > 0 {
+            i -= 1;
+            weights[i] = if power > MODULUS_16 / 2 {
+                (power as i32 - MODULUS_16 as i32) as i16
+            } else {
+                power as i16
+            };
+            power = (power << 8) % MODULUS_16;
+        }
+        weights
+    };
+
+    /// `256^64 mod 65519`, the running sum's multiplier per chunk.
+    const SHIFT: u32 = {
+        let mut power = 1u32;
+        let mut i = 0;
+        while i < CHUNK {
+            power = (power << 8) % MODULUS_16;
+            i += 1;
+        }
+        power
+    };
+
+    /// Multiple of the modulus added to the signed weighted sum to make it
+    /// non-negative: `|dot| <= 64 * 255 * 32759 = 534_634_560 < 8192 * 65519`.
+    const OFFSET: i64 = 8192 * MODULUS_16 as i64;
+
+    #[inline]
+    pub(super) fn has_sse2() -> bool {
+        #[cfg(feature = "std")]
+        let detected = || std::is_x86_feature_detected!("sse2");
+        #[cfg(not(feature = "std"))]
+        let detected = || false;
+        // Compile-time true on x86_64, where SSE2 is baseline
+        cfg!(target_feature = "sse2") || detected()
+    }
+
+    /// # Safety
+    /// The CPU must support SSE2.
+    #[target_feature(enable = "sse2")]
+    pub(super) unsafe fn fold64_65519_sse2<'a>(
+        mut sum: u32,
+        data: &'a [u8],
+        reductions: &mut Reductions,
+    ) -> (u32, &'a [u8]) {
+        let zero = _mm_setzero_si128();
+        let mut weights = [zero; CHUNK / 8];
+        for (k, w) in weights.iter_mut().enumerate() {
+            *w = _mm_loadu_si128(WEIGHTS.as_ptr().add(8 * k).cast());
+        }
+
+        let mut chunks = data.chunks_exact(CHUNK);
+        for chunk in &mut chunks {
+            // Each i32 lane sums 16 products of at most 255 * 32759
+            let mut acc = zero;
+            for j in 0..CHUNK / 16 {
+                let bytes = _mm_loadu_si128(chunk.as_ptr().add(16 * j).cast());
+                let lo = _mm_unpacklo_epi8(bytes, zero);
+                let hi = _mm_unpackhi_epi8(bytes, zero);
+                acc = _mm_add_epi32(acc, _mm_madd_epi16(lo, weights[2 * j]));
+                acc = _mm_add_epi32(acc, _mm_madd_epi16(hi, weights[2 * j + 1]));
+            }
+            let acc = _mm_add_epi32(acc, _mm_shuffle_epi32(acc, 0b01_00_11_10));
+            let acc = _mm_add_epi32(acc, _mm_shuffle_epi32(acc, 0b10_11_00_01));
+            let dot = _mm_cvtsi128_si32(acc) as i64;
+
+            // sum * SHIFT < 2^32, so x < 2^33; one 2^16 ≡ 17 fold brings it under 2^22
+            let x = sum as u64 * SHIFT as u64 + (dot + OFFSET) as u64;
+            sum = fast_mod_65519(((x >> 16) * 17 + (x & 0xFFFF)) as u32);
+            reductions.add(1);
+        }
+        (sum, chunks.remainder())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{koopman16, koopman16_with_modulus, Koopman16, NONZERO_MODULUS_16};
+
+    fn test_data(len: usize) -> Vec<u8> {
+        (0..len as u32).map(|i| (i * 131 + 7) as u8).collect()
+    }
+
+    #[test]
+    fn test_simd_update_matches_koopman16() {
+        for data in [test_data(256), vec![0xFF; 256]] {
+            for len in 0..=256 {
+                for seed in [0u8, 0xee] {
+                    let expected = koopman16_with_modulus(&data[..len], seed, NONZERO_MODULUS_16);
+                    assert_eq!(koopman16(&data[..len], seed), expected);
+
+                    let mut hasher = Koopman16::with_seed(seed);
+                    hasher.update(&data[..len]);
+                    assert_eq!(hasher.finalize(), expected, "len = {len}");
+
+                    // A leading update moves the chunk boundaries off the data start
+                    let mut hasher = Koopman16::with_seed(seed);
+                    hasher.update(&data[..len.min(3)]);
+                    hasher.update(&data[len.min(3)..len]);
+                    assert_eq!(hasher.finalize(), expected, "len = {len}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_fold64_extreme_sums() {
+        // Largest running sum with all-ones and all-zero chunks
+        for fill in [0x00, 0xFF] {
+            let data = [fill; 100];
+            let (sum, rest) = fold64_65519(65518, &data, &mut Reductions::default());
+            if cfg!(any(target_arch = "x86", target_arch = "x86_64")) {
+                assert_eq!(rest.len(), 36);
+            }
+
+            let mut expected = 65518u64;
+            for &byte in &data[..data.len() - rest.len()] {
+                expected = ((expected << 8) + byte as u64) % 65519;
+            }
+            assert_eq!(sum as u64, expected);
+        }
+    }
+}
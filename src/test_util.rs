@@ -0,0 +1,175 @@
+//! Helpers for exercising error detection in downstream tests.
+//!
+//! Enabled by the `test-util` feature. These mirror the helpers used by this
+//! crate's exhaustive Hamming distance tests.
+
+// Copyright (c) 2025 the koopman-checksum authors, all rights reserved.
+// See README.md for licensing information.
+
+use crate::{koopman16, Koopman16};
+
+/// Flip a single bit in the data at the given bit position.
+///
+/// Bit `n` is bit `n % 8` (LSB first) of byte `n / 8`.
+///
+/// # Panics
+/// Panics if `bit_pos >= data.len() * 8`.
+///
+/// # Example
+/// ```rust
+/// use koopman_checksum::test_util::flip_bit;
+///
+/// let mut data = [0u8; 2];
+/// flip_bit(&mut data, 9);
+/// assert_eq!(data, [0x00, 0x02]);
+/// ```
+#[inline]
+#[track_caller]
+pub fn flip_bit(data: &mut [u8], bit_pos: usize) {
+    let byte_idx = bit_pos / 8;
+    let bit_idx = bit_pos % 8;
+    data[byte_idx] ^= 1 << bit_idx;
+}
+
+/// Iterate over every distinct pair of bit positions in `len` bytes of data.
+///
+/// Yields `(bit1, bit2)` with `bit1 < bit2`, suitable for passing to
+/// [`flip_bit`] to enumerate all 2-bit error patterns.
+///
+/// # Example
+/// ```rust
+/// use koopman_checksum::test_util::all_2bit_patterns;
+///
+/// // 16 bits => C(16, 2) = 120 patterns
+/// assert_eq!(all_2bit_patterns(2).count(), 120);
+/// ```
+pub fn all_2bit_patterns(len: usize) -> impl Iterator<Item = (usize, usize)> {
+    let total_bits = len * 8;
+    (0..total_bits).flat_map(move |bit1| ((bit1 + 1)..total_bits).map(move |bit2| (bit1, bit2)))
+}
+
+/// A [`Koopman16`] that re-checks itself against the one-shot [`koopman16`]
+/// after every update.
+///
+/// Keeps a copy of all data fed so far and asserts that the streaming
+/// hasher's [`peek`](Koopman16::peek) matches a from-scratch computation,
+/// catching internal desync as soon as it happens. Quadratic in the total
+/// input length; for debugging and tests only. Created by
+/// [`Koopman16::with_oracle`].
+///
+/// # Example
+/// ```rust
+/// use koopman_checksum::{koopman16, Koopman16};
+///
+/// let mut hasher = Koopman16::with_oracle();
+/// hasher.update(b"test ");
+/// hasher.update(b"data");
+/// assert_eq!(hasher.finalize(), koopman16(b"test data", 0));
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug)]
+pub struct Oracle16 {
+    hasher: Koopman16,
+    seed: u8,
+    data: alloc::vec::Vec<u8>,
+}
+
+#[cfg(feature = "alloc")]
+impl Oracle16 {
+    /// Create an oracle-checked hasher with a custom seed.
+    pub fn with_seed(seed: u8) -> Self {
+        Self {
+            hasher: Koopman16::with_seed(seed),
+            seed,
+            data: alloc::vec::Vec::new(),
+        }
+    }
+
+    /// Update the hasher, then check it against the oracle.
+    ///
+    /// # Panics
+    /// Panics if the streaming state no longer matches the one-shot checksum
+    /// of all data fed so far.
+    #[track_caller]
+    pub fn update(&mut self, data: &[u8]) {
+        self.hasher.update(data);
+        self.data.extend_from_slice(data);
+
+        let expected = koopman16(&self.data, self.seed);
+        let actual = self.hasher.peek();
+        assert_eq!(
+            actual,
+            expected,
+            "streaming Koopman16 desynced from oracle after {} bytes",
+            self.data.len()
+        );
+    }
+
+    /// Finalize the wrapped hasher.
+    #[must_use]
+    pub fn finalize(self) -> u16 {
+        self.hasher.finalize()
+    }
+
+    /// Test hook: XOR `mask` into the wrapped hasher's running sum, simulating
+    /// internal state corruption. The next [`update`](Self::update) panics
+    /// unless the corruption happens to cancel out.
+    pub fn corrupt_sum(&mut self, mask: u16) {
+        self.hasher.sum ^= u32::from(mask);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flip_bit_toggles_expected_bit() {
+        for bit in 0..24 {
+            let mut data = [0u8; 3];
+            flip_bit(&mut data, bit);
+            assert_eq!(data[bit / 8], 1 << (bit % 8));
+            assert_eq!(data.iter().map(|b| b.count_ones()).sum::<u32>(), 1);
+
+            flip_bit(&mut data, bit);
+            assert_eq!(data, [0u8; 3]);
+        }
+    }
+
+    #[test]
+    fn test_all_2bit_patterns() {
+        assert_eq!(all_2bit_patterns(0).count(), 0);
+        assert_eq!(all_2bit_patterns(1).count(), 28);
+        assert!(all_2bit_patterns(3).all(|(a, b)| a < b && b < 24));
+
+        // Every pattern is detected within the HD-safe length
+        let data = *b"test";
+        let original = koopman16(&data, 1);
+        for (bit1, bit2) in all_2bit_patterns(data.len()) {
+            let mut corrupted = data;
+            flip_bit(&mut corrupted, bit1);
+            flip_bit(&mut corrupted, bit2);
+            assert_ne!(koopman16(&corrupted, 1), original);
+        }
+    }
+
+    #[test]
+    fn test_oracle16_tracks_one_shot() {
+        let data: Vec<u8> = (0..=255).collect();
+        let mut hasher = Oracle16::with_seed(0xee);
+        for chunk in data.chunks(37) {
+            hasher.update(chunk);
+        }
+        hasher.update(&[]);
+        assert_eq!(hasher.finalize(), koopman16(&data, 0xee));
+    }
+
+    #[test]
+    #[should_panic(expected = "desynced from oracle")]
+    fn test_oracle16_catches_corruption() {
+        let mut hasher = Koopman16::with_oracle();
+        hasher.update(b"test");
+        hasher.corrupt_sum(0x0100);
+        hasher.update(b" data");
+    }
+}
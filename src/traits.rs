@@ -0,0 +1,67 @@
+//! Compile-time checks that public types stay `Send` and `Sync`.
+//!
+//! Hashers are routinely moved between threads. These assertions cost
+//! nothing at runtime; a field that breaks thread safety, such as an `Rc`,
+//! fails the build here instead of in a downstream crate.
+
+// Copyright (c) 2025 the koopman-checksum authors, all rights reserved.
+// See README.md for licensing information.
+
+use crate::algorithm::{Koopman, KoopmanAlgorithm};
+use crate::frame::Frame16;
+use crate::typestate::{Koopman16Builder, Open, Sealed};
+use crate::*;
+
+const _: fn() = || {
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    // Streaming hashers and their wrappers
+    assert_send_sync::<Koopman8>();
+    assert_send_sync::<Koopman16>();
+    assert_send_sync::<Koopman32>();
+    assert_send_sync::<Koopman8P>();
+    assert_send_sync::<Koopman16P>();
+    assert_send_sync::<Koopman32P>();
+    assert_send_sync::<Koopman16Default>();
+    assert_send_sync::<Koopman16Hybrid>();
+    assert_send_sync::<KoopmanState16>();
+    assert_send_sync::<CheckedDigest<Koopman16>>();
+    assert_send_sync::<OverflowWatch16<fn()>>();
+    assert_send_sync::<StreamVerifier16>();
+    assert_send_sync::<ReductionScratch>();
+    assert_send_sync::<Koopman<'static>>();
+    assert_send_sync::<Koopman16Builder<Open>>();
+    assert_send_sync::<Koopman16Builder<Sealed>>();
+
+    // Configuration, results, and errors
+    assert_send_sync::<KoopmanAlgorithm>();
+    assert_send_sync::<Width>();
+    assert_send_sync::<Endian>();
+    assert_send_sync::<Checksum8>();
+    assert_send_sync::<Checksum16>();
+    assert_send_sync::<Checksum32>();
+    assert_send_sync::<Guarantee>();
+    assert_send_sync::<VerifyResult>();
+    assert_send_sync::<ChecksumPos>();
+    assert_send_sync::<ParityCheck>();
+    assert_send_sync::<KoopmanError>();
+    assert_send_sync::<Frame16<'static>>();
+};
+
+#[cfg(feature = "std")]
+const _: fn() = || {
+    fn assert_send<T: Send>() {}
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    assert_send_sync::<io::KoopmanWriter16<Vec<u8>>>();
+    assert_send_sync::<io::KoopmanReader16<&'static [u8]>>();
+    // Per-thread by design: the free list is a `RefCell`
+    assert_send::<pool::Koopman16Pool>();
+};
+
+#[cfg(all(feature = "test-util", feature = "alloc"))]
+const _: fn() = || {
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    assert_send_sync::<test_util::Oracle16>();
+};
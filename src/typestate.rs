@@ -0,0 +1,110 @@
+//! Typestate wrapper that makes finalization a one-way transition.
+//!
+//! [`Koopman16Builder`] starts [`Open`], accepting data. Finalizing consumes
+//! it and yields a [`Sealed`] builder that only exposes the checksum, so
+//! folding more data after finalization is a compile error rather than a
+//! silent mistake:
+//!
+//! ```compile_fail
+//! use koopman_checksum::typestate::Koopman16Builder;
+//!
+//! let sealed = Koopman16Builder::new().finalize();
+//! sealed.update(b"too late"); // no `update` on a sealed builder
+//! ```
+
+// Copyright (c) 2025 the koopman-checksum authors, all rights reserved.
+// See README.md for licensing information.
+
+use crate::Koopman16;
+
+/// Builder state: accepting data.
+#[derive(Clone, Debug)]
+pub struct Open(Koopman16);
+
+/// Builder state: finalized, holding the checksum.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Sealed(u16);
+
+/// A [`Koopman16`] wrapper whose state is tracked in its type.
+///
+/// # Example
+/// ```rust
+/// use koopman_checksum::{koopman16, typestate::Koopman16Builder};
+///
+/// let mut builder = Koopman16Builder::with_seed(0xee);
+/// builder.update(b"Hello, ");
+/// builder.update(b"World!");
+/// let sealed = builder.finalize();
+/// assert_eq!(sealed.value(), koopman16(b"Hello, World!", 0xee));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Koopman16Builder<S = Open> {
+    state: S,
+}
+
+impl Koopman16Builder<Open> {
+    /// Create an open builder with seed 0, like [`Koopman16::new`].
+    #[inline]
+    pub fn new() -> Self {
+        Self::from_hasher(Koopman16::new())
+    }
+
+    /// Create an open builder with the given seed, like [`Koopman16::with_seed`].
+    #[inline]
+    pub fn with_seed(seed: u8) -> Self {
+        Self::from_hasher(Koopman16::with_seed(seed))
+    }
+
+    /// Wrap an existing hasher, keeping its configuration and progress.
+    #[inline]
+    pub fn from_hasher(hasher: Koopman16) -> Self {
+        Self { state: Open(hasher) }
+    }
+
+    /// Fold more data into the checksum.
+    #[inline]
+    pub fn update(&mut self, data: &[u8]) {
+        self.state.0.update(data);
+    }
+
+    /// Finalize, consuming the open builder.
+    #[inline]
+    #[must_use]
+    pub fn finalize(self) -> Koopman16Builder<Sealed> {
+        Koopman16Builder {
+            state: Sealed(self.state.0.finalize()),
+        }
+    }
+}
+
+impl Default for Koopman16Builder<Open> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Koopman16Builder<Sealed> {
+    /// The finalized checksum.
+    #[inline]
+    #[must_use]
+    pub fn value(&self) -> u16 {
+        self.state.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::koopman16;
+
+    #[test]
+    fn test_builder_matches_hasher() {
+        let mut builder = Koopman16Builder::with_seed(0xee);
+        for chunk in b"typestate builder".chunks(3) {
+            builder.update(chunk);
+        }
+        assert_eq!(builder.finalize().value(), koopman16(b"typestate builder", 0xee));
+
+        assert_eq!(Koopman16Builder::new().finalize().value(), 0);
+    }
+}
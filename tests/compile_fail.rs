@@ -0,0 +1,14 @@
+//! Compile-fail tests for attributes and typestate on the public API.
+//!
+//! The expected compiler output lives next to each case in `tests/ui/`.
+//! Regenerate it after an intentional change with:
+//!
+//! ```bash
+//! TRYBUILD=overwrite cargo test --test compile_fail
+//! ```
+
+#[test]
+fn compile_fail() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
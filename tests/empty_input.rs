@@ -0,0 +1,130 @@
+//! Empty-input conventions across the public API.
+//!
+//! Every checksum computation returns 0 for empty input, regardless of seed
+//! or modulus. Streaming hashers that never received data finalize to 0.
+//! These tests pin that convention so no single path can diverge.
+
+use core::num::{NonZeroU32, NonZeroU64};
+use koopman_checksum::*;
+
+const SEEDS: [u8; 4] = [0, 1, 0xee, 0xff];
+
+fn moduli32() -> [NonZeroU32; 3] {
+    [
+        NonZeroU32::new(125).unwrap(),
+        NonZeroU32::new(253).unwrap(),
+        NonZeroU32::new(32749).unwrap(),
+    ]
+}
+
+fn moduli64() -> [NonZeroU64; 2] {
+    [
+        NonZeroU64::new(MODULUS_31P).unwrap(),
+        NonZeroU64::new(MODULUS_32).unwrap(),
+    ]
+}
+
+#[test]
+fn one_shot_empty_is_zero() {
+    for seed in SEEDS {
+        assert_eq!(koopman8(&[], seed), 0);
+        assert_eq!(koopman16(&[], seed), 0);
+        assert_eq!(koopman32(&[], seed), 0);
+        assert_eq!(koopman8p(&[], seed), 0);
+        assert_eq!(koopman16p(&[], seed), 0);
+        assert_eq!(koopman32p(&[], seed), 0);
+
+        assert_eq!(koopman8_of([], seed), 0);
+        assert_eq!(koopman16_of("", seed), 0);
+        assert_eq!(koopman32_of(Vec::new(), seed), 0);
+        assert_eq!(koopman8p_of([], seed), 0);
+        assert_eq!(koopman16p_of([], seed), 0);
+        assert_eq!(koopman32p_of([], seed), 0);
+
+        assert_eq!(koopman8_typed(&[], seed).get(), 0);
+        assert_eq!(koopman16_typed(&[], seed).get(), 0);
+        assert_eq!(koopman32_typed(&[], seed).get(), 0);
+
+        assert_eq!(koopman16_reversed(&[], seed), 0);
+        assert_eq!(&koopman32_key(&[], seed), b"00000000");
+    }
+}
+
+#[test]
+fn custom_modulus_empty_is_zero() {
+    for seed in SEEDS {
+        for m in moduli32() {
+            assert_eq!(koopman8_with_modulus(&[], seed, m), 0);
+            assert_eq!(koopman16_with_modulus(&[], seed, m), 0);
+            // Parity moduli must fit the checksum portion of the packing
+            if m.get() < 1 << 7 {
+                assert_eq!(koopman8p_with_modulus(&[], seed, m), 0);
+            }
+            assert_eq!(koopman16p_with_modulus(&[], seed, m), 0);
+        }
+        for m in moduli64() {
+            assert_eq!(koopman32_with_modulus(&[], seed, m), 0);
+            if m.get() < 1 << 31 {
+                assert_eq!(koopman32p_with_modulus(&[], seed, m), 0);
+            }
+        }
+    }
+}
+
+#[test]
+fn checked_empty_is_zero() {
+    for seed in SEEDS {
+        assert_eq!(koopman8_checked(&[], seed), Ok(0));
+        assert_eq!(koopman16_checked(&[], seed), Ok(0));
+        assert_eq!(koopman32_checked(&[], seed), Ok(0));
+        assert_eq!(koopman8p_checked(&[], seed), Ok(0));
+        assert_eq!(koopman16p_checked(&[], seed), Ok(0));
+        assert_eq!(koopman32p_checked(&[], seed), Ok(0));
+    }
+}
+
+#[test]
+fn streaming_without_data_is_zero() {
+    macro_rules! check {
+        ($hasher:ident, $moduli:expr, $max_modulus:expr) => {{
+            assert_eq!($hasher::new().finalize(), 0);
+            assert_eq!($hasher::default().finalize(), 0);
+            for m in $moduli.into_iter().filter(|m| m.get() <= $max_modulus) {
+                assert_eq!($hasher::with_modulus(m).finalize(), 0);
+            }
+            for seed in SEEDS {
+                let mut h = $hasher::with_seed(seed);
+                assert_eq!(h.peek(), 0);
+                h.update(&[]);
+                assert_eq!(h.finalize(), 0);
+
+                let mut h = $hasher::with_seed(seed);
+                h.update(b"data");
+                h.reset();
+                assert_eq!(h.finalize(), 0);
+
+                assert_eq!(CheckedDigest::new($hasher::with_seed(seed)).try_finalize(), Ok(0));
+            }
+        }};
+    }
+
+    check!(Koopman8, moduli32(), u32::MAX);
+    check!(Koopman16, moduli32(), u32::MAX);
+    check!(Koopman32, moduli64(), u64::MAX);
+    // Parity moduli must fit the checksum portion of the packing
+    check!(Koopman8P, moduli32(), 127);
+    check!(Koopman16P, moduli32(), 32767);
+    check!(Koopman32P, moduli64(), 2147483647);
+}
+
+#[test]
+fn verify_empty_against_zero() {
+    for seed in SEEDS {
+        assert!(verify8(&[], 0, seed));
+        assert!(verify16(&[], 0, seed));
+        assert!(verify32(&[], 0, seed));
+        assert!(verify8p(&[], 0, seed));
+        assert!(verify16p(&[], 0, seed));
+        assert!(verify32p(&[], 0, seed));
+    }
+}
@@ -21,6 +21,7 @@
 //! |------|-------------|------|
 //! | `koopman8_hd3_exhaustive` | koopman8 all lengths 1-13, verifies all 1-2 bit errors detected | seconds |
 //! | `koopman8p_hd4_exhaustive` | koopman8p all lengths 1-5, verifies all 1-3 bit errors detected | seconds |
+//! | `koopman8p_hd4_boundary` | koopman8p just past its limit, shows where the guarantee ends | seconds |
 //! | `koopman16_hd3_exhaustive` | koopman16 at 4092 bytes, verifies all 1-2 bit errors detected | ~1 day |
 //! | `koopman16p_hd4_exhaustive` | koopman16p at 2044 bytes, verifies all 1-3 bit errors detected | week+ |
 //! | `hd_quick_sanity` | Quick sanity check of all variants | instant |
@@ -38,23 +39,15 @@
 //! cargo test --release --test hd_exhaustive -- koopman16_hd3_exhaustive --nocapture
 //! ```
 
-use koopman_checksum::{koopman8, koopman8p, koopman16, koopman16p};
+use koopman_checksum::{
+    koopman8, koopman8p, koopman16, koopman16p, KOOPMAN16P_MAX_LEN as MAX_LEN_16P,
+    KOOPMAN16_MAX_LEN as MAX_LEN_16, KOOPMAN8P_MAX_LEN as MAX_LEN_8P,
+    KOOPMAN8_MAX_LEN as MAX_LEN_8,
+};
 use rayon::prelude::*;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::Instant;
 
-/// Maximum data length for koopman8 to detect all 1-2 bit errors
-const MAX_LEN_8: usize = 13;
-
-/// Maximum data length for koopman16 to detect all 1-2 bit errors
-const MAX_LEN_16: usize = 4092;
-
-/// Maximum data length for koopman8p to detect all 1-3 bit errors
-const MAX_LEN_8P: usize = 5;
-
-/// Maximum data length for koopman16p to detect all 1-3 bit errors
-const MAX_LEN_16P: usize = 2044;
-
 /// Generate all-zero test data of given length.
 fn generate_zeros(len: usize) -> Vec<u8> {
     vec![0; len]
@@ -230,19 +223,15 @@ fn run_hd_test<F, C>(
         tests_completed.fetch_add(c1, Ordering::Relaxed);
 
         // 2-bit errors
-        if max_errors >= 2 {
-            if !verify_2bit(name, seed, data, &checksum_fn, &tests_completed) {
-                failed.fetch_add(1, Ordering::Relaxed);
-                return;
-            }
+        if max_errors >= 2 && !verify_2bit(name, seed, data, &checksum_fn, &tests_completed) {
+            failed.fetch_add(1, Ordering::Relaxed);
+            return;
         }
 
         // 3-bit errors
-        if max_errors >= 3 {
-            if !verify_3bit(name, seed, data, &checksum_fn, &tests_completed) {
-                failed.fetch_add(1, Ordering::Relaxed);
-                return;
-            }
+        if max_errors >= 3 && !verify_3bit(name, seed, data, &checksum_fn, &tests_completed) {
+            failed.fetch_add(1, Ordering::Relaxed);
+            return;
         }
 
         let done = completed_seeds.fetch_add(1, Ordering::Relaxed) + 1;
@@ -344,6 +333,85 @@ fn koopman8p_hd4_exhaustive() {
     run_hd_tests_all_lengths("koopman8p", MAX_LEN_8P, 3, koopman8p);
 }
 
+/// Bit positions of every undetected error of exactly `errors` bits.
+fn undetected_errors<F, C>(seed: u8, data: &[u8], errors: usize, checksum_fn: &F) -> Vec<Vec<usize>>
+where
+    F: Fn(&[u8], u8) -> C,
+    C: Eq,
+{
+    fn search<F, C>(
+        seed: u8,
+        data: &mut Vec<u8>,
+        original: &C,
+        bits: &mut Vec<usize>,
+        errors: usize,
+        checksum_fn: &F,
+        found: &mut Vec<Vec<usize>>,
+    ) where
+        F: Fn(&[u8], u8) -> C,
+        C: Eq,
+    {
+        if bits.len() == errors {
+            if checksum_fn(data, seed) == *original {
+                found.push(bits.clone());
+            }
+            return;
+        }
+        let start = bits.last().map_or(0, |&b| b + 1);
+        for bit in start..data.len() * 8 {
+            flip_bit(data, bit);
+            bits.push(bit);
+            search(seed, data, original, bits, errors, checksum_fn, found);
+            bits.pop();
+            flip_bit(data, bit);
+        }
+    }
+
+    let original = checksum_fn(data, seed);
+    let mut found = Vec::new();
+    search(seed, &mut data.to_vec(), &original, &mut Vec::new(), errors, checksum_fn, &mut found);
+    found
+}
+
+// koopman8p HD=4 boundary (lengths 6 and 7)
+//
+// The parity bit catches every odd-weight error, so no 3-bit error is ever
+// missed at any length; the HD=4 limit is set by 2-bit errors in the mod-125
+// sum. Those need two flips exactly 50 bits apart (2^50 = -1 mod 125), which
+// first fits at 7 bytes. Data-only HD=4 therefore holds through 6 bytes, one
+// past KOOPMAN8P_MAX_LEN; the documented limit keeps the published figure,
+// which like KOOPMAN16P_MAX_LEN (2044, where the same argument gives 2046)
+// leaves room for the check value itself.
+#[test]
+fn koopman8p_hd4_boundary() {
+    for data_len in [MAX_LEN_8P + 1, MAX_LEN_8P + 2] {
+        for data in [generate_zeros(data_len), generate_pattern(data_len)] {
+            for seed in 0..=255u8 {
+                assert!(
+                    undetected_errors(seed, &data, 3, &koopman8p).is_empty(),
+                    "koopman8p missed a 3-bit error at {data_len} bytes, seed {seed:#04x}"
+                );
+            }
+        }
+    }
+
+    for data in [generate_zeros(MAX_LEN_8P + 1), generate_pattern(MAX_LEN_8P + 1)] {
+        for seed in 0..=255u8 {
+            assert!(verify_1bit("koopman8p", seed, &data, &koopman8p));
+            assert!(undetected_errors(seed, &data, 2, &koopman8p).is_empty());
+        }
+    }
+
+    let data_len = MAX_LEN_8P + 2;
+    let missed = undetected_errors(0, &generate_zeros(data_len), 2, &koopman8p);
+    assert!(!missed.is_empty(), "expected an undetected 2-bit error at {data_len} bytes");
+    // Power of two each flipped bit contributes, counting the implicit zero byte
+    let weight = |bit: usize| 8 * (data_len - bit / 8) + bit % 8;
+    for bits in missed {
+        assert_eq!(weight(bits[0]) - weight(bits[1]), 50, "{bits:?}");
+    }
+}
+
 // koopman16 HD=3 exhaustive test (max length: 4092 bytes)
 // WARNING: This test takes several hours to complete
 #[test]
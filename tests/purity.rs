@@ -0,0 +1,194 @@
+//! Purity of the core one-shot functions.
+//!
+//! `koopman8/16/32` and their parity variants are documented as interrupt-safe
+//! and reentrant: they read only their arguments, touch no global state, and
+//! never allocate. These tests enforce that: a counting allocator checks the
+//! calls allocate nothing, the crate sources are scanned for global mutable
+//! state, and concurrent calls from many threads must agree.
+
+use koopman_checksum::*;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+/// Counts allocations made by the current thread.
+struct CountingAlloc;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+// SAFETY: defers to `System` for every operation; only bumps a counter.
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|n| n.set(n.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+fn allocations() -> usize {
+    ALLOCATIONS.with(Cell::get)
+}
+
+fn test_data() -> Vec<u8> {
+    (0..4096u32).map(|i| (i * 131 + 7) as u8).collect()
+}
+
+/// Every core one-shot result for `data`, widened for comparison.
+fn all_variants(data: &[u8], seed: u8) -> [u32; 6] {
+    [
+        koopman8(data, seed) as u32,
+        koopman16(data, seed) as u32,
+        koopman32(data, seed),
+        koopman8p(data, seed) as u32,
+        koopman16p(data, seed) as u32,
+        koopman32p(data, seed),
+    ]
+}
+
+#[test]
+fn one_shot_functions_do_not_allocate() {
+    let data = test_data();
+
+    let before = allocations();
+    for len in [0, 1, 2, 7, 64, 4096] {
+        std::hint::black_box(all_variants(std::hint::black_box(&data[..len]), 0xee));
+    }
+    assert_eq!(allocations(), before);
+}
+
+/// Identifier and `!` tokens of Rust source, with comments, string
+/// literals, and character literals removed, so prose and messages that
+/// mention a type do not count as using it.
+fn code_tokens(text: &str) -> Vec<String> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+        if c == '/' && next == Some('/') {
+            while i < chars.len() && chars[i] != '\n' {
+                i += 1;
+            }
+        } else if c == '/' && next == Some('*') {
+            let mut depth = 0;
+            while i < chars.len() {
+                if chars[i] == '/' && chars.get(i + 1) == Some(&'*') {
+                    depth += 1;
+                    i += 2;
+                } else if chars[i] == '*' && chars.get(i + 1) == Some(&'/') {
+                    depth -= 1;
+                    i += 2;
+                    if depth == 0 {
+                        break;
+                    }
+                } else {
+                    i += 1;
+                }
+            }
+        } else if c == 'r' && matches!(next, Some('"' | '#')) {
+            // Raw string: r"..." or r#"..."#
+            let hashes = chars[i + 1..].iter().take_while(|&&c| c == '#').count();
+            if chars.get(i + 1 + hashes) != Some(&'"') {
+                tokens.push("r".to_owned());
+                i += 1;
+                continue;
+            }
+            i += hashes + 2;
+            while i < chars.len()
+                && !(chars[i] == '"' && chars[i + 1..].iter().take(hashes).all(|&c| c == '#'))
+            {
+                i += 1;
+            }
+            i += hashes + 1;
+        } else if c == '"' {
+            i += 1;
+            while i < chars.len() && chars[i] != '"' {
+                i += if chars[i] == '\\' { 2 } else { 1 };
+            }
+            i += 1;
+        } else if c == '\'' {
+            // Character literal, or the start of a lifetime or label
+            if next == Some('\\') {
+                i += 2;
+                while i < chars.len() && chars[i] != '\'' {
+                    i += 1;
+                }
+                i += 1;
+            } else if chars.get(i + 2) == Some(&'\'') {
+                i += 3;
+            } else {
+                i += 1;
+            }
+        } else if c.is_alphanumeric() || c == '_' {
+            let start = i;
+            while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                i += 1;
+            }
+            tokens.push(chars[start..i].iter().collect());
+        } else {
+            if c == '!' {
+                tokens.push("!".to_owned());
+            }
+            i += 1;
+        }
+    }
+    tokens
+}
+
+#[test]
+fn code_tokens_skip_comments_and_literals() {
+    let text = r##"
+        // A Mutex in a line comment
+        /* and in a /* nested */ block comment: static mut */
+        let s = "Mutex \" OnceLock";
+        let r = r#"thread_local!"#;
+        let c = '"'; fn f<'a>(x: &'a AtomicUsize) {}
+    "##;
+    assert_eq!(
+        code_tokens(text),
+        ["let", "s", "let", "r", "let", "c", "fn", "f", "a", "x", "a", "AtomicUsize"]
+    );
+}
+
+#[test]
+fn sources_have_no_global_mutable_state() {
+    let src = concat!(env!("CARGO_MANIFEST_DIR"), "/src");
+    for entry in std::fs::read_dir(src).unwrap() {
+        let path = entry.unwrap().path();
+        let tokens = code_tokens(&std::fs::read_to_string(&path).unwrap());
+        for (i, token) in tokens.iter().enumerate() {
+            let next = tokens.get(i + 1).map(String::as_str);
+            let forbidden = match token.as_str() {
+                "static" if next == Some("mut") => true,
+                "thread_local" if next == Some("!") => true,
+                "OnceLock" | "OnceCell" | "Mutex" | "RwLock" => true,
+                t => t.starts_with("Atomic"),
+            };
+            assert!(!forbidden, "{} uses `{token}`", path.display());
+        }
+    }
+}
+
+#[test]
+fn one_shot_functions_are_reentrant() {
+    let data = test_data();
+    let expected = all_variants(&data, 0xee);
+
+    std::thread::scope(|scope| {
+        for _ in 0..8 {
+            scope.spawn(|| {
+                for _ in 0..50 {
+                    assert_eq!(all_variants(&data, 0xee), expected);
+                }
+            });
+        }
+    });
+}
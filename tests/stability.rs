@@ -0,0 +1,164 @@
+//! Checksum stability across build profiles.
+//!
+//! Every variant is checked against a `u128` reference implementation that
+//! cannot overflow, on inputs that drive the running sum and the fast
+//! reductions to their upper bounds (runs of `0xFF`, seed `0xFF`, and the
+//! largest moduli each variant supports). An intermediate that overflowed
+//! would panic under `overflow-checks` and wrap without them, so passing both
+//! `cargo test` and `cargo test --release` shows results do not depend on the
+//! profile.
+
+use core::num::{NonZeroU32, NonZeroU64};
+use koopman_checksum::*;
+
+const SEEDS: [u8; 3] = [0x00, 0x01, 0xFF];
+
+/// Inputs that exercise near-bound intermediates.
+fn test_vectors() -> Vec<Vec<u8>> {
+    let mut vectors = vec![b"123456789".to_vec(), (0..=255).collect(), vec![0x00; 100]];
+    for len in (1..=17).chain([63, 64, 65, 4096]) {
+        vectors.push(vec![0xFF; len]);
+        vectors.push((0..len).map(|i| if i % 2 == 0 { 0xFF } else { 0x00 }).collect());
+    }
+    vectors
+}
+
+/// Koopman register over `data` followed by `zeros` implicit zero bytes.
+fn reference(data: &[u8], seed: u8, modulus: u128, zeros: usize) -> u128 {
+    let Some((&first, rest)) = data.split_first() else {
+        return 0;
+    };
+
+    let mut sum = (first ^ seed) as u128;
+    for &byte in rest {
+        sum = (sum * 256 + byte as u128) % modulus;
+    }
+    for _ in 0..zeros {
+        sum = sum * 256 % modulus;
+    }
+    sum
+}
+
+/// Parity variant: checksum shifted up, parity of the folded byte stream in the LSB.
+fn reference_parity(data: &[u8], seed: u8, modulus: u128, zeros: usize) -> u128 {
+    let Some((&first, rest)) = data.split_first() else {
+        return 0;
+    };
+
+    let psum = rest.iter().fold(first ^ seed, |acc, &b| acc ^ b);
+    (reference(data, seed, modulus, zeros) << 1) | (psum.count_ones() & 1) as u128
+}
+
+#[test]
+fn default_moduli_match_reference() {
+    for data in &test_vectors() {
+        for seed in SEEDS {
+            let m8 = MODULUS_8 as u128;
+            let m16 = MODULUS_16 as u128;
+            let m32 = MODULUS_32 as u128;
+            assert_eq!(koopman8(data, seed) as u128, reference(data, seed, m8, 1));
+            assert_eq!(koopman16(data, seed) as u128, reference(data, seed, m16, 2));
+            assert_eq!(koopman32(data, seed) as u128, reference(data, seed, m32, 4));
+
+            let (p8, p16, p32) = (MODULUS_7P as u128, MODULUS_15P as u128, MODULUS_31P as u128);
+            assert_eq!(koopman8p(data, seed) as u128, reference_parity(data, seed, p8, 1));
+            assert_eq!(koopman16p(data, seed) as u128, reference_parity(data, seed, p16, 2));
+            assert_eq!(koopman32p(data, seed) as u128, reference_parity(data, seed, p32, 4));
+        }
+    }
+}
+
+#[test]
+fn streaming_matches_reference() {
+    for data in &test_vectors() {
+        for seed in SEEDS {
+            // Uneven chunks so every unrolled and wide fold sees partial input
+            macro_rules! streamed {
+                ($hasher:ident) => {{
+                    let mut h = $hasher::with_seed(seed);
+                    for chunk in data.chunks(13) {
+                        h.update(chunk);
+                    }
+                    h.finalize() as u128
+                }};
+            }
+
+            assert_eq!(streamed!(Koopman8), reference(data, seed, MODULUS_8 as u128, 1));
+            assert_eq!(streamed!(Koopman16), reference(data, seed, MODULUS_16 as u128, 2));
+            assert_eq!(streamed!(Koopman32), reference(data, seed, MODULUS_32 as u128, 4));
+            assert_eq!(streamed!(Koopman8P), reference_parity(data, seed, MODULUS_7P as u128, 1));
+            assert_eq!(streamed!(Koopman16P), reference_parity(data, seed, MODULUS_15P as u128, 2));
+            assert_eq!(streamed!(Koopman32P), reference_parity(data, seed, MODULUS_31P as u128, 4));
+        }
+    }
+}
+
+#[test]
+fn largest_custom_moduli_match_reference() {
+    // Largest moduli whose checksums fit each variant's output
+    let m8 = NonZeroU32::new(u8::MAX as u32).unwrap();
+    let m16 = NonZeroU32::new(u16::MAX as u32).unwrap();
+    let m32 = NonZeroU64::new(u32::MAX as u64).unwrap();
+    let m7p = NonZeroU32::new(127).unwrap();
+    let m15p = NonZeroU32::new(32767).unwrap();
+    let m31p = NonZeroU64::new((1 << 31) - 1).unwrap();
+
+    for data in &test_vectors() {
+        for seed in SEEDS {
+            let r = |m: u64, zeros| reference(data, seed, m as u128, zeros);
+            let p = |m: u64, zeros| reference_parity(data, seed, m as u128, zeros);
+
+            assert_eq!(koopman8_with_modulus(data, seed, m8) as u128, r(255, 1));
+            assert_eq!(koopman16_with_modulus(data, seed, m16) as u128, r(65535, 2));
+            assert_eq!(koopman32_with_modulus(data, seed, m32) as u128, r(u32::MAX as u64, 4));
+            assert_eq!(koopman8p_with_modulus(data, seed, m7p) as u128, p(127, 1));
+            assert_eq!(koopman16p_with_modulus(data, seed, m15p) as u128, p(32767, 2));
+            assert_eq!(koopman32p_with_modulus(data, seed, m31p) as u128, p((1 << 31) - 1, 4));
+        }
+
+        let mut h = Koopman32::with_modulus(m32);
+        h.update(data);
+        assert_eq!(h.finalize() as u128, reference(data, 0, u32::MAX as u128, 4));
+
+        let mut h = Koopman16::with_modulus(m16);
+        h.update(data);
+        assert_eq!(h.finalize() as u128, reference(data, 0, 65535, 2));
+    }
+}
+
+#[test]
+fn single_byte_matches_reference() {
+    // One data byte and the implicit zeros: a single reduction of (b ^ seed) << 8k
+    let expected = |v: u8, modulus: u128, zeros: u32| ((v as u128) << (8 * zeros)) % modulus;
+    let parity = |v: u8, modulus: u128, zeros: u32| {
+        (expected(v, modulus, zeros) << 1) | (v.count_ones() & 1) as u128
+    };
+
+    for seed in SEEDS.into_iter().chain([0x5A, 0xA5]) {
+        for byte in 0..=255u8 {
+            let data = [byte];
+            let v = byte ^ seed;
+            assert_eq!(koopman8(&data, seed) as u128, expected(v, MODULUS_8 as u128, 1));
+            assert_eq!(koopman16(&data, seed) as u128, expected(v, MODULUS_16 as u128, 2));
+            assert_eq!(koopman32(&data, seed) as u128, expected(v, MODULUS_32 as u128, 4));
+            assert_eq!(koopman8p(&data, seed) as u128, parity(v, MODULUS_7P as u128, 1));
+            assert_eq!(koopman16p(&data, seed) as u128, parity(v, MODULUS_15P as u128, 2));
+            assert_eq!(koopman32p(&data, seed) as u128, parity(v, MODULUS_31P as u128, 4));
+
+            macro_rules! streamed {
+                ($hasher:ident) => {{
+                    let mut h = $hasher::with_seed(seed);
+                    h.update(&data);
+                    h.finalize() as u128
+                }};
+            }
+
+            assert_eq!(streamed!(Koopman8), expected(v, MODULUS_8 as u128, 1));
+            assert_eq!(streamed!(Koopman16), expected(v, MODULUS_16 as u128, 2));
+            assert_eq!(streamed!(Koopman32), expected(v, MODULUS_32 as u128, 4));
+            assert_eq!(streamed!(Koopman8P), parity(v, MODULUS_7P as u128, 1));
+            assert_eq!(streamed!(Koopman16P), parity(v, MODULUS_15P as u128, 2));
+            assert_eq!(streamed!(Koopman32P), parity(v, MODULUS_31P as u128, 4));
+        }
+    }
+}
@@ -0,0 +1,53 @@
+//! Tests for the `trace` feature's construction logging.
+
+// Copyright (c) 2025 the koopman-checksum authors, all rights reserved.
+// See README.md for licensing information.
+
+#![cfg(feature = "trace")]
+
+use koopman_checksum::{Koopman16, Koopman16P};
+use log::{Level, LevelFilter, Log, Metadata, Record};
+use std::num::NonZeroU32;
+use std::sync::Mutex;
+
+/// Records every debug message so tests can inspect them.
+struct CaptureLogger {
+    messages: Mutex<Vec<String>>,
+}
+
+impl Log for CaptureLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= Level::Debug
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            self.messages.lock().unwrap().push(record.args().to_string());
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+static LOGGER: CaptureLogger = CaptureLogger {
+    messages: Mutex::new(Vec::new()),
+};
+
+/// The logger is process-global, so all assertions live in one test.
+#[test]
+fn test_construction_logs_strategy() {
+    log::set_logger(&LOGGER).unwrap();
+    log::set_max_level(LevelFilter::Debug);
+
+    let _ = Koopman16::new();
+    let _ = Koopman16::with_modulus(NonZeroU32::new(65521).unwrap());
+    let _ = Koopman16P::with_seed(0xee);
+
+    let messages = LOGGER.messages.lock().unwrap();
+    assert_eq!(messages.len(), 3, "{messages:?}");
+    assert!(messages[0].contains("Koopman16: modulus 65519"), "{}", messages[0]);
+    assert!(messages[0].contains("use_fast_mod = true"), "{}", messages[0]);
+    assert!(messages[1].contains("modulus 65521"), "{}", messages[1]);
+    assert!(messages[1].contains("use_fast_mod = false"), "{}", messages[1]);
+    assert!(messages[2].starts_with("Koopman16P: modulus 32749"), "{}", messages[2]);
+}
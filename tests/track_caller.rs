@@ -0,0 +1,37 @@
+//! Panicking public functions report the caller's location, not a line
+//! inside the crate.
+//!
+//! Kept in its own test binary because the panic hook is process-global.
+
+use koopman_checksum::{koopman16_region, koopman16_sampled, Koopman16};
+use std::panic;
+use std::sync::Mutex;
+
+static LOCATION: Mutex<Option<(String, u32)>> = Mutex::new(None);
+
+/// Run `f`, which must panic, and return the file and line the panic
+/// reported.
+fn panic_location(f: impl FnOnce() + panic::UnwindSafe) -> (String, u32) {
+    panic::set_hook(Box::new(|info| {
+        let location = info.location().unwrap();
+        *LOCATION.lock().unwrap() = Some((location.file().to_owned(), location.line()));
+    }));
+    let result = panic::catch_unwind(f);
+    let _ = panic::take_hook();
+    assert!(result.is_err(), "call did not panic");
+    LOCATION.lock().unwrap().take().unwrap()
+}
+
+#[test]
+fn panics_report_the_calling_line() {
+    let this_file = file!().to_owned();
+
+    let (reported, line) = (panic_location(|| { let _ = koopman16_sampled(b"data", 0xee, 0); }), line!());
+    assert_eq!(reported, (this_file.clone(), line));
+
+    let (reported, line) = (panic_location(|| { let _ = koopman16_region(&[0; 16], 0, 8, 0, 0); }), line!());
+    assert_eq!(reported, (this_file.clone(), line));
+
+    let (reported, line) = (panic_location(|| { let _ = Koopman16::split_at(b"short", 6, 0xee); }), line!());
+    assert_eq!(reported, (this_file, line));
+}
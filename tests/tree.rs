@@ -0,0 +1,71 @@
+//! Directory-tree checksumming over a real temporary directory.
+
+#![cfg(feature = "std")]
+
+use koopman_checksum::io::koopman32_tree;
+use koopman_checksum::koopman32;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Temporary directory removed on drop.
+struct TempDir(PathBuf);
+
+impl TempDir {
+    fn new(name: &str) -> Self {
+        let path = std::env::temp_dir().join(format!("koopman-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&path);
+        fs::create_dir_all(&path).unwrap();
+        Self(path)
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+#[test]
+fn tree_checksums_match_direct_computation() {
+    let dir = TempDir::new("tree");
+    let root = &dir.0;
+    let big: Vec<u8> = (0..200_000u32).map(|i| (i * 131 + 7) as u8).collect();
+
+    fs::write(root.join("a.txt"), b"first file").unwrap();
+    fs::write(root.join("empty"), b"").unwrap();
+    fs::create_dir_all(root.join("sub/nested")).unwrap();
+    fs::write(root.join("sub/nested/big.bin"), &big).unwrap();
+
+    let sums = koopman32_tree(root, 0xee).unwrap();
+
+    let expected = [
+        (Path::new("a.txt"), koopman32(b"first file", 0xee)),
+        (Path::new("empty"), 0),
+        (Path::new("sub/nested/big.bin"), koopman32(&big, 0xee)),
+    ];
+    assert_eq!(sums.len(), expected.len());
+    for (path, checksum) in expected {
+        assert_eq!(sums[path], checksum, "{}", path.display());
+    }
+}
+
+#[cfg(unix)]
+#[test]
+fn tree_skips_symlinks() {
+    let dir = TempDir::new("tree-symlink");
+    let root = &dir.0;
+
+    fs::write(root.join("file"), b"data").unwrap();
+    // A link back to the root would loop forever if followed
+    std::os::unix::fs::symlink(root, root.join("loop")).unwrap();
+    std::os::unix::fs::symlink(root.join("file"), root.join("file-link")).unwrap();
+
+    let sums = koopman32_tree(root, 0xee).unwrap();
+    assert_eq!(sums.keys().collect::<Vec<_>>(), [Path::new("file")]);
+}
+
+#[test]
+fn tree_missing_root_is_an_error() {
+    let dir = TempDir::new("tree-missing");
+    assert!(koopman32_tree(dir.0.join("does-not-exist"), 0).is_err());
+}
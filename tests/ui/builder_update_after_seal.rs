@@ -0,0 +1,8 @@
+use koopman_checksum::typestate::Koopman16Builder;
+
+fn main() {
+    let mut builder = Koopman16Builder::new();
+    builder.update(b"data");
+    let mut sealed = builder.finalize();
+    sealed.update(b"more");
+}
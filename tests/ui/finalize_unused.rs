@@ -0,0 +1,9 @@
+#![deny(unused_must_use)]
+
+use koopman_checksum::Koopman16;
+
+fn main() {
+    let mut hasher = Koopman16::new();
+    hasher.update(b"data");
+    hasher.finalize();
+}